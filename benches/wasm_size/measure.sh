@@ -0,0 +1,53 @@
+#!/bin/bash
+# Measure the size of the WASM bundle and the compile time of the crate with 4 locales of 2000 keys,
+# en-GB and en-US sharing 95% of the values of en, then with en-GB and en-US having only distinct values.
+set -e
+cd "$(dirname "$0")"
+
+KEYS=2000
+
+# write_locale <locale> <text> <shared>: every 20th key has its own text, the others the English text if <shared> is 1.
+write_locale() {
+    mkdir -p locales
+    {
+        echo "{"
+        for ((i = 0; i < KEYS; i++)); do
+            if [ "$3" = 1 ] && ((i % 20 != 0)); then
+                text="This is the English text number $i of the application"
+            else
+                text="This is the $2 text number $i of the application"
+            fi
+            sep=","
+            ((i == KEYS - 1)) && sep=""
+            echo "  \"key_$i\": \"$text\"$sep"
+        done
+        echo "}"
+    } > "locales/$1.json"
+}
+
+# timed <command...>: run the command after touching the crate so only it is rebuilt, and print the seconds it took.
+timed() {
+    touch src/lib.rs
+    start=$(date +%s%N)
+    "$@"
+    end=$(date +%s%N)
+    echo "$(((end - start) / 1000000 / 1000)).$(((end - start) / 100000000 % 10))"
+}
+
+measure() {
+    write_locale en English 0
+    write_locale en-GB British "$1"
+    write_locale en-US American "$1"
+    write_locale fr French 0
+    # build the dependencies first so only the crate is timed.
+    cargo check --target wasm32-unknown-unknown --quiet
+    cargo build --release --target wasm32-unknown-unknown --quiet
+    check_time=$(timed cargo check --target wasm32-unknown-unknown --quiet)
+    build_time=$(timed cargo build --release --target wasm32-unknown-unknown --quiet)
+    wasm-opt -Oz target/wasm32-unknown-unknown/release/wasm_size.wasm -o target/wasm_size.opt.wasm
+    echo "$2: $(stat -c %s target/wasm32-unknown-unknown/release/wasm_size.wasm) bytes, $(stat -c %s target/wasm_size.opt.wasm) bytes after wasm-opt -Oz"
+    echo "$2: cargo check in ${check_time} s, release build in ${build_time} s"
+}
+
+measure 1 "shared values"
+measure 0 "distinct values"
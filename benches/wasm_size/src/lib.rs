@@ -0,0 +1,13 @@
+leptos_i18n::load_locales!();
+
+use leptos_i18n::Locale as _;
+
+/// Return the length of the value of a key, so every value is kept in the binary.
+#[no_mangle]
+pub extern "C" fn text_len(locale: usize, key: usize) -> usize {
+    let locale = i18n::Locale::get_all()[locale % 4];
+    locale
+        .get_keys()
+        .get_string(&format!("key_{}", key))
+        .map_or(0, str::len)
+}
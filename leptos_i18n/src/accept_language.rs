@@ -0,0 +1,118 @@
+use crate::Locale;
+
+/// Parse the value of an `Accept-Language` header, returning the languages sorted by their quality value (`q=`) in preferred order.
+///
+/// ```
+/// let langs = leptos_i18n::parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+/// assert_eq!(langs, ["fr-CH", "fr", "en", "*"]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut parsed_lang: Vec<(f32, &str)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut params = entry.split(';');
+            let lang = params.next()?.trim();
+            if lang.is_empty() {
+                return None;
+            }
+            let q = params
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(1.0, |q| q.trim().parse::<f32>().unwrap_or(0.0));
+            // `q=0` means the language is not acceptable, an invalid quality is ignored the same way.
+            (q > 0.0).then_some((q, lang))
+        })
+        .collect();
+
+    // the sort is stable so the languages with the same quality keep the order of the header.
+    parsed_lang.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    parsed_lang
+        .into_iter()
+        .map(|(_q, lang)| lang.to_string())
+        .collect()
+}
+
+/// Return the locale that match the best the value of an `Accept-Language` header, or the default locale if none match.
+pub fn negotiate_locale<T: Locale>(header: &str) -> T {
+    T::find_locale(&parse_accept_language(header))
+}
+
+/// Find the locale matching the tag, removing its subtags from the end until one match.
+pub fn lookup<T: Locale>(tag: &str) -> Option<T> {
+    let mut tag = tag.trim();
+    while !tag.is_empty() && tag != "*" {
+        let found = T::get_all()
+            .iter()
+            .copied()
+            .find(|locale| crate::locale_tag::tag_eq(locale.as_str(), tag));
+        if found.is_some() {
+            return found;
+        }
+        tag = truncate_tag(tag);
+    }
+    None
+}
+
+/// Remove the last subtag of the tag, and the singleton before it if any (`"en-a-bbb"` become `"en"`).
+fn truncate_tag(tag: &str) -> &str {
+    let tag = tag.rfind(['-', '_']).map_or("", |i| &tag[..i]);
+    match tag.rfind(['-', '_']) {
+        Some(i) if tag.len() - i == 2 => &tag[..i],
+        _ => tag,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_tag() {
+        assert_eq!(truncate_tag("zh-Hant-CN"), "zh-Hant");
+        assert_eq!(truncate_tag("zh-Hant"), "zh");
+        assert_eq!(truncate_tag("zh"), "");
+        assert_eq!(truncate_tag("en-a-bbb-x-a"), "en-a-bbb");
+        assert_eq!(truncate_tag("en-a-bbb"), "en");
+        assert_eq!(truncate_tag("en_US"), "en");
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed_lang = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+
+        assert_eq!(parsed_lang, &["fr-CH", "fr", "en", "de", "*"]);
+    }
+
+    #[test]
+    fn test_parse_unsorted() {
+        let parsed_lang = parse_accept_language("en;q=0.1, fr;q=0.9");
+        assert_eq!(parsed_lang, &["fr", "en"]);
+
+        let parsed_lang = parse_accept_language("de;q=0.2, en");
+        assert_eq!(parsed_lang, &["en", "de"]);
+
+        let parsed_lang = parse_accept_language("*;q=0.5, de;q=0.7, fr-CH, en;level=1;q=0.8");
+        assert_eq!(parsed_lang, &["fr-CH", "en", "de", "*"]);
+    }
+
+    #[test]
+    fn test_parse_equal_quality() {
+        let parsed_lang = parse_accept_language("it;q=0.5, de, es;q=0.5, fr, pt;q=0.5");
+
+        assert_eq!(parsed_lang, &["de", "fr", "it", "es", "pt"]);
+    }
+
+    #[test]
+    fn test_parse_not_acceptable() {
+        let parsed_lang = parse_accept_language("fr;q=0, en;q=0.000, , de;q=0.1");
+
+        assert_eq!(parsed_lang, &["de"]);
+    }
+
+    #[test]
+    fn test_parse_invalid_quality() {
+        let parsed_lang = parse_accept_language("fr;q=abc, en;q=0.5, de;q=");
+
+        assert_eq!(parsed_lang, &["en"]);
+    }
+}
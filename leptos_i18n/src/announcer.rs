@@ -0,0 +1,47 @@
+use leptos::*;
+
+use crate::{I18nContext, Locale, LocaleKeys};
+
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+impl<T: Locale> I18nContext<T> {
+    /// Return the announcement of the last locale change, in the new language.
+    ///
+    /// The announcement is the value of the string key at the given path for the new locale (`key.subkey`, or `namespace::key.subkey` with namespaces),
+    /// it is empty until the locale changes.
+    pub fn locale_announcement(self, key_path: &'static str) -> Signal<&'static str> {
+        // (locale tag, announcement)
+        let announcement = create_memo(move |previous: Option<&(&'static str, &'static str)>| {
+            let locale = self.get_locale();
+            let message = match previous {
+                None => "",
+                Some((previous_locale, message)) if *previous_locale == locale.as_str() => message,
+                Some(_) => locale.get_keys().get_string(key_path).unwrap_or_default(),
+            };
+            (locale.as_str(), message)
+        });
+        // track the initial locale right away.
+        announcement.with_untracked(|_| ());
+        Signal::derive(move || announcement.with(|(_, message)| *message))
+    }
+
+    /// Create an `aria-live` region announcing to screen readers, in the new language, when the locale changes.
+    ///
+    /// The announcement is the value of the string key at the given path for the new locale, see `locale_announcement`.
+    /// The region is visually hidden.
+    ///
+    /// ```rust, ignore
+    /// // "locale_changed": "The language is now English"
+    /// view! {
+    ///     {i18n.locale_announcer("locale_changed")}
+    /// }
+    /// ```
+    pub fn locale_announcer(self, key_path: &'static str) -> impl IntoView {
+        let announcement = self.locale_announcement(key_path);
+        view! {
+            <div role="status" aria-live="polite" aria-atomic="true" style=VISUALLY_HIDDEN>
+                {announcement}
+            </div>
+        }
+    }
+}
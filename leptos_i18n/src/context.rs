@@ -1,7 +1,10 @@
 use leptos::*;
 use leptos_meta::*;
 
-use crate::{fetch_locale, locale_traits::*};
+use crate::{
+    fetch_locale, locale_override, locale_traits::*, missing_keys, storage::default_storage,
+    LocaleStorage,
+};
 
 /// This context is the heart of the i18n system:
 ///
@@ -36,6 +39,14 @@ impl<T: Locale> I18nContext<T> {
         self.get_locale_untracked().get_keys()
     }
 
+    /// Render the message of the error in the current locale, subscribing to any changes.
+    ///
+    /// Return `None` if the key does not exist or if its value contains components or plurals.
+    #[inline]
+    pub fn localize_error<E: crate::LocalizedError + ?Sized>(self, error: &E) -> Option<String> {
+        crate::localize_error(self.get_locale(), error)
+    }
+
     /// Set the locale and notify all subscribers
     #[inline]
     pub fn set_locale(self, lang: T) {
@@ -49,32 +60,44 @@ impl<T: Locale> I18nContext<T> {
     }
 }
 
-fn set_html_lang_attr(lang: &'static str) {
-    let lang = || lang.to_string();
-    Html(HtmlProps {
-        lang: Some(lang.into()),
-        dir: None,
-        class: None,
-        attributes: vec![],
-    });
-}
-
-fn init_context<T: Locale>() -> I18nContext<T> {
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+fn init_context<T: Locale>(storage: impl LocaleStorage) -> I18nContext<T> {
     provide_meta_context();
 
-    let locale = fetch_locale::fetch_locale::<T>();
+    // when hydrating the locale rendered by the server must be kept.
+    let stored_locale = if cfg!(feature = "hydrate") {
+        None
+    } else {
+        storage.load().and_then(|tag| T::from_str(&tag))
+    };
+
+    let locale = locale_override::get_locale_override::<T>()
+        .or(stored_locale)
+        .unwrap_or_else(fetch_locale::fetch_locale::<T>);
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(locale = locale.as_str(), "locale negotiated");
 
     let locale = create_rw_signal(locale);
 
     create_isomorphic_effect(move |_| {
         let new_lang = locale.get();
-        set_html_lang_attr(new_lang.as_str());
-        #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
-        set_lang_cookie::<T>(new_lang);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(locale = new_lang.as_str(), "locale changed");
+        missing_keys::report_missing_keys(new_lang);
+        #[cfg(any(feature = "hydrate", feature = "csr"))]
+        storage.save(new_lang.as_str());
+        #[cfg(all(feature = "wasm-bindgen", any(feature = "hydrate", feature = "csr")))]
+        crate::js_interop::notify_locale_change(new_lang.as_str());
     });
 
     let context = I18nContext::<T>(locale);
 
+    crate::provide_dir(context);
+
+    #[cfg(all(feature = "wasm-bindgen", any(feature = "hydrate", feature = "csr")))]
+    crate::js_interop::register_context(context);
+
     provide_context(context);
 
     context
@@ -88,29 +111,44 @@ fn init_context<T: Locale>() -> I18nContext<T> {
 ///
 /// If called when a context is already present it will not overwrite it and just return the current context.
 pub fn provide_i18n_context<T: Locale>() -> I18nContext<T> {
-    use_context().unwrap_or_else(init_context)
+    provide_i18n_context_with_storage(default_storage::<T>())
+}
+
+/// Same as `provide_i18n_context` but load and save the locale with the given storage
+/// instead of the default one (the cookie named by `Locale::COOKIE_NAME` if the `cookie` feature is enabled).
+pub fn provide_i18n_context_with_storage<T: Locale>(storage: impl LocaleStorage) -> I18nContext<T> {
+    use_context().unwrap_or_else(|| init_context(storage))
+}
+
+/// Provide a new `I18nContext` set to the given locale.
+///
+/// This is meant for reactive code that does not run under the application owner, such as a global store
+/// or a test, as `provide_i18n_context` return the context already present and `use_i18n_context` panics without one.
+///
+/// Unlike `provide_i18n_context` it always creates a new context, and it neither loads nor saves the locale or updates the `lang` attribute of `<html>`.
+pub fn provide_i18n_context_with<T: Locale>(locale: T) -> I18nContext<T> {
+    let context = I18nContext::<T>(create_rw_signal(locale));
+    provide_context(context);
+    context
 }
 
 /// Return the `I18nContext` previously set.
 ///
+/// The context is looked up in the current owner, so it must be called in a component or in code run under it.
+/// The context is `Copy`, so for resources or `spawn_local` tasks get it beforehand and move it in the closure.
+///
 /// ## Panic
 ///
-/// Panics if the context is missing.
+/// Panics if the context is missing, see `try_use_i18n_context` to not panic.
 #[inline]
 pub fn use_i18n_context<T: Locale>() -> I18nContext<T> {
     use_context().expect("I18nContext is missing, use provide_i18n_context() to provide it.")
 }
 
-#[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
-fn set_lang_cookie<T: Locale>(lang: T) -> Option<()> {
-    use crate::COOKIE_PREFERED_LANG;
-    let document = super::get_html_document()?;
-    let cookie = format!(
-        "{}={}; SameSite=Lax; Secure; Path=/; Max-Age=31536000",
-        COOKIE_PREFERED_LANG,
-        lang.as_str()
-    );
-    document.set_cookie(&cookie).ok()
+/// Return the `I18nContext` previously set, or `None` if it is missing.
+#[inline]
+pub fn try_use_i18n_context<T: Locale>() -> Option<I18nContext<T>> {
+    use_context()
 }
 
 // get locale
@@ -0,0 +1,190 @@
+use std::fmt::{self, Display};
+
+use leptos::*;
+use leptos_meta::{Html, HtmlProps};
+
+use crate::{I18nContext, Locale};
+
+/// Languages written from right to left.
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "arc", "azb", "ckb", "dv", "fa", "he", "iw", "ji", "ks", "lrc", "mzn", "nqo", "pnb",
+    "ps", "sd", "syr", "ug", "ur", "yi",
+];
+
+/// Scripts written from right to left.
+const RTL_SCRIPTS: &[&str] = &[
+    "adlm", "arab", "hebr", "nkoo", "rohg", "samr", "syrc", "thaa",
+];
+
+/// The direction of the text of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Left to right.
+    #[default]
+    Ltr,
+    /// Right to left.
+    Rtl,
+}
+
+impl Direction {
+    /// Return the direction of the given locale tag.
+    ///
+    /// The direction is given by the script subtag if any (`"az-Arab"` is right to left), else by the language subtag.
+    pub fn of(tag: &str) -> Self {
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        let is_rtl = match subtags.next().filter(|subtag| subtag.len() == 4) {
+            Some(script) => RTL_SCRIPTS
+                .iter()
+                .any(|rtl| rtl.eq_ignore_ascii_case(script)),
+            None => RTL_LANGUAGES
+                .iter()
+                .any(|rtl| rtl.eq_ignore_ascii_case(language)),
+        };
+        if is_rtl {
+            Direction::Rtl
+        } else {
+            Direction::Ltr
+        }
+    }
+
+    /// Return `true` if the direction is right to left.
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Direction::Rtl)
+    }
+
+    /// Return the value of the `dir` HTML attribute for this direction: `"ltr"` or `"rtl"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    /// Return the physical side where the text starts: `"left"` or `"right"`.
+    pub const fn start(self) -> &'static str {
+        match self {
+            Direction::Ltr => "left",
+            Direction::Rtl => "right",
+        }
+    }
+
+    /// Return the physical side where the text ends: `"right"` or `"left"`.
+    pub const fn end(self) -> &'static str {
+        match self {
+            Direction::Ltr => "right",
+            Direction::Rtl => "left",
+        }
+    }
+
+    /// Resolve the classes prefixed with `rtl:` or `ltr:` for this direction.
+    ///
+    /// Classes with the prefix of this direction are kept without the prefix, classes with the other prefix are removed
+    /// and the others are kept as is, so `"ml-4 rtl:mr-4 ltr:pl-2"` becomes `"ml-4 mr-4"` for right to left.
+    pub fn resolve_classes(self, classes: &str) -> String {
+        let (this, other) = match self {
+            Direction::Ltr => ("ltr:", "rtl:"),
+            Direction::Rtl => ("rtl:", "ltr:"),
+        };
+        classes
+            .split_whitespace()
+            .filter(|class| !class.starts_with(other))
+            .map(|class| class.strip_prefix(this).unwrap_or(class))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<T: Locale> I18nContext<T> {
+    /// Return the text direction of the current locale, subscribing to any changes.
+    #[inline]
+    pub fn direction(self) -> Direction {
+        self.get_locale().direction()
+    }
+
+    /// Return a signal of the `dir` HTML attribute for the current locale: `"ltr"` or `"rtl"`.
+    ///
+    /// ```rust, ignore
+    /// view! {
+    ///     <div dir=i18n.dir()>...</div>
+    /// }
+    /// ```
+    pub fn dir(self) -> Signal<&'static str> {
+        Signal::derive(move || self.direction().as_str())
+    }
+
+    /// Return a signal that is `true` when the current locale is written from right to left.
+    pub fn is_rtl(self) -> Signal<bool> {
+        Signal::derive(move || self.direction().is_rtl())
+    }
+
+    /// Return a signal of the physical side where the text starts for the current locale: `"left"` or `"right"`.
+    ///
+    /// Useful for the properties that have no logical equivalent, such as `float` on older browsers or a `transform` origin.
+    pub fn start(self) -> Signal<&'static str> {
+        Signal::derive(move || self.direction().start())
+    }
+
+    /// Return a signal of the physical side where the text ends for the current locale: `"right"` or `"left"`.
+    pub fn end(self) -> Signal<&'static str> {
+        Signal::derive(move || self.direction().end())
+    }
+
+    /// Return a signal of the given classes with the `rtl:` and `ltr:` prefixes resolved for the current locale,
+    /// see [`Direction::resolve_classes`].
+    ///
+    /// ```rust, ignore
+    /// view! {
+    ///     <span class=i18n.dir_classes("icon ltr:ml-2 rtl:mr-2 rtl:flip")>...</span>
+    /// }
+    /// ```
+    pub fn dir_classes(self, classes: &'static str) -> Signal<String> {
+        Signal::derive(move || self.direction().resolve_classes(classes))
+    }
+}
+
+/// Keep the `lang` and `dir` attributes of the `<html>` element in sync with the locale of the context,
+/// on the server and on the client so the page does not flip direction when hydrating.
+///
+/// The `I18nContext` calls it when it is created, the `I18nHtmlAttrs` component generated in the `i18n` module
+/// calls it again for the context of `use_i18n`, and it can be called directly with another context.
+pub fn provide_dir<T: Locale>(i18n: I18nContext<T>) {
+    let lang = move || i18n.get_locale().as_str().to_string();
+    let dir = move || i18n.direction().as_str().to_string();
+    Html(HtmlProps {
+        lang: Some(lang.into()),
+        dir: Some(dir.into()),
+        class: None,
+        attributes: vec![],
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_of() {
+        assert_eq!(Direction::of("en"), Direction::Ltr);
+        assert_eq!(Direction::of("en-US"), Direction::Ltr);
+        assert_eq!(Direction::of("ar"), Direction::Rtl);
+        assert_eq!(Direction::of("he_IL"), Direction::Rtl);
+        assert_eq!(Direction::of("FA-ir"), Direction::Rtl);
+        assert_eq!(Direction::of("az-Arab"), Direction::Rtl);
+        assert_eq!(Direction::of("ks-Deva"), Direction::Ltr);
+        assert_eq!(Direction::of(""), Direction::Ltr);
+    }
+
+    #[test]
+    fn test_resolve_classes() {
+        let classes = "ml-4  rtl:mr-4 ltr:pl-2";
+        assert_eq!(Direction::Ltr.resolve_classes(classes), "ml-4 pl-2");
+        assert_eq!(Direction::Rtl.resolve_classes(classes), "ml-4 mr-4");
+    }
+}
@@ -0,0 +1,367 @@
+//! Translations of the locales other than the default one loaded at runtime, with `load_locales!(dynamic)`.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Display},
+    sync::{Mutex, OnceLock},
+};
+
+use leptos::*;
+
+use crate::{I18nContext, Locale};
+
+/// Keys whose values are loaded at runtime for the locales other than the default one.
+///
+/// Implemented by the `I18nKeys` type generated by `load_locales!(dynamic)`.
+pub trait DynamicKeys: 'static + Send + Sync + Sized {
+    /// The enum of the locales.
+    type Locale: Locale;
+
+    /// The namespaces of the keys, each one loaded from its own file. Empty without namespaces.
+    const NAMESPACES: &'static [&'static str];
+
+    /// The directory the files of the locales are read from outside the browser,
+    /// unless overridden with `set_dynamic_assets_dir`.
+    const ASSETS_DIR: &'static str;
+
+    /// The URL the files of the locales are fetched from in the browser.
+    const ASSETS_URL: &'static str;
+
+    /// Create the keys of the locale, the keys missing in the values keeping the value of the default locale.
+    fn from_dynamic(locale: Self::Locale, values: &DynamicValues) -> Self;
+}
+
+/// Error returned when the translations of a locale could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicLoadError {
+    path: String,
+    message: String,
+}
+
+impl DynamicLoadError {
+    pub(crate) fn new(path: &str, message: impl Display) -> Self {
+        DynamicLoadError {
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Return the path or the URL of the file that could not be loaded.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Display for DynamicLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to load {:?}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for DynamicLoadError {}
+
+/// The values of a locale loaded at runtime, by key path (`key.subkey`, or `namespace::key.subkey`).
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct DynamicValues(HashMap<String, serde_json::Value>);
+
+impl DynamicValues {
+    fn extend(&mut self, prefix: &str, contents: &str) -> Result<(), serde_json::Error> {
+        let values: HashMap<String, serde_json::Value> = serde_json::from_str(contents)?;
+        self.0.extend(
+            values
+                .into_iter()
+                .map(|(path, value)| (format!("{}{}", prefix, path), value)),
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "hot_reload")]
+    pub(crate) fn insert(&mut self, path: String, value: serde_json::Value) {
+        self.0.insert(path, value);
+    }
+
+    fn get(&self, prefix: &str, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(&format!("{}{}", prefix, key))
+    }
+
+    /// Return the string at the path.
+    pub fn string(&self, prefix: &str, key: &str) -> Option<&'static str> {
+        self.get(prefix, key)?.as_str().map(leak_str)
+    }
+
+    /// Return the list of strings at the path.
+    pub fn list(&self, prefix: &str, key: &str) -> Option<&'static [&'static str]> {
+        let items = self.get(prefix, key)?.as_array()?;
+        let items = items
+            .iter()
+            .map(|item| item.as_str().map(leak_str))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Vec::leak(items))
+    }
+
+    /// Return the list of objects at the path, each one created by `new` from a getter of its fields.
+    pub fn object_list<T>(
+        &self,
+        prefix: &str,
+        key: &str,
+        new: impl Fn(&dyn Fn(&str) -> &'static str) -> T,
+    ) -> Option<&'static [T]> {
+        let items = self.get(prefix, key)?.as_array()?;
+        let items = items
+            .iter()
+            .map(|item| {
+                let item = item.as_object()?;
+                let field =
+                    |name: &str| item.get(name).and_then(|v| v.as_str()).map_or("", leak_str);
+                Some(new(&field))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Vec::leak(items))
+    }
+}
+
+/// The values are loaded once per locale and live for the rest of the program, like the ones compiled in.
+fn leak_str(s: &str) -> &'static str {
+    String::leak(s.to_string())
+}
+
+type Registry = HashMap<(TypeId, &'static str), &'static (dyn Any + Send + Sync)>;
+
+fn registry() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+fn get_loaded<K: DynamicKeys>(locale: K::Locale) -> Option<&'static K> {
+    let registry = registry().lock().unwrap_or_else(|err| err.into_inner());
+    let keys = registry.get(&(TypeId::of::<K>(), locale.as_str()))?;
+    keys.downcast_ref()
+}
+
+fn insert_loaded<K: DynamicKeys>(locale: K::Locale, values: &DynamicValues) -> &'static K {
+    let mut registry = registry().lock().unwrap_or_else(|err| err.into_inner());
+    let keys = registry
+        .entry((TypeId::of::<K>(), locale.as_str()))
+        .or_insert_with(|| {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                locale = locale.as_str(),
+                keys = values.0.len(),
+                "locale chunk loaded"
+            );
+            Box::leak(Box::new(K::from_dynamic(locale, values)))
+        });
+    keys.downcast_ref()
+        .expect("keys registered with another type")
+}
+
+/// Return the keys of the locale if they are loaded.
+///
+/// Outside the browser the files are read the first time the keys are needed, so the server renders every locale without loading them first.
+#[doc(hidden)]
+pub fn dynamic_keys<K: DynamicKeys>(locale: K::Locale) -> Option<&'static K> {
+    if let Some(keys) = get_loaded::<K>(locale) {
+        return Some(keys);
+    }
+    if cfg!(any(feature = "hydrate", feature = "csr")) {
+        return None;
+    }
+    match read_values::<K>(locale) {
+        Ok(values) => Some(insert_loaded(locale, &values)),
+        Err(_err) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("{}", _err);
+            None
+        }
+    }
+}
+
+fn assets_dir_override() -> &'static Mutex<Option<String>> {
+    static ASSETS_DIR: OnceLock<Mutex<Option<String>>> = OnceLock::new();
+    ASSETS_DIR.get_or_init(Default::default)
+}
+
+/// Set the directory the files of the locales are read from outside the browser.
+///
+/// By default it is the directory they were written to when compiling, which may not exist where the server is deployed.
+pub fn set_dynamic_assets_dir(dir: impl Into<String>) {
+    *assets_dir_override()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = Some(dir.into());
+}
+
+/// Return the relative paths of the files of the locale, with the prefix of their keys.
+fn files<K: DynamicKeys>(locale: K::Locale) -> Vec<(String, String)> {
+    let locale = locale.as_str();
+    if K::NAMESPACES.is_empty() {
+        vec![(format!("{}.json", locale), String::new())]
+    } else {
+        K::NAMESPACES
+            .iter()
+            .map(|namespace| {
+                (
+                    format!("{}/{}.json", locale, namespace),
+                    format!("{}::", namespace),
+                )
+            })
+            .collect()
+    }
+}
+
+fn read_values<K: DynamicKeys>(locale: K::Locale) -> Result<DynamicValues, DynamicLoadError> {
+    let dir = assets_dir_override()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+        .unwrap_or_else(|| K::ASSETS_DIR.to_string());
+    let mut values = DynamicValues::default();
+    for (file, prefix) in files::<K>(locale) {
+        let path = format!("{}/{}", dir, file);
+        let contents =
+            std::fs::read_to_string(&path).map_err(|err| DynamicLoadError::new(&path, err))?;
+        values
+            .extend(&prefix, &contents)
+            .map_err(|err| DynamicLoadError::new(&path, err))?;
+    }
+    Ok(values)
+}
+
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+async fn fetch_values<K: DynamicKeys>(
+    locale: K::Locale,
+) -> Result<DynamicValues, DynamicLoadError> {
+    let mut values = DynamicValues::default();
+    for (file, prefix) in files::<K>(locale) {
+        let url = format!("{}/{}", K::ASSETS_URL.trim_end_matches('/'), file);
+        let contents = fetch_text(&url).await?;
+        values
+            .extend(&prefix, &contents)
+            .map_err(|err| DynamicLoadError::new(&url, err))?;
+    }
+    Ok(values)
+}
+
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+async fn fetch_text(url: &str) -> Result<String, DynamicLoadError> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let js_err = |err: wasm_bindgen::JsValue| DynamicLoadError::new(url, format!("{:?}", err));
+    let window = web_sys::window().ok_or_else(|| DynamicLoadError::new(url, "no window"))?;
+    let response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(js_err)?;
+    let response: web_sys::Response = response.dyn_into().map_err(js_err)?;
+    if !response.ok() {
+        let message = format!("the server responded with status {}", response.status());
+        return Err(DynamicLoadError::new(url, message));
+    }
+    let text = JsFuture::from(response.text().map_err(js_err)?)
+        .await
+        .map_err(js_err)?;
+    text.as_string()
+        .ok_or_else(|| DynamicLoadError::new(url, "the response is not text"))
+}
+
+/// Load the keys of the locale, fetching their files in the browser and reading them from the disk elsewhere.
+///
+/// Nothing is loaded for the default locale, which is compiled in, or for a locale already loaded.
+pub async fn load_keys<K: DynamicKeys>(locale: K::Locale) -> Result<(), DynamicLoadError> {
+    if locale.as_str() == K::Locale::default().as_str() || get_loaded::<K>(locale).is_some() {
+        return Ok(());
+    }
+    #[cfg(any(feature = "hydrate", feature = "csr"))]
+    let values = fetch_values::<K>(locale).await?;
+    #[cfg(not(any(feature = "hydrate", feature = "csr")))]
+    let values = read_values::<K>(locale)?;
+    insert_loaded::<K>(locale, &values);
+    Ok(())
+}
+
+/// Load the keys of the locale, see `load_keys`.
+pub async fn load_locale<T: Locale>(locale: T) -> Result<(), DynamicLoadError>
+where
+    T::Keys: DynamicKeys<Locale = T>,
+{
+    load_keys::<T::Keys>(locale).await
+}
+
+/// Return `true` if the keys of the locale can be used without loading them first.
+pub fn is_locale_loaded<T: Locale>(locale: T) -> bool
+where
+    T::Keys: DynamicKeys<Locale = T>,
+{
+    locale.as_str() == T::default().as_str()
+        || !cfg!(any(feature = "hydrate", feature = "csr"))
+        || get_loaded::<T::Keys>(locale).is_some()
+}
+
+/// Load the keys of the locale then set it, so the translations are never shown in the default locale in the meantime.
+///
+/// If the keys can't be loaded the locale is still set, with the values of the default locale.
+pub fn set_locale_when_loaded<T: Locale>(i18n: I18nContext<T>, locale: T)
+where
+    T::Keys: DynamicKeys<Locale = T>,
+{
+    spawn_local(async move {
+        if let Err(_err) = load_locale(locale).await {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("{}", _err);
+        }
+        i18n.set_locale(locale);
+    });
+}
+
+/// Create a resource loading the keys of the current locale, to read in a `<Suspense/>` so its children
+/// are rendered once the translations are available:
+///
+/// ```rust, ignore
+/// let loaded = leptos_i18n::create_locale_resource(i18n);
+/// view! {
+///     <Suspense fallback=|| ()>
+///         {move || loaded.get().map(|_| view! { <Home /> })}
+///     </Suspense>
+/// }
+/// ```
+pub fn create_locale_resource<T: Locale + PartialEq>(i18n: I18nContext<T>) -> Resource<T, ()>
+where
+    T::Keys: DynamicKeys<Locale = T>,
+{
+    create_resource(
+        move || i18n.get_locale(),
+        |locale| async move {
+            if let Err(_err) = load_locale(locale).await {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("{}", _err);
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_by_path() {
+        let mut values = DynamicValues::default();
+        values
+            .extend(
+                "ns::",
+                r#"{ "a": "A", "sub.b": "B", "list": ["x", "y"], "objects": [{ "f": "1" }, {}] }"#,
+            )
+            .unwrap();
+        assert_eq!(values.string("ns::", "a"), Some("A"));
+        assert_eq!(values.string("ns::sub.", "b"), Some("B"));
+        assert_eq!(values.string("", "a"), None);
+        assert_eq!(values.list("ns::", "list"), Some(&["x", "y"][..]));
+        assert_eq!(values.list("ns::", "a"), None);
+        assert_eq!(
+            values.object_list("ns::", "objects", |field| field("f")),
+            Some(&["1", ""][..])
+        );
+        assert!(values.extend("", "not json").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use crate::{
+    template::{render_key, TemplateRenderer},
+    Locale,
+};
+
+/// A rendered email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email {
+    /// The subject, rendered as plain text.
+    pub subject: String,
+    /// The body, rendered as HTML.
+    pub html: String,
+    /// The body, rendered as plain text.
+    pub text: String,
+}
+
+type RenderComponent<'a> = Box<dyn Fn(String) -> String + 'a>;
+
+/// Render translations to complete HTML and plain text strings for a given locale, outside of any reactive context.
+///
+/// Meant to render transactional emails on the server with the locale of the recipient, typically from an `emails` namespace:
+///
+/// ```json
+/// {
+///   "welcome": {
+///     "subject": "Welcome {{ name }}!",
+///     "body": "Hello {{ name }}, <link>confirm your email</link> to get started."
+///   }
+/// }
+/// ```
+///
+/// ```rust, ignore
+/// let email = EmailRenderer::new(user.locale)
+///     .var("name", &user.name)
+///     .component("link", |children| format!("<a href=\"{}\">{}</a>", confirm_url, children))
+///     .render("emails::welcome")
+///     .unwrap();
+/// ```
+///
+/// In HTML, the strings and the variables are escaped, and the components are rendered with the function registered for them
+/// or as an HTML element of the same name if none is registered. In plain text, the components are replaced by their children.
+pub struct EmailRenderer<'a, T: Locale> {
+    locale: T,
+    vars: Vec<(&'a str, String)>,
+    components: Vec<(&'a str, RenderComponent<'a>)>,
+}
+
+impl<'a, T: Locale> EmailRenderer<'a, T> {
+    /// Create a renderer for the given locale.
+    pub fn new(locale: T) -> Self {
+        EmailRenderer {
+            locale,
+            vars: vec![],
+            components: vec![],
+        }
+    }
+
+    /// Set the value of a variable.
+    pub fn var(mut self, name: &'a str, value: impl Display) -> Self {
+        self.vars.push((name, value.to_string()));
+        self
+    }
+
+    /// Set the function rendering a component to HTML, it receive the already rendered HTML of the children.
+    pub fn component<F>(mut self, name: &'a str, render: F) -> Self
+    where
+        F: Fn(String) -> String + 'a,
+    {
+        self.components.push((name, Box::new(render)));
+        self
+    }
+
+    fn render_inner(&self, key_path: &str, html: bool) -> Option<String> {
+        render_key(
+            self.locale,
+            key_path,
+            &mut EmailTemplateRenderer { email: self, html },
+        )
+    }
+
+    /// Render the value of the key at the given path to HTML.
+    ///
+    /// Return `None` if the key does not exist or if its value contains plurals.
+    pub fn render_html(&self, key_path: &str) -> Option<String> {
+        self.render_inner(key_path, true)
+    }
+
+    /// Render the value of the key at the given path to plain text.
+    ///
+    /// Return `None` if the key does not exist or if its value contains plurals.
+    pub fn render_text(&self, key_path: &str) -> Option<String> {
+        self.render_inner(key_path, false)
+    }
+
+    /// Render the email at the given path, which must contain a `subject` and a `body` subkey.
+    pub fn render(&self, key_path: &str) -> Option<Email> {
+        let subject = self.render_text(&format!("{}.subject", key_path))?;
+        let body_path = format!("{}.body", key_path);
+        let html = self.render_html(&body_path)?;
+        let text = self.render_text(&body_path)?;
+        Some(Email {
+            subject,
+            html,
+            text,
+        })
+    }
+}
+
+struct EmailTemplateRenderer<'r, 'a, T: Locale> {
+    email: &'r EmailRenderer<'a, T>,
+    html: bool,
+}
+
+impl<T: Locale> TemplateRenderer for EmailTemplateRenderer<'_, '_, T> {
+    fn render_str(&mut self, s: &str, output: &mut String) {
+        if self.html {
+            output.push_str(&escape_html(s))
+        } else {
+            output.push_str(s)
+        }
+    }
+
+    fn get_var(&mut self, name: &str) -> Option<String> {
+        let (_, value) = self.email.vars.iter().find(|(var, _)| *var == name)?;
+        Some(if self.html {
+            escape_html(value)
+        } else {
+            value.clone()
+        })
+    }
+
+    fn render_comp(&mut self, name: &str, children: String) -> Option<String> {
+        if !self.html {
+            return Some(children);
+        }
+        let rendered = match self.email.components.iter().find(|(comp, _)| *comp == name) {
+            Some((_, render)) => render(children),
+            None => format!("<{0}>{1}</{0}>", name, children),
+        };
+        Some(rendered)
+    }
+}
+
+pub(crate) fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,37 @@
+use crate::{email::escape_html, EmailRenderer, Locale};
+
+/// Render a complete HTML error page, such as a 404 or a 500 page, for the given locale.
+///
+/// Error handlers run before the `I18nContext` exists, so the page is rendered from the translations directly.
+/// The key at the given path must contain a `title` and a `message` subkey:
+///
+/// ```json
+/// {
+///   "errors": {
+///     "404": {
+///       "title": "Page not found",
+///       "message": "The page you are looking for does not exist, <link>go back home</link>."
+///     }
+///   }
+/// }
+/// ```
+///
+/// ```rust, ignore
+/// let html = leptos_i18n::render_error_page(locale, "errors.404").unwrap();
+/// ```
+///
+/// The message is rendered like the body of an [`EmailRenderer`], components being rendered as HTML elements of the same name.
+/// With the `actix` or `axum` feature, `error_page_response` negotiates the locale from the failed request and returns the response.
+///
+/// Return `None` if the keys don't exist or if their values contain plurals.
+pub fn render_error_page<T: Locale>(locale: T, key_path: &str) -> Option<String> {
+    let renderer = EmailRenderer::new(locale);
+    let title = renderer.render_text(&format!("{}.title", key_path))?;
+    let message = renderer.render_html(&format!("{}.message", key_path))?;
+    Some(format!(
+        "<!DOCTYPE html><html lang=\"{lang}\" dir=\"{dir}\"><head><meta charset=\"utf-8\"><title>{title}</title></head><body><h1>{title}</h1><p>{message}</p></body></html>",
+        lang = locale.as_str(),
+        dir = locale.direction(),
+        title = escape_html(&title),
+    ))
+}
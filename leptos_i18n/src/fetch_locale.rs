@@ -15,29 +15,23 @@ pub fn fetch_locale<T: Locale>() -> T {
         .unwrap_or_default()
 }
 
-#[cfg(not(any(
-    feature = "ssr",
-    feature = "hydrate",
-    all(feature = "csr", feature = "cookie")
-)))]
+#[cfg(not(any(feature = "ssr", feature = "hydrate")))]
 #[inline]
 pub fn fetch_locale<T: Locale>() -> T {
-    Default::default()
+    fallback_locale()
 }
 
-#[cfg(all(feature = "csr", feature = "cookie"))]
-pub fn fetch_locale<T: Locale>() -> T {
-    fn inner<T: Locale>() -> Option<T> {
-        let document = super::get_html_document()?;
-        let cookies = document.cookie().ok()?;
-        cookies.split(';').find_map(|cookie| {
-            let (key, value) = cookie.split_once('=')?;
-            if key.trim() == super::COOKIE_PREFERED_LANG {
-                T::from_str(value)
-            } else {
-                None
-            }
-        })
-    }
-    inner().unwrap_or_default()
+#[cfg(all(
+    feature = "system_locale",
+    not(any(feature = "ssr", feature = "hydrate"))
+))]
+#[inline]
+fn fallback_locale<T: Locale>() -> T {
+    crate::system_locale::fetch_system_locale()
+}
+
+#[cfg(not(any(feature = "system_locale", feature = "ssr", feature = "hydrate")))]
+#[inline]
+fn fallback_locale<T: Locale>() -> T {
+    Default::default()
 }
@@ -0,0 +1,441 @@
+use std::fmt::{self, Display};
+
+use crate::localized::{fmt_date, fmt_datetime, fmt_time};
+
+/// Numbers that can be interpolated with the `number` and `currency` formatters: `{{ count, number }}`.
+///
+/// Implemented for all the integer and float primitive types, it can be implemented for your own number types.
+pub trait FormatNumber {
+    /// Return the digits of the number, with `.` as decimal separator and a leading `-` when negative.
+    ///
+    /// When `fraction_digits` is given the number must have exactly this number of fraction digits, rounded if needed.
+    fn to_digits(&self, fraction_digits: Option<usize>) -> String;
+}
+
+/// Values that can be interpolated with the `date` and `datetime` formatters: `{{ day, date }}`.
+///
+/// Implemented for `time::Date`, `time::PrimitiveDateTime` and `time::OffsetDateTime` with the `time` feature,
+/// and for `chrono::NaiveDate` and `chrono::NaiveDateTime` with the `chrono` feature,
+/// it can be implemented for the date types of other crates.
+pub trait FormatDate {
+    /// Return the year, the month (1 to 12) and the day of the month.
+    fn to_date(&self) -> (i32, u8, u8);
+}
+
+/// Values that can be interpolated with the `time` and `datetime` formatters: `{{ start, time }}`.
+///
+/// Implemented for `time::Time`, `time::PrimitiveDateTime` and `time::OffsetDateTime` with the `time` feature,
+/// and for `chrono::NaiveTime` and `chrono::NaiveDateTime` with the `chrono` feature,
+/// it can be implemented for the time types of other crates.
+pub trait FormatTime {
+    /// Return the hour (0 to 23) and the minute.
+    fn to_time(&self) -> (u8, u8);
+}
+
+macro_rules! impl_format_integer {
+    ($($t:ty)*) => {
+        $(
+            impl FormatNumber for $t {
+                fn to_digits(&self, fraction_digits: Option<usize>) -> String {
+                    match fraction_digits {
+                        None | Some(0) => self.to_string(),
+                        Some(digits) => format!("{}.{}", self, "0".repeat(digits)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_format_float {
+    ($($t:ty)*) => {
+        $(
+            impl FormatNumber for $t {
+                fn to_digits(&self, fraction_digits: Option<usize>) -> String {
+                    match fraction_digits {
+                        None => self.to_string(),
+                        Some(digits) => format!("{:.*}", digits, self),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_format_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_format_float!(f32 f64);
+
+impl<T: FormatNumber + ?Sized> FormatNumber for &T {
+    fn to_digits(&self, fraction_digits: Option<usize>) -> String {
+        T::to_digits(self, fraction_digits)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatDate for time::Date {
+    fn to_date(&self) -> (i32, u8, u8) {
+        (self.year(), u8::from(self.month()), self.day())
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatTime for time::Time {
+    fn to_time(&self) -> (u8, u8) {
+        (self.hour(), self.minute())
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatDate for time::PrimitiveDateTime {
+    fn to_date(&self) -> (i32, u8, u8) {
+        self.date().to_date()
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatTime for time::PrimitiveDateTime {
+    fn to_time(&self) -> (u8, u8) {
+        self.time().to_time()
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatDate for time::OffsetDateTime {
+    fn to_date(&self) -> (i32, u8, u8) {
+        self.date().to_date()
+    }
+}
+
+#[cfg(feature = "time")]
+impl FormatTime for time::OffsetDateTime {
+    fn to_time(&self) -> (u8, u8) {
+        self.time().to_time()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FormatDate for chrono::NaiveDate {
+    fn to_date(&self) -> (i32, u8, u8) {
+        use chrono::Datelike;
+        (self.year(), self.month() as u8, self.day() as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FormatTime for chrono::NaiveTime {
+    fn to_time(&self) -> (u8, u8) {
+        use chrono::Timelike;
+        (self.hour() as u8, self.minute() as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FormatDate for chrono::NaiveDateTime {
+    fn to_date(&self) -> (i32, u8, u8) {
+        self.date().to_date()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FormatTime for chrono::NaiveDateTime {
+    fn to_time(&self) -> (u8, u8) {
+        self.time().to_time()
+    }
+}
+
+/// Placeholder value of the formatted variables, used by the code generated with the `debug_interpolations` feature
+/// and to render the dates and times with `TestI18n::render_keys`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct EmptyFormatValue;
+
+impl FormatNumber for EmptyFormatValue {
+    fn to_digits(&self, _fraction_digits: Option<usize>) -> String {
+        String::new()
+    }
+}
+
+impl FormatDate for EmptyFormatValue {
+    fn to_date(&self) -> (i32, u8, u8) {
+        (1970, 1, 1)
+    }
+}
+
+impl FormatTime for EmptyFormatValue {
+    fn to_time(&self) -> (u8, u8) {
+        (0, 0)
+    }
+}
+
+/// Separators of the numbers of a locale, given by the code generated by the `load_locales!` macro
+/// to format the numbers when the `icu` feature is disabled or ICU4X has no data for the locale.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct NumberSymbols {
+    pub group: &'static str,
+    pub decimal: &'static str,
+    /// Minimum number of digits before the first group separator, `2` to write `1234` but `12 345`.
+    pub min_grouping: usize,
+}
+
+impl NumberSymbols {
+    /// Write the digits of a number with the separators of the locale, return if the number is negative.
+    fn write_digits(&self, digits: &str, output: &mut String) -> bool {
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, digits),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+        // "NaN" and "inf" are written as is.
+        if integer.bytes().all(|b| b.is_ascii_digit()) && integer.len() >= 3 + self.min_grouping {
+            let first_group = match integer.len() % 3 {
+                0 => 3,
+                len => len,
+            };
+            output.push_str(&integer[..first_group]);
+            for group in integer.as_bytes()[first_group..].chunks(3) {
+                output.push_str(self.group);
+                // the integer part only contains ASCII digits.
+                output.extend(group.iter().map(|b| *b as char));
+            }
+        } else {
+            output.push_str(integer);
+        }
+        if let Some(fraction) = fraction {
+            output.push_str(self.decimal);
+            output.push_str(fraction);
+        }
+        negative
+    }
+}
+
+/// Number of fraction digits of the currency with the given ISO 4217 code.
+fn currency_fraction_digits(code: &str) -> usize {
+    match code {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// Symbol of the currency with the given ISO 4217 code when the `icu` feature is disabled, the code itself for the less common ones.
+fn currency_symbol(code: &str) -> &str {
+    match code {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "RUB" => "₽",
+        "BRL" => "R$",
+        code => code,
+    }
+}
+
+/// Format the number for the locale, with ICU4X when the `icu` feature is enabled and the separators of the locale otherwise.
+#[cfg_attr(not(feature = "icu"), allow(unused_variables))]
+pub(crate) fn format_number<N: FormatNumber + ?Sized>(
+    number: &N,
+    locale: &str,
+    symbols: &NumberSymbols,
+) -> String {
+    let digits = number.to_digits(None);
+    #[cfg(feature = "icu")]
+    if let Some(formatted) = crate::icu::format_decimal(&digits, locale) {
+        return formatted;
+    }
+    let mut output = String::new();
+    let mut formatted = String::new();
+    if symbols.write_digits(&digits, &mut formatted) {
+        output.push('-');
+    }
+    output.push_str(&formatted);
+    output
+}
+
+/// Format the amount in the given currency for the locale, with ICU4X when the `icu` feature is enabled.
+///
+/// Otherwise the placement of the symbol is approximated: before the number in English, Japanese, Chinese, Korean, Hindi,
+/// Thai, Dutch and Brazilian Portuguese, and after it for the other languages.
+pub(crate) fn format_currency<N: FormatNumber + ?Sized>(
+    amount: &N,
+    currency: &str,
+    locale: &str,
+    symbols: &NumberSymbols,
+) -> String {
+    let digits = amount.to_digits(Some(currency_fraction_digits(currency)));
+    #[cfg(feature = "icu")]
+    if let Some(formatted) = crate::icu::format_currency(&digits, currency, locale) {
+        return formatted;
+    }
+    let symbol = currency_symbol(currency);
+    let mut formatted = String::new();
+    let negative = symbols.write_digits(&digits, &mut formatted);
+    let sign = if negative { "-" } else { "" };
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    let region = subtags.find(|subtag| subtag.len() == 2 || subtag.len() == 3);
+    // ISO codes are always separated from the number.
+    let separator = if symbol.chars().all(|c| c.is_ascii_alphabetic()) {
+        "\u{a0}"
+    } else {
+        ""
+    };
+    match (language, region) {
+        ("en" | "ja" | "zh" | "ko" | "hi" | "th", _) => {
+            format!("{}{}{}{}", sign, symbol, separator, formatted)
+        }
+        ("nl", _) | ("pt", None | Some("BR")) => {
+            format!("{}\u{a0}{}{}", symbol, sign, formatted)
+        }
+        _ => format!("{}{}\u{a0}{}", sign, formatted, symbol),
+    }
+}
+
+struct FormatWith<F>(F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> Display for FormatWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Functions returning a number, the values of the variables interpolated with the `number` and `currency` formatters.
+#[doc(hidden)]
+pub trait NumberFn: Clone + 'static {
+    fn format_number(&self, locale: &str, symbols: &NumberSymbols) -> String;
+    fn format_currency(&self, currency: &str, locale: &str, symbols: &NumberSymbols) -> String;
+}
+
+impl<F: Fn() -> N + Clone + 'static, N: FormatNumber> NumberFn for F {
+    fn format_number(&self, locale: &str, symbols: &NumberSymbols) -> String {
+        format_number(&self(), locale, symbols)
+    }
+
+    fn format_currency(&self, currency: &str, locale: &str, symbols: &NumberSymbols) -> String {
+        format_currency(&self(), currency, locale, symbols)
+    }
+}
+
+/// Functions returning a date, the values of the variables interpolated with the `date` formatter.
+#[doc(hidden)]
+pub trait DateFn: Clone + 'static {
+    fn format_date(&self, locale: &str) -> String;
+}
+
+impl<F: Fn() -> D + Clone + 'static, D: FormatDate> DateFn for F {
+    fn format_date(&self, locale: &str) -> String {
+        let date = self().to_date();
+        FormatWith(|f: &mut fmt::Formatter<'_>| fmt_date(date, locale, f)).to_string()
+    }
+}
+
+/// Functions returning a time, the values of the variables interpolated with the `time` formatter.
+#[doc(hidden)]
+pub trait TimeFn: Clone + 'static {
+    fn format_time(&self, locale: &str) -> String;
+}
+
+impl<F: Fn() -> T + Clone + 'static, T: FormatTime> TimeFn for F {
+    fn format_time(&self, locale: &str) -> String {
+        let time = self().to_time();
+        FormatWith(|f: &mut fmt::Formatter<'_>| fmt_time(time, locale, f)).to_string()
+    }
+}
+
+/// Functions returning a date and a time, the values of the variables interpolated with the `datetime` formatter.
+#[doc(hidden)]
+pub trait DateTimeFn: Clone + 'static {
+    fn format_datetime(&self, locale: &str) -> String;
+}
+
+impl<F: Fn() -> T + Clone + 'static, T: FormatDate + FormatTime> DateTimeFn for F {
+    fn format_datetime(&self, locale: &str) -> String {
+        let value = self();
+        let (date, time) = (value.to_date(), value.to_time());
+        FormatWith(|f: &mut fmt::Formatter<'_>| fmt_datetime(date, time, locale, f)).to_string()
+    }
+}
+
+#[cfg(all(test, not(feature = "icu")))]
+mod tests {
+    use super::*;
+
+    const EN: NumberSymbols = NumberSymbols {
+        group: ",",
+        decimal: ".",
+        min_grouping: 1,
+    };
+    const FR: NumberSymbols = NumberSymbols {
+        group: "\u{202f}",
+        decimal: ",",
+        min_grouping: 1,
+    };
+    const DE: NumberSymbols = NumberSymbols {
+        group: ".",
+        decimal: ",",
+        min_grouping: 1,
+    };
+    const ES: NumberSymbols = NumberSymbols {
+        group: ".",
+        decimal: ",",
+        min_grouping: 2,
+    };
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(&1234567, "en", &EN), "1,234,567");
+        assert_eq!(format_number(&-1234.5, "en-US", &EN), "-1,234.5");
+        assert_eq!(
+            format_number(&1234567.25, "fr", &FR),
+            "1\u{202f}234\u{202f}567,25"
+        );
+        assert_eq!(format_number(&1234, "de", &DE), "1.234");
+        assert_eq!(format_number(&1234, "es", &ES), "1234");
+        assert_eq!(format_number(&12345, "es", &ES), "12.345");
+        assert_eq!(format_number(&123, "de", &DE), "123");
+        assert_eq!(format_number(&f64::NAN, "fr", &FR), "NaN");
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(format_currency(&1234.5, "USD", "en", &EN), "$1,234.50");
+        assert_eq!(format_currency(&-3, "EUR", "en", &EN), "-€3.00");
+        assert_eq!(
+            format_currency(&1234.5, "EUR", "fr", &FR),
+            "1\u{202f}234,50\u{a0}€"
+        );
+        assert_eq!(
+            format_currency(&1234.5, "EUR", "nl", &DE),
+            "€\u{a0}1.234,50"
+        );
+        assert_eq!(format_currency(&1500, "JPY", "ja", &EN), "¥1,500");
+        assert_eq!(format_currency(&10, "CHF", "en", &EN), "CHF\u{a0}10.00");
+        assert_eq!(format_currency(&1.5, "KWD", "en", &EN), "KWD\u{a0}1.500");
+    }
+
+    #[test]
+    fn test_format_date_fn() {
+        struct Day;
+        impl FormatDate for Day {
+            fn to_date(&self) -> (i32, u8, u8) {
+                (2023, 12, 1)
+            }
+        }
+        impl FormatTime for Day {
+            fn to_time(&self) -> (u8, u8) {
+                (13, 5)
+            }
+        }
+        let day = || Day;
+        assert_eq!(day.format_date("en"), "12/1/2023");
+        assert_eq!(day.format_time("fr"), "13:05");
+        assert_eq!(day.format_datetime("de"), "01.12.2023 13:05");
+    }
+}
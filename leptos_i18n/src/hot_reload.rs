@@ -0,0 +1,222 @@
+//! Translations read again from the locale files when they are edited, with the `hot_reload` feature in debug builds.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    path::Path,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{dynamic::DynamicValues, DynamicLoadError, Locale};
+
+/// Keys whose values are read again from the locale files when they are modified after the build.
+///
+/// Implemented by the `I18nKeys` type generated by `load_locales!()` in debug builds with the `hot_reload` feature.
+#[doc(hidden)]
+pub trait HotReloadKeys: 'static + Send + Sync + Sized {
+    /// The enum of the locales.
+    type Locale: Locale;
+
+    /// The namespaces of the keys, each one read from its own file. Empty without namespaces.
+    const NAMESPACES: &'static [&'static str];
+
+    /// The absolute path of the directory of the locale files.
+    const LOCALES_DIR: &'static str;
+
+    /// The extension of the locale files.
+    const EXTENSION: &'static str;
+
+    /// When the locale files were read by the macro, in nanoseconds since the Unix epoch.
+    const BUILD_TIME: u64;
+
+    /// Create the keys of the locale, the keys missing in the values keeping their compiled value.
+    fn from_dynamic(locale: Self::Locale, values: &DynamicValues) -> Self;
+}
+
+/// The files are checked at most once per interval, as the keys are needed for every translation.
+const CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+struct Reloaded {
+    checked_at: Instant,
+    modified: Vec<Option<u64>>,
+    keys: Option<&'static (dyn Any + Send + Sync)>,
+}
+
+type Registry = HashMap<(TypeId, &'static str), Reloaded>;
+
+fn registry() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Return the keys of the locale read from its files if one of them was modified after the build,
+/// `None` meaning the compiled keys are up to date.
+///
+/// The files are never read in the browser, where the compiled keys are always used.
+/// The keys read are leaked, as the compiled ones live for the rest of the program.
+#[doc(hidden)]
+pub fn hot_reload_keys<K: HotReloadKeys>(locale: K::Locale) -> Option<&'static K> {
+    if cfg!(any(feature = "hydrate", feature = "csr")) {
+        return None;
+    }
+    let mut registry = registry().lock().unwrap_or_else(|err| err.into_inner());
+    let entry = registry.entry((TypeId::of::<K>(), locale.as_str()));
+    let now = Instant::now();
+    let reloaded = match entry {
+        std::collections::hash_map::Entry::Occupied(entry)
+            if now.duration_since(entry.get().checked_at) < CHECK_INTERVAL =>
+        {
+            entry.into_mut()
+        }
+        entry => {
+            let files = files::<K>(locale);
+            let modified = files
+                .iter()
+                .map(|(path, _)| modified_time(Path::new(path)))
+                .collect::<Vec<_>>();
+            let reloaded = entry.or_insert_with(|| Reloaded {
+                checked_at: now,
+                modified: vec![None; files.len()],
+                keys: None,
+            });
+            reloaded.checked_at = now;
+            let edited = modified.iter().flatten().any(|&time| time > K::BUILD_TIME);
+            if !edited {
+                reloaded.keys = None;
+            } else if reloaded.keys.is_none() || reloaded.modified != modified {
+                match read_values::<K>(&files) {
+                    Ok(values) => {
+                        let keys: &'static K =
+                            Box::leak(Box::new(K::from_dynamic(locale, &values)));
+                        reloaded.keys = Some(keys);
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(locale = locale.as_str(), "locale files reloaded");
+                    }
+                    Err(_err) => {
+                        // the last values read stay in use until the files are fixed.
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("{}", _err);
+                    }
+                }
+            }
+            reloaded.modified = modified;
+            reloaded
+        }
+    };
+    reloaded.keys?.downcast_ref()
+}
+
+fn modified_time(path: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let since_epoch = modified.duration_since(SystemTime::UNIX_EPOCH).ok()?;
+    u64::try_from(since_epoch.as_nanos()).ok()
+}
+
+/// Return the paths of the files of the locale, with the prefix of their keys.
+fn files<K: HotReloadKeys>(locale: K::Locale) -> Vec<(String, String)> {
+    let locale = locale.as_str();
+    let dir = K::LOCALES_DIR;
+    let ext = K::EXTENSION;
+    if K::NAMESPACES.is_empty() {
+        vec![(format!("{}/{}.{}", dir, locale, ext), String::new())]
+    } else {
+        K::NAMESPACES
+            .iter()
+            .map(|namespace| {
+                (
+                    format!("{}/{}/{}.{}", dir, locale, namespace, ext),
+                    format!("{}::", namespace),
+                )
+            })
+            .collect()
+    }
+}
+
+fn read_values<K: HotReloadKeys>(
+    files: &[(String, String)],
+) -> Result<DynamicValues, DynamicLoadError> {
+    let mut values = DynamicValues::default();
+    for (path, prefix) in files {
+        // a namespace missing for the locale keeps the values of the default locale, as when compiling.
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let value =
+            parse_file(K::EXTENSION, &contents).map_err(|err| DynamicLoadError::new(path, err))?;
+        flatten_values(&mut values, prefix, value);
+    }
+    Ok(values)
+}
+
+fn parse_file(ext: &str, contents: &str) -> Result<serde_json::Value, String> {
+    match ext {
+        "json" => serde_json::from_str(contents).map_err(|err| err.to_string()),
+        "yaml" => serde_yaml::from_str(contents).map_err(|err| err.to_string()),
+        "toml" => toml::from_str(contents).map_err(|err| err.to_string()),
+        _ => Err(format!("hot reloading of .{} files is not supported", ext)),
+    }
+}
+
+/// Insert the values of the file by key path (`key.subkey`), the objects being subkeys.
+///
+/// The strings needing to be parsed, with interpolations or foreign keys, are skipped and keep their compiled value.
+fn flatten_values(values: &mut DynamicValues, path: &str, value: serde_json::Value) {
+    match value {
+        serde_json::Value::Object(subkeys) => {
+            let separator = if path.is_empty() || path.ends_with("::") {
+                ""
+            } else {
+                "."
+            };
+            for (key, value) in subkeys {
+                flatten_values(values, &format!("{}{}{}", path, separator, key), value);
+            }
+        }
+        serde_json::Value::String(s) if needs_parsing(&s) => {}
+        serde_json::Value::Array(items)
+            if items.iter().any(|item| match item {
+                serde_json::Value::String(s) => needs_parsing(s),
+                serde_json::Value::Object(fields) => fields
+                    .values()
+                    .filter_map(|v| v.as_str())
+                    .any(needs_parsing),
+                _ => false,
+            }) => {}
+        value => values.insert(path.to_string(), value),
+    }
+}
+
+fn needs_parsing(s: &str) -> bool {
+    s.contains("{{") || s.contains("$t(")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_subkeys() {
+        let mut values = DynamicValues::default();
+        let file = parse_file(
+            "yaml",
+            "a: A\nsub:\n  b: B\n  c: \"{{ count }} items\"\nlist: [x, y]\nforeign: $t(a)\n",
+        )
+        .unwrap();
+        flatten_values(&mut values, "ns::", file);
+        assert_eq!(values.string("ns::", "a"), Some("A"));
+        assert_eq!(values.string("ns::sub.", "b"), Some("B"));
+        assert_eq!(values.string("ns::sub.", "c"), None);
+        assert_eq!(values.string("ns::", "foreign"), None);
+        assert_eq!(values.list("ns::", "list"), Some(&["x", "y"][..]));
+
+        let mut values = DynamicValues::default();
+        let file = parse_file("toml", "a = \"A\"\n[sub]\nb = \"B\"\n").unwrap();
+        flatten_values(&mut values, "", file);
+        assert_eq!(values.string("", "a"), Some("A"));
+        assert_eq!(values.string("sub.", "b"), Some("B"));
+
+        assert!(parse_file("json", "not json").is_err());
+        assert!(parse_file("ftl", "a = A").is_err());
+    }
+}
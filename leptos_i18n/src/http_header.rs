@@ -0,0 +1,45 @@
+use http::HeaderValue;
+
+use crate::{parse_accept_language, Locale};
+
+/// Create a `Content-Language` header value for the given locale.
+pub fn content_language_header<T: Locale>(locale: T) -> HeaderValue {
+    HeaderValue::from_static(locale.as_str())
+}
+
+/// Create an `Accept-Language` header value from locales sorted in preferred order, with decreasing quality values (`fr, en;q=0.9`).
+pub fn accept_language_header<T: Locale>(locales: &[T]) -> HeaderValue {
+    let mut value = String::new();
+    for (i, locale) in locales.iter().enumerate() {
+        if i > 0 {
+            value.push_str(", ");
+        }
+        value.push_str(locale.as_str());
+        if i > 0 {
+            let q = 10usize.saturating_sub(i).max(1);
+            value.push_str(&format!(";q=0.{}", q));
+        }
+    }
+    // locales tags and quality values are always valid header characters.
+    HeaderValue::from_str(&value).expect("invalid Accept-Language header value")
+}
+
+/// Parse an `Accept-Language` header value, returning the languages sorted in preferred order.
+///
+/// Return an empty list if the value is not valid UTF-8.
+pub fn parse_accept_language_header(value: &HeaderValue) -> Vec<String> {
+    value
+        .to_str()
+        .map(parse_accept_language)
+        .unwrap_or_default()
+}
+
+/// Return the locale that match the best an `Accept-Language` header value, or the default locale if none match.
+pub fn locale_from_accept_language_header<T: Locale>(value: &HeaderValue) -> T {
+    T::find_locale(&parse_accept_language_header(value))
+}
+
+/// Return the first locale of a `Content-Language` header value, if any match.
+pub fn locale_from_content_language_header<T: Locale>(value: &HeaderValue) -> Option<T> {
+    value.to_str().ok()?.split(',').find_map(T::from_str)
+}
@@ -0,0 +1,153 @@
+use fixed_decimal::Decimal;
+use icu_datetime::{
+    fieldsets,
+    input::{Date, DateTime, Time},
+    options::{TimePrecision, YearStyle},
+    DateTimeFormatter, NoCalendarFormatter,
+};
+use icu_decimal::DecimalFormatter;
+use icu_experimental::dimension::currency::{formatter::CurrencyFormatter, CurrencyCode};
+use icu_locale_core::{LanguageIdentifier, Locale};
+use tinystr::TinyAsciiStr;
+
+/// Parse the tag of a locale declared in the configuration to an `icu_locale_core::Locale`.
+pub fn to_icu_locale(tag: &str) -> Locale {
+    Locale::try_from_str(tag)
+        .unwrap_or_else(|err| panic!("locale {:?} is not a valid BCP 47 locale: {}", tag, err))
+}
+
+/// Parse the tag of a locale declared in the configuration to an `icu_locale_core::LanguageIdentifier`.
+pub fn to_icu_language_identifier(tag: &str) -> LanguageIdentifier {
+    LanguageIdentifier::try_from_str(tag).unwrap_or_else(|err| {
+        panic!(
+            "locale {:?} is not a valid BCP 47 language identifier: {}",
+            tag, err
+        )
+    })
+}
+
+/// Format the digits of a number (`-1234.5`) with the decimal formatter of the locale,
+/// `None` if they are not a finite number or the tag is not valid.
+pub(crate) fn format_decimal(digits: &str, locale: &str) -> Option<String> {
+    let locale = Locale::try_from_str(locale).ok()?;
+    let decimal = digits.parse::<Decimal>().ok()?;
+    let formatter = DecimalFormatter::try_new(locale.into(), Default::default()).ok()?;
+    Some(formatter.format(&decimal).to_string())
+}
+
+/// Format the digits of an amount in the currency with the given ISO 4217 code for the locale,
+/// `None` if they are not a finite number or the tag is not valid.
+pub(crate) fn format_currency(digits: &str, currency: &str, locale: &str) -> Option<String> {
+    let locale = Locale::try_from_str(locale).ok()?;
+    let code = CurrencyCode(TinyAsciiStr::try_from_str(currency).ok()?);
+    // the currency formatter does not apply the negative pattern of the locale, so the sign is written before the amount.
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits),
+    };
+    let amount = digits.parse::<Decimal>().ok()?;
+    let formatter = CurrencyFormatter::try_new(locale.into(), Default::default()).ok()?;
+    Some(format!(
+        "{}{}",
+        sign,
+        formatter.format_fixed_decimal(&amount, &code)
+    ))
+}
+
+fn to_icu_date((year, month, day): (i32, u8, u8)) -> Option<Date<icu_calendar::Iso>> {
+    Date::try_new_iso(year, month, day).ok()
+}
+
+fn to_icu_time((hour, minute): (u8, u8)) -> Option<Time> {
+    Time::try_new(hour, minute, 0, 0).ok()
+}
+
+/// Format the date with the short date format of the locale, with the full year.
+pub(crate) fn format_date(date: (i32, u8, u8), locale: &str) -> Option<String> {
+    let locale = Locale::try_from_str(locale).ok()?;
+    let date = to_icu_date(date)?;
+    let fieldset = fieldsets::YMD::short().with_year_style(YearStyle::Full);
+    let formatter = DateTimeFormatter::try_new(locale.into(), fieldset).ok()?;
+    Some(formatter.format(&date).to_string())
+}
+
+/// Format the time with the hours and minutes format of the locale.
+pub(crate) fn format_time(time: (u8, u8), locale: &str) -> Option<String> {
+    let locale = Locale::try_from_str(locale).ok()?;
+    let time = to_icu_time(time)?;
+    let fieldset = fieldsets::T::short().with_time_precision(TimePrecision::Minute);
+    let formatter = NoCalendarFormatter::try_new(locale.into(), fieldset).ok()?;
+    Some(formatter.format(&time).to_string())
+}
+
+/// Format the date and the time with the short date and time format of the locale.
+pub(crate) fn format_datetime(date: (i32, u8, u8), time: (u8, u8), locale: &str) -> Option<String> {
+    let locale = Locale::try_from_str(locale).ok()?;
+    let datetime = DateTime {
+        date: to_icu_date(date)?,
+        time: to_icu_time(time)?,
+    };
+    let fieldset = fieldsets::YMDT::short()
+        .with_year_style(YearStyle::Full)
+        .with_time_precision(TimePrecision::Minute);
+    let formatter = DateTimeFormatter::try_new(locale.into(), fieldset).ok()?;
+    Some(formatter.format(&datetime).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tags() {
+        assert_eq!(to_icu_locale("en-GB").to_string(), "en-GB");
+        assert_eq!(to_icu_locale("fr").to_string(), "fr");
+        let langid = to_icu_language_identifier("zh-Hant-TW");
+        assert_eq!(
+            langid.script.map(|script| script.to_string()).as_deref(),
+            Some("Hant")
+        );
+    }
+
+    #[test]
+    fn format_numbers() {
+        assert_eq!(format_decimal("1234567.25", "en").unwrap(), "1,234,567.25");
+        assert_eq!(
+            format_decimal("-1234567", "fr").unwrap(),
+            "-1\u{202f}234\u{202f}567"
+        );
+        assert_eq!(format_decimal("1234567", "de-CH").unwrap(), "1'234'567");
+        assert_eq!(format_decimal("1234567", "hi").unwrap(), "12,34,567");
+        assert_eq!(format_decimal("1234", "es").unwrap(), "1234");
+        assert_eq!(format_decimal("NaN", "en"), None);
+    }
+
+    #[test]
+    fn format_currencies() {
+        assert_eq!(
+            format_currency("1234.50", "USD", "en").unwrap(),
+            "$1,234.50"
+        );
+        assert_eq!(format_currency("-3.00", "EUR", "en").unwrap(), "-€3.00");
+        assert_eq!(
+            format_currency("1234.50", "EUR", "fr").unwrap(),
+            "1\u{202f}234,50\u{a0}€"
+        );
+        assert_eq!(format_currency("1500", "JPY", "ja").unwrap(), "￥1,500");
+        assert_eq!(format_currency("10", "CHF", "en").unwrap(), "CHF\u{a0}10");
+        assert_eq!(format_currency("10", "EURO", "en"), None);
+    }
+
+    #[test]
+    fn format_dates() {
+        assert_eq!(format_date((2023, 12, 1), "en").unwrap(), "12/1/2023");
+        assert_eq!(format_date((2023, 12, 1), "de").unwrap(), "01.12.2023");
+        assert_eq!(format_time((13, 5), "fr").unwrap(), "13:05");
+        assert_eq!(format_time((13, 5), "en").unwrap(), "1:05\u{202f}PM");
+        assert_eq!(
+            format_datetime((2023, 12, 1), (13, 5), "ja").unwrap(),
+            "2023/12/01 13:05"
+        );
+        assert_eq!(format_date((2023, 2, 30), "en"), None);
+    }
+}
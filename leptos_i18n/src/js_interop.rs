@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{I18nContext, Locale, LocaleKeys};
+
+type GetString = Box<dyn Fn(&str) -> Option<&'static str>>;
+
+struct JsContext {
+    get_locale: Box<dyn Fn() -> &'static str>,
+    set_locale: Box<dyn Fn(&str) -> bool>,
+    get_string: GetString,
+}
+
+thread_local! {
+    static JS_CONTEXT: RefCell<Option<JsContext>> = const { RefCell::new(None) };
+    static LISTENERS: RefCell<Vec<js_sys::Function>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Make the given context the one used by the functions exported to JavaScript.
+pub(crate) fn register_context<T: Locale>(context: I18nContext<T>) {
+    let js_context = JsContext {
+        get_locale: Box::new(move || context.get_locale_untracked().as_str()),
+        set_locale: Box::new(move |tag| match T::from_str(tag) {
+            Some(locale) => {
+                context.set_locale(locale);
+                true
+            }
+            None => false,
+        }),
+        get_string: Box::new(move |key_path| context.get_keys_untracked().get_string(key_path)),
+    };
+    JS_CONTEXT.with(|cell| *cell.borrow_mut() = Some(js_context));
+}
+
+/// Notify the JavaScript listeners that the locale changed.
+pub(crate) fn notify_locale_change(locale: &str) {
+    let locale = JsValue::from_str(locale);
+    LISTENERS.with(|listeners| {
+        for listener in listeners.borrow().iter() {
+            let _ = listener.call1(&JsValue::NULL, &locale);
+        }
+    });
+}
+
+fn with_context<U>(f: impl FnOnce(&JsContext) -> U) -> Option<U> {
+    JS_CONTEXT.with(|cell| cell.borrow().as_ref().map(f))
+}
+
+/// Return the current locale, or `undefined` if the i18n context is not yet provided.
+#[wasm_bindgen]
+pub fn get_locale() -> Option<String> {
+    with_context(|context| (context.get_locale)().to_string())
+}
+
+/// Set the current locale, return `false` if the tag does not match any locale or if the i18n context is not yet provided.
+#[wasm_bindgen]
+pub fn set_locale(tag: &str) -> bool {
+    with_context(|context| (context.set_locale)(tag)).unwrap_or(false)
+}
+
+/// Return the translation of the string key at the given path for the current locale, or `undefined` if it does not exist.
+#[wasm_bindgen]
+pub fn t(key: &str) -> Option<String> {
+    with_context(|context| (context.get_string)(key))
+        .flatten()
+        .map(str::to_string)
+}
+
+/// Register a callback called with the new locale each time the locale changes.
+#[wasm_bindgen]
+pub fn on_locale_change(callback: js_sys::Function) {
+    LISTENERS.with(|listeners| listeners.borrow_mut().push(callback));
+}
@@ -17,15 +17,15 @@
 //! If you want to see what Leptos i18n is capable of, check out
 //! the [examples](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples):
 //! - [`hello_world_actix`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_actix) is a simple example
-//!     to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
+//!   to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
 //! - [`hello_world_axum`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_axum) is like the actix hello world example
-//!     but uses axum as the backend, it showcases that the code you will write with this library will be the same using actix or axum as a backend.
+//!   but uses axum as the backend, it showcases that the code you will write with this library will be the same using actix or axum as a backend.
 //! - [`counter`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter) is the classic
-//!     counter example, showing how you can interpolate values in the translations and switch locale without full reload.
+//!   counter example, showing how you can interpolate values in the translations and switch locale without full reload.
 //! - [`counter_plurals`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter_plurals) is like the `counter` example
-//!     but show how you can use plurals to display different texts based on a count.
+//!   but show how you can use plurals to display different texts based on a count.
 //! - [`namespaces`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/namespaces) show how to break down your translations
-//!     in multiple namespaces to avoid big files
+//!   in multiple namespaces to avoid big files
 //!
 //!
 //! Details on how to run each example can be found in its README.
@@ -38,6 +38,18 @@
 //! - `debug_interpolations`: Enable the macros to generate code to emit a warning if a key is supplied twice in interpolations and a better compilation error when a key is missing.
 //! - `cookie` (*Default*): Enable this feature to set a cookie on the client to remember the last locale set.
 //! - `suppress_key_warnings`: Disable the warning emission of the `load_locales!()` macro when some keys are missing or ignored.
+//! - `system_locale`: When not running in the browser or on the server (desktop apps, Tauri), initialize the locale from the system locale.
+//! - `wasm-bindgen`: Export `get_locale`, `set_locale`, `t` and `on_locale_change` to JavaScript on the client to keep it in sync with the current locale.
+//! - `http`: Add functions to build and parse `Content-Language` and `Accept-Language` header values of the `http` crate, enabled by the `actix` and `axum` features.
+//! - `router`: Add `use_locale_param` to keep the locale in sync with a route param of `leptos_router`, and `I18nRoute` to mount routes under a `/:locale` segment.
+//! - `time`: Implement `LocaleFormat` for the `time` crate date and time types, to display them for the current locale with `Localized`,
+//!   and `FormatDate`/`FormatTime` to interpolate them with the `date`, `time` and `datetime` formatters.
+//! - `chrono`: Same as `time` for the `NaiveDate`, `NaiveTime` and `NaiveDateTime` types of the `chrono` crate.
+//! - `icu`: Implement `From<Locale>` for the `Locale` and `LanguageIdentifier` types of `icu_locale_core`, to use the locales with ICU4X.
+//! - `hot_reload`: In debug builds, read the locale files again when they are edited after the build so the server renders the new translations without recompiling.
+//!   Release builds keep only the compiled translations.
+//! - `snapshot`: Add `Locale::snapshot` returning every key of a locale rendered to text, for snapshot tests. Enable it only for tests, as the snapshots are compiled in the binary.
+//! - `tracing`: Emit `tracing` events and spans when the locale is negotiated or changed and when a missing key is resolved.
 //!
 //! # A Simple Counter
 //!
@@ -116,27 +128,156 @@
 //! }
 //! ```
 
+mod accept_language;
+mod announcer;
 mod context;
+mod direction;
+#[cfg(feature = "dynamic_load")]
+mod dynamic;
+mod email;
+mod error_page;
 mod fetch_locale;
+mod formatting;
+#[cfg(feature = "hot_reload")]
+mod hot_reload;
+#[cfg(feature = "http")]
+mod http_header;
+#[cfg(feature = "icu")]
+mod icu;
+#[cfg(all(feature = "wasm-bindgen", any(feature = "hydrate", feature = "csr")))]
+mod js_interop;
+mod locale_override;
+#[cfg(feature = "serde")]
+mod locale_serde;
+mod locale_tag;
 mod locale_traits;
+mod localize;
+mod localized;
+mod localized_error;
+mod missing_keys;
+mod negotiation;
+mod plural_operands;
+mod requested_locales;
+#[cfg(feature = "router")]
+mod router;
+mod scope;
+mod select;
 #[cfg(feature = "ssr")]
 mod server;
+mod shard;
+mod static_paths;
+mod storage;
+#[cfg(feature = "system_locale")]
+mod system_locale;
+mod template;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
+mod translation;
 
 pub use locale_traits::*;
 
-pub use context::{provide_i18n_context, use_i18n_context, I18nContext};
+pub use locale_tag::ParseLocaleError;
 
-pub use leptos_i18n_macro::{load_locales, t, td};
+pub use locale_override::{override_locale, LocaleOverrideGuard};
+
+pub use accept_language::{negotiate_locale, parse_accept_language};
+
+pub use negotiation::LocaleSource;
+
+#[cfg(feature = "http")]
+pub use http_header::{
+    accept_language_header, content_language_header, locale_from_accept_language_header,
+    locale_from_content_language_header, parse_accept_language_header,
+};
+
+pub use context::{
+    provide_i18n_context, provide_i18n_context_with, provide_i18n_context_with_storage,
+    try_use_i18n_context, use_i18n_context, I18nContext,
+};
+
+#[cfg(feature = "router")]
+pub use router::{use_locale_param, I18nRoute};
+
+pub use direction::{provide_dir, Direction};
+
+pub use shard::{I18nShardContext, ShardKeys, ShardLocale};
+
+pub use scope::I18nScope;
+
+#[cfg(feature = "dynamic_load")]
+pub use dynamic::{
+    create_locale_resource, is_locale_loaded, load_keys, load_locale, set_dynamic_assets_dir,
+    set_locale_when_loaded, DynamicKeys, DynamicLoadError,
+};
+
+pub use requested_locales::use_locales;
+
+pub use static_paths::{localized_path, localized_paths};
+
+#[cfg(feature = "cookie")]
+pub use storage::CookieStorage;
+pub use storage::{
+    ConsentStorage, CookieAttributes, LocalStorage, LocaleStorage, NoStorage, SameSite, StorageKind,
+};
+
+pub use email::{Email, EmailRenderer};
+
+pub use localize::localize;
+
+pub use translation::{TranslationBuilder, Value};
+
+pub use error_page::render_error_page;
+
+#[cfg(any(feature = "actix", feature = "axum"))]
+pub use server::{error_page_response, request_locale};
+
+pub use localized::{LocaleFormat, Localized};
+
+pub use formatting::{FormatDate, FormatNumber, FormatTime};
+
+pub use select::SelectVariant;
+
+pub use localized_error::{localize_error, ErrorArg, LocalizedError};
+
+pub use missing_keys::{clear_missing_key_handler, report_missing_key, set_missing_key_handler};
+
+#[cfg(feature = "system_locale")]
+pub use system_locale::{fetch_system_locale, get_system_locales};
+
+pub use leptos_i18n_macro::{
+    load_locales, scope_i18n, t, t_asset, t_meta, t_string, t_title, td, td_string, use_i18n_scoped,
+};
 
 #[doc(hidden)]
 pub mod __private {
-    pub use super::locale_traits::BuildStr;
+    pub use super::accept_language::lookup as lookup_locale;
+    #[cfg(feature = "dynamic_load")]
+    pub use super::dynamic::{dynamic_keys, DynamicValues};
+    pub use super::formatting::{
+        DateFn, DateTimeFn, EmptyFormatValue, NumberFn, NumberSymbols, TimeFn,
+    };
+    #[cfg(feature = "hot_reload")]
+    pub use super::hot_reload::{hot_reload_keys, HotReloadKeys};
+    pub use super::locale_tag::tag_eq;
+    pub use super::locale_traits::{BuildStr, BuildString};
+    pub use super::plural_operands::PluralOperands;
+    pub use super::select::SelectFn;
+    pub use super::shard::GetKeys;
+    pub use super::template::{display_component, TemplatePart};
+    pub use leptos_meta::{Meta, MetaProps, Title, TitleProps};
+
+    #[cfg(feature = "serde")]
+    pub use super::locale_serde::{deserialize_locale, serialize_locale};
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "icu")]
+    pub use super::icu::{to_icu_language_identifier, to_icu_locale};
+    #[cfg(feature = "icu")]
+    pub use icu_locale_core;
 }
 
-#[cfg(all(
-    feature = "cookie",
-    any(feature = "ssr", feature = "hydrate", feature = "csr")
-))]
+#[cfg(feature = "cookie")]
 pub(crate) const COOKIE_PREFERED_LANG: &str = "i18n_pref_locale";
 
 #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
@@ -0,0 +1,51 @@
+use std::{cell::Cell, marker::PhantomData};
+
+use crate::Locale;
+
+thread_local! {
+    static LOCALE_OVERRIDE: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// Guard returned by `override_locale`, the previous override is restored when it is dropped.
+#[must_use = "the locale is only overridden until the guard is dropped"]
+#[derive(Debug)]
+pub struct LocaleOverrideGuard {
+    previous: Option<&'static str>,
+    // the override is per thread, so the guard must be dropped on the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for LocaleOverrideGuard {
+    fn drop(&mut self) {
+        LOCALE_OVERRIDE.with(|locale| locale.set(self.previous));
+    }
+}
+
+/// Force the locale of the `I18nContext` created on the current thread until the returned guard is dropped.
+///
+/// The overridden locale takes precedence over the storage (the cookie by default), the `Accept-Language` header
+/// and the locale rendered by the server when hydrating. Meant to render a page in every locale from tests:
+///
+/// ```rust, ignore
+/// for locale in Locale::iter() {
+///     let _guard = leptos_i18n::override_locale(locale);
+///     let html = render_to_string(App);
+///     insta::assert_snapshot!(locale.as_str(), html);
+/// }
+/// ```
+///
+/// Guards can be nested, the previous override is restored when a guard is dropped.
+pub fn override_locale<T: Locale>(locale: T) -> LocaleOverrideGuard {
+    let previous = LOCALE_OVERRIDE.with(|current| current.replace(Some(locale.as_str())));
+    LocaleOverrideGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Return the locale forced on the current thread, if any.
+pub(crate) fn get_locale_override<T: Locale>() -> Option<T> {
+    LOCALE_OVERRIDE
+        .with(Cell::get)
+        .and_then(|tag| T::from_str(tag))
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use serde::{de, Deserializer, Serializer};
+
+use crate::Locale;
+
+/// Serialize the locale as its tag.
+pub fn serialize_locale<T: Locale, S: Serializer>(
+    locale: T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(locale.as_str())
+}
+
+struct LocaleVisitor<T>(std::marker::PhantomData<T>);
+
+impl<'de, T: Locale> de::Visitor<'de> for LocaleVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a locale tag")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserialize the locale from its tag.
+pub fn deserialize_locale<'de, T: Locale, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_str(LocaleVisitor(std::marker::PhantomData))
+}
@@ -0,0 +1,53 @@
+use std::fmt::{self, Display};
+
+/// Error returned when parsing a string that does not match any locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLocaleError(String);
+
+impl ParseLocaleError {
+    #[doc(hidden)]
+    pub fn new(tag: &str) -> Self {
+        ParseLocaleError(tag.to_string())
+    }
+
+    /// Return the string that failed to be parsed.
+    pub fn tag(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown locale {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLocaleError {}
+
+/// Compare two locale tags ignoring ASCII case and treating `_` and `-` as the same separator.
+pub fn tag_eq(a: &str, b: &str) -> bool {
+    fn normalize(b: u8) -> u8 {
+        match b {
+            b'_' => b'-',
+            b => b.to_ascii_lowercase(),
+        }
+    }
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_eq() {
+        assert!(tag_eq("en-US", "en-US"));
+        assert!(tag_eq("en-US", "en_us"));
+        assert!(tag_eq("EN-us", "en-US"));
+        assert!(!tag_eq("en-US", "en"));
+        assert!(!tag_eq("en-US", "en-GB"));
+    }
+}
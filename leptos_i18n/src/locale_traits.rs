@@ -5,12 +5,82 @@ pub trait Locale: 'static + Default + Clone + Copy {
     /// The associated struct containing the translations
     type Keys: LocaleKeys<Locale = Self>;
 
+    /// The sources the locale of a request is negotiated from on the server, in the order they are consulted.
+    ///
+    /// Set with the `negotiation` option of the configuration, the cookie then the `Accept-Language` header by default.
+    const NEGOTIATION: &'static [crate::LocaleSource] = &[
+        crate::LocaleSource::Cookie,
+        crate::LocaleSource::AcceptLanguage,
+    ];
+
+    /// The name of the cookie saving the locale chosen by the user.
+    ///
+    /// Set with the `cookie-name` option of the configuration, `"i18n_pref_locale"` by default.
+    const COOKIE_NAME: &'static str = "i18n_pref_locale";
+
+    /// Where `provide_i18n_context` persists the locale chosen by the user.
+    ///
+    /// Set with the `locale-storage` option of the configuration, the cookie by default.
+    const STORAGE: crate::StorageKind = crate::StorageKind::Cookie;
+
+    /// The attributes of the cookie saving the locale chosen by the user.
+    ///
+    /// Set with the `cookie-max-age`, `cookie-same-site`, `cookie-secure` and `cookie-path` options of the configuration.
+    const COOKIE_ATTRIBUTES: crate::CookieAttributes = crate::CookieAttributes::DEFAULT;
+
     /// Try to match the given str to a locale and returns it.
+    ///
+    /// If no locale has this tag, its subtags are removed from the end until one match, so `"fr-CA"` match `"fr"`.
     fn from_str(s: &str) -> Option<Self>;
 
     /// Return a static str that represent the locale.
     fn as_str(self) -> &'static str;
 
+    /// Return all the locales, in the order they are declared in the configuration.
+    fn get_all() -> &'static [Self];
+
+    /// Return this locale followed by the locales its missing keys are taken from, ending with the default locale.
+    ///
+    /// A locale takes the keys it is missing from the locale it extends with `inherits` in the configuration,
+    /// or else from the locale of its tag without the last subtags (`fr-CA` from `fr`), then from the default locale.
+    fn fallback_chain(self) -> &'static [Self];
+
+    /// Return the paths of the keys missing in this locale that fallback to the default locale.
+    fn missing_keys(self) -> &'static [&'static str];
+
+    /// Return every key of this locale rendered to a deterministic text, for snapshot tests.
+    ///
+    /// There is one `path = value` line per key, sorted by path, plurals and lists having one line per value (`path[count] = value`).
+    /// Variables are replaced by the default value declared in the `_variables` section if any, else written as `{{ name }}`,
+    /// and components are written as tags. The keys missing in this locale have the value of the default locale.
+    ///
+    /// Only available with the `snapshot` feature, meant for tests so the snapshots are not compiled in the bundle,
+    /// empty if the locale was not generated by `load_locales!()`.
+    #[cfg(feature = "snapshot")]
+    fn snapshot(self) -> &'static str {
+        ""
+    }
+
+    /// Return the path of every key, sorted: `key.subkey`, or `namespace::key.subkey` with namespaces.
+    fn key_paths() -> &'static [&'static str];
+
+    /// Return the value of the key at the given path for this locale, to translate keys only known at runtime
+    /// such as error codes sent by the server.
+    ///
+    /// The path use the same syntax as foreign keys, `namespace.key.subkey` being also accepted for `namespace::key.subkey`.
+    /// The variables are rendered as `{{ name }}`, use `translation_builder` to give them a value.
+    ///
+    /// Return `None` if the key does not exist or if its value contains components or plurals.
+    fn get_translation(self, key_path: &str) -> Option<std::borrow::Cow<'static, str>> {
+        crate::translation::get_translation(self, key_path)
+    }
+
+    /// Return a builder rendering the value of the key at the given path for this locale with the values of its variables,
+    /// see `get_translation`.
+    fn translation_builder<'a>(self, key_path: &str) -> Option<crate::TranslationBuilder<'a>> {
+        crate::translation::translation_builder(self, key_path)
+    }
+
     /// Given a slice of accepted languages sorted in preferred order, return the locale that fit the best the request.
     fn find_locale<T: AsRef<str>>(accepted_langs: &[T]) -> Self {
         accepted_langs
@@ -19,6 +89,40 @@ pub trait Locale: 'static + Default + Clone + Copy {
             .unwrap_or_default()
     }
 
+    /// Given a slice of requested locale tags sorted in preferred order, return the declared locale that match the best
+    /// following the BCP 47 lookup scheme ([RFC 4647](https://www.rfc-editor.org/rfc/rfc4647#section-3.4)):
+    /// the subtags of each requested tag are removed from the end until a locale match, so `"fr-CH"` match `"fr"`.
+    ///
+    /// Tags are compared ignoring case and `_` is accepted as a separator. Return the default locale if none match.
+    fn find_best_match<T: AsRef<str>>(requested: &[T]) -> Self {
+        requested
+            .iter()
+            .find_map(|tag| crate::accept_language::lookup(tag.as_ref()))
+            .unwrap_or_default()
+    }
+
+    /// Given a slice of requested locale tags sorted in preferred order, return every declared locale matching one of them,
+    /// in the same order and without duplicates, with the same lookup scheme as `find_best_match`.
+    ///
+    /// Unlike `find_best_match` the default locale is not added if none match.
+    fn find_matches<T: AsRef<str>>(requested: &[T]) -> Vec<Self> {
+        let mut matches: Vec<Self> = vec![];
+        for locale in requested
+            .iter()
+            .filter_map(|tag| crate::accept_language::lookup::<Self>(tag.as_ref()))
+        {
+            if !matches.iter().any(|m| m.as_str() == locale.as_str()) {
+                matches.push(locale);
+            }
+        }
+        matches
+    }
+
+    /// Return the direction of the text of this locale, derived from its tag.
+    fn direction(self) -> crate::Direction {
+        crate::Direction::of(self.as_str())
+    }
+
     /// Return the keys based on self
     #[inline]
     fn get_keys(self) -> &'static Self::Keys {
@@ -35,6 +139,20 @@ pub trait LocaleKeys: 'static + Clone + Copy {
 
     /// Create self according to the given locale.
     fn from_variant(variant: Self::Locale) -> &'static Self;
+
+    /// Return the value of the string key at the given path, if any.
+    ///
+    /// The path use the same syntax as foreign keys: `key.subkey`, or `namespace::key.subkey` with namespaces.
+    fn get_string(&self, key_path: &str) -> Option<&'static str>;
+
+    /// Return the template of the interpolated key at the given path, if its value does not contain plurals.
+    #[doc(hidden)]
+    fn get_template(&self, key_path: &str) -> Option<&'static [crate::template::TemplatePart]>;
+
+    /// Render every string and interpolated key with placeholder values, with their path, see `testing::TestI18n::render_keys`.
+    #[doc(hidden)]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_keys(&self) -> Vec<(String, leptos::View)>;
 }
 
 /// This is used to call `.build` on `&str` when building interpolations
@@ -50,4 +168,20 @@ pub trait BuildStr: Sized {
     }
 }
 
-impl<'a> BuildStr for &'a str {}
+impl BuildStr for &str {}
+
+impl<T> BuildStr for &[T] {}
+
+/// This is used to call `.build_string` on `&'static str` in `t_string!`,
+/// the builders of the interpolations implementing it as an inherent method rendering them to a `String`.
+#[doc(hidden)]
+pub trait BuildString: Sized {
+    fn build_string(self) -> std::borrow::Cow<'static, str>;
+}
+
+impl BuildString for &'static str {
+    #[inline]
+    fn build_string(self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self)
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+use crate::{
+    template::{render_key, PlainRenderer},
+    Locale,
+};
+
+/// Render the value of the key at the given path to a `String` for the given locale, outside of any reactive context.
+///
+/// Useful on the server to localize API responses, such as error messages or notification payloads,
+/// with the locale negotiated for the request.
+///
+/// The key path use the same syntax as foreign keys: `key.subkey`, or `namespace::key.subkey` with namespaces.
+/// Variables of the value are replaced by the arguments with the same name, variables without argument are rendered as `{{ name }}`.
+///
+/// Return `None` if the key does not exist or if its value contains components or plurals.
+///
+/// ```rust, ignore
+/// // "not_found": "No item with id {{ id }}"
+/// let message = leptos_i18n::localize(locale, "not_found", &[("id", &item_id)]);
+/// ```
+pub fn localize<T: Locale>(
+    locale: T,
+    key_path: &str,
+    args: &[(&str, &dyn Display)],
+) -> Option<String> {
+    let get_var = |name: &str| {
+        args.iter()
+            .find(|(arg_name, _)| *arg_name == name)
+            .map(|(_, value)| value.to_string())
+    };
+    render_key(locale, key_path, &mut PlainRenderer(get_var))
+}
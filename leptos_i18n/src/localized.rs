@@ -0,0 +1,278 @@
+use std::fmt::{self, Display};
+
+use leptos::{IntoView, View};
+
+use crate::{I18nContext, Locale};
+
+/// Trait for values that can be formatted for a given locale.
+///
+/// Implemented for `time::Date`, `time::Time` and `time::PrimitiveDateTime` with the `time` feature,
+/// and for `chrono::NaiveDate`, `chrono::NaiveTime` and `chrono::NaiveDateTime` with the `chrono` feature,
+/// it can be implemented for your own types to use them with [`Localized`].
+///
+/// Without the `icu` feature the dates and times use built-in formats known only for English, Chinese, Japanese, Korean,
+/// Swedish, Lithuanian, Hungarian, Dutch and the languages writing `31.12.2023` (German, Russian, Polish, Czech, ...),
+/// every other language is formatted as `31/12/2023` with the 24-hour clock.
+pub trait LocaleFormat {
+    /// Write the value formatted for the locale with the given tag.
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Wrapper formatting its inner value for the current locale of the context.
+///
+/// When rendered it follows the locale changes, and its `Display` implementation use the current locale without subscribing to it.
+///
+/// ```rust, ignore
+/// let i18n = use_i18n();
+/// view! { <td>{Localized::new(i18n, date)}</td> }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<T, L: Locale> {
+    value: T,
+    context: I18nContext<L>,
+}
+
+impl<T, L: Locale> Localized<T, L> {
+    /// Wrap the value to format it for the current locale of the context.
+    pub const fn new(context: I18nContext<L>, value: T) -> Self {
+        Localized { value, context }
+    }
+
+    /// Return the inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: LocaleFormat, L: Locale> Display for Localized<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = self.context.get_locale_untracked();
+        self.value.fmt_locale(locale.as_str(), f)
+    }
+}
+
+impl<T: LocaleFormat + 'static, L: Locale> IntoView for Localized<T, L> {
+    fn into_view(self) -> View {
+        let Localized { value, context } = self;
+        (move || {
+            let locale = context.get_locale();
+            LocaleFormatter(&value, locale.as_str()).to_string()
+        })
+        .into_view()
+    }
+}
+
+struct LocaleFormatter<'a, T>(&'a T, &'a str);
+
+impl<T: LocaleFormat> Display for LocaleFormatter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_locale(self.1, f)
+    }
+}
+
+/// Order and separator of the numeric date format of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateFormat {
+    /// `12/31/2023`
+    MonthDayYear,
+    /// `31/12/2023`, `31.12.2023` or `31-12-2023`
+    DayMonthYear(char),
+    /// `2023-12-31` or `2023/12/31`
+    YearMonthDay(char),
+}
+
+impl DateFormat {
+    fn from_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        let region = subtags.find(|subtag| subtag.len() == 2 || subtag.len() == 3);
+        match (language, region) {
+            ("en", None | Some("US" | "PH")) => DateFormat::MonthDayYear,
+            ("en", Some("CA")) => DateFormat::YearMonthDay('-'),
+            ("zh" | "ja", _) => DateFormat::YearMonthDay('/'),
+            ("sv" | "lt" | "ko" | "hu", _) => DateFormat::YearMonthDay('-'),
+            (
+                "de" | "ru" | "pl" | "fi" | "cs" | "sk" | "nb" | "no" | "da" | "tr" | "uk" | "ro"
+                | "bg" | "et" | "lv",
+                _,
+            ) => DateFormat::DayMonthYear('.'),
+            ("nl", _) => DateFormat::DayMonthYear('-'),
+            _ => DateFormat::DayMonthYear('/'),
+        }
+    }
+}
+
+/// Write the short date format of the locale, with ICU4X when the `icu` feature is enabled.
+pub(crate) fn fmt_date(
+    (year, month, day): (i32, u8, u8),
+    locale: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    #[cfg(feature = "icu")]
+    if let Some(formatted) = crate::icu::format_date((year, month, day), locale) {
+        return f.write_str(&formatted);
+    }
+    match DateFormat::from_locale(locale) {
+        DateFormat::MonthDayYear => write!(f, "{}/{}/{}", month, day, year),
+        DateFormat::DayMonthYear(sep) => {
+            write!(f, "{:02}{sep}{:02}{sep}{}", day, month, year)
+        }
+        DateFormat::YearMonthDay(sep) => {
+            write!(f, "{}{sep}{:02}{sep}{:02}", year, month, day)
+        }
+    }
+}
+
+/// Write the hours and minutes, with ICU4X when the `icu` feature is enabled
+/// and otherwise with the 12-hour clock for the locales using the month-day-year dates.
+pub(crate) fn fmt_time(
+    (hour, minute): (u8, u8),
+    locale: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    #[cfg(feature = "icu")]
+    if let Some(formatted) = crate::icu::format_time((hour, minute), locale) {
+        return f.write_str(&formatted);
+    }
+    if DateFormat::from_locale(locale) == DateFormat::MonthDayYear {
+        let period = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        write!(f, "{}:{:02} {}", hour, minute, period)
+    } else {
+        write!(f, "{:02}:{:02}", hour, minute)
+    }
+}
+
+/// Write the date and the time, with the combined format of the locale when the `icu` feature is enabled.
+pub(crate) fn fmt_datetime(
+    date: (i32, u8, u8),
+    time: (u8, u8),
+    locale: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    #[cfg(feature = "icu")]
+    if let Some(formatted) = crate::icu::format_datetime(date, time, locale) {
+        return f.write_str(&formatted);
+    }
+    fmt_date(date, locale, f)?;
+    f.write_str(" ")?;
+    fmt_time(time, locale, f)
+}
+
+#[cfg(feature = "time")]
+impl LocaleFormat for time::Date {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_date((self.year(), u8::from(self.month()), self.day()), locale, f)
+    }
+}
+
+#[cfg(feature = "time")]
+impl LocaleFormat for time::Time {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_time((self.hour(), self.minute()), locale, f)
+    }
+}
+
+#[cfg(feature = "time")]
+impl LocaleFormat for time::PrimitiveDateTime {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, time) = (self.date(), self.time());
+        fmt_datetime(
+            (date.year(), u8::from(date.month()), date.day()),
+            (time.hour(), time.minute()),
+            locale,
+            f,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl LocaleFormat for chrono::NaiveDate {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_date(crate::FormatDate::to_date(self), locale, f)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl LocaleFormat for chrono::NaiveTime {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_time(crate::FormatTime::to_time(self), locale, f)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl LocaleFormat for chrono::NaiveDateTime {
+    fn fmt_locale(&self, locale: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_datetime(
+            crate::FormatDate::to_date(self),
+            crate::FormatTime::to_time(self),
+            locale,
+            f,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "time", not(feature = "icu")))]
+mod tests {
+    use super::*;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    fn date() -> Date {
+        Date::from_calendar_date(2023, Month::December, 1).unwrap()
+    }
+
+    fn time(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).unwrap()
+    }
+
+    fn format<T: LocaleFormat>(value: T, locale: &str) -> String {
+        LocaleFormatter(&value, locale).to_string()
+    }
+
+    #[test]
+    fn test_format_date() {
+        let date = date();
+        assert_eq!(format(date, "en"), "12/1/2023");
+        assert_eq!(format(date, "en-GB"), "01/12/2023");
+        assert_eq!(format(date, "fr"), "01/12/2023");
+        assert_eq!(format(date, "de-DE"), "01.12.2023");
+        assert_eq!(format(date, "nl"), "01-12-2023");
+        assert_eq!(format(date, "ja"), "2023/12/01");
+        assert_eq!(format(date, "sv"), "2023-12-01");
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format(time(0, 5), "en-US"), "12:05 AM");
+        assert_eq!(format(time(13, 5), "en"), "1:05 PM");
+        assert_eq!(format(time(13, 5), "fr"), "13:05");
+        assert_eq!(
+            format(PrimitiveDateTime::new(date(), time(13, 5)), "de"),
+            "01.12.2023 13:05"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "chrono", not(feature = "icu")))]
+mod chrono_tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn format<T: LocaleFormat>(value: T, locale: &str) -> String {
+        LocaleFormatter(&value, locale).to_string()
+    }
+
+    #[test]
+    fn test_format_chrono() {
+        let date = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+        let time = NaiveTime::from_hms_opt(13, 5, 0).unwrap();
+        assert_eq!(format(date, "en"), "12/1/2023");
+        assert_eq!(format(date, "fr"), "01/12/2023");
+        assert_eq!(format(time, "en"), "1:05 PM");
+        assert_eq!(format(time, "fr"), "13:05");
+        assert_eq!(format(date.and_time(time), "de"), "01.12.2023 13:05");
+    }
+}
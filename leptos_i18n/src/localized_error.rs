@@ -0,0 +1,86 @@
+use std::{borrow::Cow, fmt::Display};
+
+use crate::{
+    template::{lookup, render_key, PlainRenderer, Translation},
+    Locale,
+};
+
+/// Value interpolated in a localized error message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorArg {
+    /// A value interpolated as is, such as a limit.
+    Value(String),
+    /// The path of a string key interpolated with its translation, such as a field name.
+    Key(Cow<'static, str>),
+}
+
+impl ErrorArg {
+    /// Create an argument interpolated as is.
+    pub fn value(value: impl Display) -> Self {
+        ErrorArg::Value(value.to_string())
+    }
+
+    /// Create an argument interpolated with the translation of the string key at the given path.
+    pub fn key(key_path: impl Into<Cow<'static, str>>) -> Self {
+        ErrorArg::Key(key_path.into())
+    }
+}
+
+/// Trait mapping errors, such as form validation errors, to translation keys.
+///
+/// The key path use the same syntax as foreign keys: `key.subkey`, or `namespace::key.subkey` with namespaces.
+/// The value at that path can either be a plain string or contain variables, but can't contain components or plurals.
+///
+/// ```rust, ignore
+/// enum PasswordError {
+///     TooShort { min: usize },
+/// }
+///
+/// impl LocalizedError for PasswordError {
+///     fn key_path(&self) -> Cow<'static, str> {
+///         match self {
+///             // "{{ field }} must be at least {{ min }} characters long"
+///             PasswordError::TooShort { .. } => "validation.too_short".into(),
+///         }
+///     }
+///
+///     fn args(&self) -> Vec<(&'static str, ErrorArg)> {
+///         match self {
+///             PasswordError::TooShort { min } => vec![
+///                 ("field", ErrorArg::key("fields.password")),
+///                 ("min", ErrorArg::value(min)),
+///             ],
+///         }
+///     }
+/// }
+/// ```
+pub trait LocalizedError {
+    /// Return the path of the key containing the message for this error.
+    fn key_path(&self) -> Cow<'static, str>;
+
+    /// Return the values to interpolate in the message, by variable name.
+    fn args(&self) -> Vec<(&'static str, ErrorArg)> {
+        Vec::new()
+    }
+}
+
+/// Render the message of the error in the given locale.
+///
+/// Return `None` if the key does not exist or if its value contains components or plurals.
+pub fn localize_error<T: Locale, E: LocalizedError + ?Sized>(
+    locale: T,
+    error: &E,
+) -> Option<String> {
+    let args = error.args();
+    let get_var = |name: &str| {
+        let (_, arg) = args.iter().find(|(arg_name, _)| *arg_name == name)?;
+        match arg {
+            ErrorArg::Value(value) => Some(value.clone()),
+            ErrorArg::Key(key_path) => match lookup(locale, key_path)? {
+                Translation::Str(s) => Some(s.to_string()),
+                Translation::Template(_) => None,
+            },
+        }
+    };
+    render_key(locale, &error.key_path(), &mut PlainRenderer(get_var))
+}
@@ -0,0 +1,119 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::Locale;
+
+type MissingKeyHandler = Arc<dyn Fn(&str, &str) + Send + Sync>;
+
+static MISSING_KEY_HANDLER: RwLock<Option<MissingKeyHandler>> = RwLock::new(None);
+
+static REPORTED_LOCALES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Register a callback that is invoked for every key of a locale that falls back to the default locale.
+///
+/// The callback receive the locale the key is missing from and the path of the key (`namespace::key.subkey`).
+///
+/// The missing keys of a locale are reported the first time that locale is set in the `I18nContext`,
+/// switching back to a locale already reported does not call the callback again.
+///
+/// Keys declared as explicit defaults (`null`) are not reported.
+///
+/// Registering a new handler replace the previous one.
+pub fn set_missing_key_handler<F>(handler: F)
+where
+    F: Fn(&str, &str) + Send + Sync + 'static,
+{
+    let mut guard = MISSING_KEY_HANDLER
+        .write()
+        .unwrap_or_else(|err| err.into_inner());
+    *guard = Some(Arc::new(handler));
+}
+
+/// Remove the callback registered with `set_missing_key_handler`, if any.
+pub fn clear_missing_key_handler() {
+    let mut guard = MISSING_KEY_HANDLER
+        .write()
+        .unwrap_or_else(|err| err.into_inner());
+    *guard = None;
+}
+
+fn get_handler() -> Option<MissingKeyHandler> {
+    MISSING_KEY_HANDLER
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+}
+
+/// Report a single missing key to the registered handler.
+pub fn report_missing_key(locale: &str, key_path: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(locale, key_path, "missing key");
+    if let Some(handler) = get_handler() {
+        handler(locale, key_path)
+    }
+}
+
+/// Report every key missing in the given locale to the registered handler, once per locale.
+pub(crate) fn report_missing_keys<T: Locale>(locale: T) {
+    let missing_keys = locale.missing_keys();
+    if missing_keys.is_empty() || !mark_reported(locale.as_str()) {
+        return;
+    }
+    #[cfg(feature = "tracing")]
+    for key_path in missing_keys {
+        tracing::warn!(locale = locale.as_str(), key_path, "missing key");
+    }
+    if let Some(handler) = get_handler() {
+        for key_path in missing_keys {
+            handler(locale.as_str(), key_path)
+        }
+    }
+}
+
+/// Returns `true` the first time it is called with a given locale.
+fn mark_reported(locale: &'static str) -> bool {
+    let mut reported = REPORTED_LOCALES
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if reported.contains(&locale) {
+        false
+    } else {
+        reported.push(locale);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_handler_called() {
+        static REPORTED: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+        set_missing_key_handler(|locale, key_path| {
+            REPORTED
+                .lock()
+                .unwrap()
+                .push((locale.to_string(), key_path.to_string()))
+        });
+
+        report_missing_key("fr", "namespace::key.subkey");
+
+        clear_missing_key_handler();
+
+        report_missing_key("fr", "ignored");
+
+        let reported = REPORTED.lock().unwrap();
+        assert_eq!(
+            &*reported,
+            &[("fr".to_string(), "namespace::key.subkey".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_mark_reported_once() {
+        assert!(mark_reported("test-once"));
+        assert!(!mark_reported("test-once"));
+    }
+}
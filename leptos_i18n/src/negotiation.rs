@@ -0,0 +1,66 @@
+/// A source the locale of a request is negotiated from on the server.
+///
+/// The sources are consulted in the order given by the `negotiation` option of the configuration, see `Locale::NEGOTIATION`,
+/// the first one giving a declared locale wins and the default locale is used if none does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocaleSource {
+    /// The cookie saving the locale chosen by the user, named by the `cookie-name` option.
+    /// Requires the `cookie` feature, and is skipped if the locale is not stored in a cookie, see `Locale::STORAGE`.
+    Cookie,
+    /// The languages of the `Accept-Language` header, in preferred order.
+    AcceptLanguage,
+    /// The first segment of the path of the request URL, such as `fr` in `/fr/about`.
+    Url,
+    /// The default locale, the sources after it are never consulted.
+    Default,
+}
+
+/// The values of a request the locale can be negotiated from.
+#[cfg(any(feature = "actix", feature = "axum"))]
+#[derive(Debug)]
+pub(crate) struct RequestSources<'a> {
+    pub cookie: Option<&'a str>,
+    pub accept_language: Option<Vec<String>>,
+    pub path: Option<&'a str>,
+}
+
+#[cfg(any(feature = "actix", feature = "axum"))]
+use crate::Locale;
+
+#[cfg(any(feature = "actix", feature = "axum"))]
+impl RequestSources<'_> {
+    /// Return the locale of the first source of `T::NEGOTIATION` matching a declared locale, or the default locale.
+    pub fn negotiate<T: Locale>(&self) -> T {
+        for source in T::NEGOTIATION {
+            let locale = match source {
+                LocaleSource::Cookie => self.cookie.and_then(T::from_str),
+                LocaleSource::AcceptLanguage => self
+                    .accept_language
+                    .as_deref()
+                    .and_then(|langs| langs.iter().find_map(|lang| T::from_str(lang))),
+                LocaleSource::Url => self.path.and_then(path_locale::<T>),
+                LocaleSource::Default => None,
+            };
+            if let Some(locale) = locale {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(locale = locale.as_str(), source = ?source, "locale found in request");
+                return locale;
+            }
+            if *source == LocaleSource::Default {
+                break;
+            }
+        }
+        T::default()
+    }
+}
+
+/// Return the locale matching the first segment of the path, if any.
+#[cfg(any(feature = "actix", feature = "axum"))]
+fn path_locale<T: Locale>(path: &str) -> Option<T> {
+    let segment = path.trim_start_matches('/').split(['/', '?', '#']).next()?;
+    if segment.is_empty() {
+        None
+    } else {
+        T::from_str(segment)
+    }
+}
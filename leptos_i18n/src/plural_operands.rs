@@ -0,0 +1,115 @@
+//! Operands of the CLDR plural rules, used by the code generated for the plural categories.
+
+/// The operands of a number used by the [CLDR plural rules](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+///
+/// They are all stored as `f64` as the generated conditions compare them to the literals of the rules.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PluralOperands {
+    /// Absolute value of the number.
+    pub n: f64,
+    /// Integer digits of `n`.
+    pub i: f64,
+    /// Number of visible fraction digits, with trailing zeros.
+    pub v: f64,
+    /// Number of visible fraction digits, without trailing zeros.
+    pub w: f64,
+    /// Visible fraction digits, with trailing zeros.
+    pub f: f64,
+    /// Visible fraction digits, without trailing zeros.
+    pub t: f64,
+    /// Exponent of the compact decimal notation, always 0 as it is not used.
+    pub e: f64,
+}
+
+impl PluralOperands {
+    fn from_integer(n: u64) -> Self {
+        PluralOperands {
+            n: n as f64,
+            i: n as f64,
+            ..Default::default()
+        }
+    }
+
+    /// The visible fraction digits of a float are the ones of its shortest representation,
+    /// so `1.0` has none and `1.50` is `1.5`.
+    fn from_float(n: f64) -> Self {
+        let n = n.abs();
+        let repr = n.to_string();
+        let (int, fraction) = repr.split_once('.').unwrap_or((&repr, ""));
+        let trimmed = fraction.trim_end_matches('0');
+        let parse = |digits: &str| digits.parse::<f64>().unwrap_or(0.0);
+        PluralOperands {
+            n,
+            i: parse(int),
+            v: fraction.len() as f64,
+            w: trimmed.len() as f64,
+            f: parse(fraction),
+            t: parse(trimmed),
+            e: 0.0,
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty)*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(n: $t) -> Self {
+                    Self::from_integer(n as u64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty)*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(n: $t) -> Self {
+                    Self::from_integer(n.unsigned_abs() as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8 u16 u32 u64);
+impl_from_signed!(i8 i16 i32 i64);
+
+impl From<f32> for PluralOperands {
+    fn from(n: f32) -> Self {
+        // go through the string representation to not get the imprecision of the conversion (0.1f32 as f64 = 0.10000000149011612)
+        Self::from_float(n.to_string().parse().unwrap_or(n as f64))
+    }
+}
+
+impl From<f64> for PluralOperands {
+    fn from(n: f64) -> Self {
+        Self::from_float(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operands() {
+        let operands = PluralOperands::from(-12);
+        assert_eq!((operands.n, operands.i, operands.v), (12.0, 12.0, 0.0));
+
+        let operands = PluralOperands::from(1.5f64);
+        assert_eq!(
+            (operands.n, operands.i, operands.v, operands.f, operands.t),
+            (1.5, 1.0, 1.0, 5.0, 5.0)
+        );
+
+        let operands = PluralOperands::from(0.25f32);
+        assert_eq!((operands.i, operands.v, operands.f), (0.0, 2.0, 25.0));
+
+        let operands = PluralOperands::from(3.0f64);
+        assert_eq!((operands.i, operands.v), (3.0, 0.0));
+    }
+}
@@ -0,0 +1,56 @@
+/// Return the languages requested by the client, sorted in preferred order.
+///
+/// On the server they are parsed from the `Accept-Language` header of the request, and on the client they are read from
+/// `navigator.languages`. Unlike the locale of the context they are not restricted to the locales of the application,
+/// and the list is empty if the request is not available (for example when the routes are generated).
+///
+/// Useful to offer a page in the other languages the user can read:
+///
+/// ```rust, ignore
+/// let i18n = use_i18n();
+/// let others = Locale::find_matches(&leptos_i18n::use_locales())
+///     .into_iter()
+///     .filter(|locale| *locale != i18n.get_locale_untracked());
+/// ```
+pub fn use_locales() -> Vec<String> {
+    fetch_requested_locales()
+}
+
+#[cfg(feature = "ssr")]
+#[inline]
+fn fetch_requested_locales() -> Vec<String> {
+    crate::server::fetch_requested_locales_server_side()
+}
+
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn fetch_requested_locales() -> Vec<String> {
+    let Some(window) = web_sys::window() else {
+        return vec![];
+    };
+    window
+        .navigator()
+        .languages()
+        .iter()
+        .filter_map(|lang| lang.as_string())
+        .collect()
+}
+
+#[cfg(all(
+    feature = "system_locale",
+    not(any(feature = "ssr", feature = "hydrate", feature = "csr"))
+))]
+#[inline]
+fn fetch_requested_locales() -> Vec<String> {
+    crate::get_system_locales()
+}
+
+#[cfg(not(any(
+    feature = "system_locale",
+    feature = "ssr",
+    feature = "hydrate",
+    feature = "csr"
+)))]
+#[inline]
+fn fetch_requested_locales() -> Vec<String> {
+    vec![]
+}
@@ -0,0 +1,256 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::{use_i18n_context, Locale};
+
+/// Keep the locale of the context in sync with a route param.
+///
+/// Can be used at any level of nested routes, the locale is updated each time the param changes,
+/// for example when navigating between sections of the app with different locales. When the param
+/// is missing or does not match any locale the current locale is kept.
+///
+/// Return the locale parsed from the param.
+///
+/// ```rust, ignore
+/// // <Route path="/docs/:lang" view=Docs>
+/// #[component]
+/// fn Docs() -> impl IntoView {
+///     leptos_i18n::use_locale_param::<Locale>("lang");
+///     /* */
+/// }
+/// ```
+pub fn use_locale_param<T: Locale + PartialEq>(param: &'static str) -> Memo<Option<T>> {
+    let i18n = use_i18n_context::<T>();
+    let params = leptos_router::use_params_map();
+    let locale = create_memo(move |_| {
+        params.with(|params| params.get(param).and_then(|value| T::from_str(value)))
+    });
+    create_isomorphic_effect(move |_| {
+        if let Some(locale) = locale.get() {
+            if locale != i18n.get_locale_untracked() {
+                i18n.set_locale(locale);
+            }
+        }
+    });
+    locale
+}
+
+/// Mount the nested routes under a `/:locale` segment and keep it in sync with the locale of the context.
+///
+/// - the locale of the context is set from the segment each time it changes;
+/// - `/` redirects to the negotiated locale, and a path whose first segment is not a locale is redirected to the same path under it;
+/// - a segment matching a locale but not written as its tag (`/FR`, `/fr-CA`) is redirected to the tag (`/fr`);
+/// - setting the locale of the context navigates to the same path under the new locale.
+///
+/// It must be used inside `<Routes>`, and the locale is taken from the generic parameter:
+///
+/// ```rust, ignore
+/// view! {
+///     <Router>
+///         <Routes>
+///             <I18nRoute<Locale, _, _> view=Outlet>
+///                 <Route path="" view=Home />
+///                 <Route path="about" view=About />
+///             </I18nRoute<Locale, _, _>>
+///         </Routes>
+///     </Router>
+/// }
+/// ```
+///
+/// The context is created before the routes are rendered, so add `"url"` to the `negotiation` option of the configuration
+/// for the server to render the whole page, and the client to hydrate it, with the locale of the URL.
+//
+// The component is written without `#[component]`, whose instrumentation of the function
+// needs a direct `tracing` dependency when rendering on the server.
+#[allow(non_snake_case)]
+pub fn I18nRoute<T, E, F>(props: I18nRouteProps<T, E, F>) -> impl IntoView
+where
+    T: Locale + PartialEq,
+    E: IntoView,
+    F: Fn() -> E + 'static,
+{
+    let I18nRouteProps {
+        view,
+        ssr,
+        children,
+        _locale,
+    } = props;
+
+    let localized = Route(
+        RouteProps::builder()
+            .path(":locale")
+            .view(move || localized_view::<T, _>(&view))
+            .ssr(ssr)
+            .children(children)
+            .build(),
+    );
+
+    view! {
+        <Route path="" view=Outlet ssr>
+            <Route path="" view={redirect_to_locale::<T>} ssr />
+            {localized}
+        </Route>
+    }
+}
+
+/// Props for the [`I18nRoute`] component.
+#[derive(leptos::typed_builder_macro::TypedBuilder)]
+#[builder(crate_module_path = leptos::typed_builder)]
+pub struct I18nRouteProps<T, E, F>
+where
+    T: Locale + PartialEq,
+    E: IntoView,
+    F: Fn() -> E + 'static,
+{
+    /// The view of the localized routes, usually a layout containing an `<Outlet/>` for the nested routes.
+    pub view: F,
+    /// The mode that the routes prefer during server-side rendering.
+    #[builder(default)]
+    pub ssr: SsrMode,
+    /// The routes mounted under the locale segment.
+    pub children: Children,
+    /// The locale, only used to infer the generic parameter.
+    #[builder(default)]
+    pub _locale: std::marker::PhantomData<T>,
+}
+
+impl<T, E, F> Props for I18nRouteProps<T, E, F>
+where
+    T: Locale + PartialEq,
+    E: IntoView,
+    F: Fn() -> E + 'static,
+{
+    type Builder = I18nRoutePropsBuilder<T, E, F>;
+
+    fn builder() -> Self::Builder {
+        I18nRouteProps::builder()
+    }
+}
+
+impl<T, E, F> DynAttrs for I18nRouteProps<T, E, F>
+where
+    T: Locale + PartialEq,
+    E: IntoView,
+    F: Fn() -> E + 'static,
+{
+}
+
+impl<T, E, F> IntoView for I18nRouteProps<T, E, F>
+where
+    T: Locale + PartialEq,
+    E: IntoView,
+    F: Fn() -> E + 'static,
+{
+    fn into_view(self) -> View {
+        I18nRoute(self).into_view()
+    }
+}
+
+fn redirect_to_locale<T: Locale>() -> impl IntoView {
+    let locale = use_i18n_context::<T>().get_locale_untracked();
+    view! { <Redirect path=locale.as_str() /> }
+}
+
+fn localized_view<T: Locale + PartialEq, E: IntoView>(view: &impl Fn() -> E) -> View {
+    let i18n = use_i18n_context::<T>();
+    let params = use_params_map();
+    let location = use_location();
+    let route = use_route();
+
+    let segment = create_memo(move |_| {
+        params.with(|params| params.get("locale").cloned().unwrap_or_default())
+    });
+    let locale = create_memo(move |_| segment.with(|segment| T::from_str(segment)));
+
+    // the URL changed.
+    create_isomorphic_effect(move |_| {
+        if let Some(locale) = locale.get() {
+            if locale != i18n.get_locale_untracked() {
+                i18n.set_locale(locale);
+            }
+        }
+    });
+
+    // the locale of the context changed.
+    let navigate = use_navigate();
+    let (effect_location, effect_route) = (location.clone(), route.clone());
+    create_effect(move |prev: Option<()>| {
+        let new_locale = i18n.get_locale();
+        if prev.is_none() || locale.get_untracked() == Some(new_locale) {
+            return;
+        }
+        let url = current_url(&effect_location, &effect_route, new_locale, true);
+        navigate(&url, Default::default());
+    });
+
+    match locale.get_untracked() {
+        Some(locale) if segment.with_untracked(|segment| segment == locale.as_str()) => {
+            view().into_view()
+        }
+        found => {
+            // the segment is not the tag of a locale, redirect to the path with the tag.
+            let (locale, replace) = match found {
+                Some(locale) => (locale, true),
+                None => (i18n.get_locale_untracked(), false),
+            };
+            let url = current_url(&location, &route, locale, replace);
+            view! { <Redirect path=url /> }.into_view()
+        }
+    }
+}
+
+/// Return the current URL with the locale segment of the route set to the locale, see `localized_url`.
+fn current_url<T: Locale>(
+    location: &Location,
+    route: &RouteContext,
+    locale: T,
+    replace: bool,
+) -> String {
+    let mut url = localized_url(
+        &location.pathname.get_untracked(),
+        &route.path(),
+        locale.as_str(),
+        replace,
+    );
+    location.search.with_untracked(|search| {
+        if !search.is_empty() {
+            url.push('?');
+            url.push_str(search);
+        }
+    });
+    location.hash.with_untracked(|hash| url.push_str(hash));
+    url
+}
+
+/// Return the path with the last segment of the route path, the locale segment, replaced by the given locale,
+/// or with the locale inserted before it if `replace` is false.
+fn localized_url(pathname: &str, route_path: &str, locale: &str, replace: bool) -> String {
+    let route_path = route_path.trim_end_matches('/');
+    let parent = route_path.rfind('/').map_or("", |i| &route_path[..i]);
+    let rest = if replace {
+        pathname.strip_prefix(route_path).unwrap_or_default()
+    } else {
+        pathname.strip_prefix(parent).unwrap_or(pathname)
+    };
+    format!("{}/{}{}", parent, locale, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localized_url() {
+        assert_eq!(localized_url("/en/about", "/en", "fr", true), "/fr/about");
+        assert_eq!(localized_url("/en", "/en/", "fr", true), "/fr");
+        assert_eq!(
+            localized_url("/app/FR/docs/1", "/app/FR", "fr", true),
+            "/app/fr/docs/1"
+        );
+        assert_eq!(localized_url("/about", "/about", "en", false), "/en/about");
+        assert_eq!(
+            localized_url("/app/about/team", "/app/about", "en", false),
+            "/app/en/about/team"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use std::ops::Deref;
+
+use crate::shard::GetKeys;
+
+/// A context or a locale whose keys are the subkeys or the namespace at a given path,
+/// for the `t!` and `td!` macros to take the keys relative to it.
+///
+/// Created by the `scope_i18n!` and `use_i18n_scoped!` macros, it derefs to the scoped context or locale.
+pub struct I18nScope<C: GetKeys, K: 'static> {
+    inner: C,
+    scope: fn(&'static C::Keys) -> &'static K,
+}
+
+impl<C: GetKeys, K: 'static> I18nScope<C, K> {
+    /// Scope the context or the locale with the function returning the subkeys from its keys.
+    #[inline]
+    pub fn new(inner: C, scope: fn(&'static C::Keys) -> &'static K) -> Self {
+        I18nScope { inner, scope }
+    }
+
+    /// Return the scoped context or locale.
+    #[inline]
+    pub fn inner(self) -> C {
+        self.inner
+    }
+
+    /// Return the subkeys of the scope, subscribing to the changes of the locale for the contexts.
+    #[inline]
+    pub fn get_keys(self) -> &'static K {
+        (self.scope)(self.inner.get_keys())
+    }
+}
+
+impl<C: GetKeys, K: 'static> Deref for I18nScope<C, K> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<C: GetKeys, K: 'static> Clone for I18nScope<C, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GetKeys, K: 'static> Copy for I18nScope<C, K> {}
+
+impl<C: GetKeys + std::fmt::Debug, K: 'static> std::fmt::Debug for I18nScope<C, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("I18nScope").field(&self.inner).finish()
+    }
+}
+
+impl<C: GetKeys, K: 'static> GetKeys for I18nScope<C, K> {
+    type Keys = K;
+
+    #[inline]
+    fn get_keys(self) -> &'static Self::Keys {
+        I18nScope::get_keys(self)
+    }
+}
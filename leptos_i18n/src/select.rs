@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+
+/// Values selecting a variant of a select value: `{ "@@select": "gender", "female": "...", "other": "..." }`.
+///
+/// Implemented for the string types and for the enums generated in the `select` module of `i18n`,
+/// it can be implemented for your own types. A variant not declared in a locale selects the `other` value.
+pub trait SelectVariant {
+    /// Return the name of the variant.
+    fn variant(&self) -> &str;
+}
+
+impl SelectVariant for str {
+    fn variant(&self) -> &str {
+        self
+    }
+}
+
+impl SelectVariant for String {
+    fn variant(&self) -> &str {
+        self
+    }
+}
+
+impl SelectVariant for Cow<'_, str> {
+    fn variant(&self) -> &str {
+        self
+    }
+}
+
+impl<T: SelectVariant + ?Sized> SelectVariant for &T {
+    fn variant(&self) -> &str {
+        T::variant(self)
+    }
+}
+
+/// Functions returning the variant of a select value, the values of the variables selecting the values.
+#[doc(hidden)]
+pub trait SelectFn: Clone + 'static {
+    /// Return the index of the variant in the variants declared by the locale, `None` for the `other` value.
+    fn select(&self, variants: &[&str]) -> Option<usize>;
+}
+
+impl<F: Fn() -> T + Clone + 'static, T: SelectVariant> SelectFn for F {
+    fn select(&self, variants: &[&str]) -> Option<usize> {
+        let value = self();
+        let variant = value.variant();
+        variants.iter().position(|name| *name == variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_fn() {
+        let variants = ["female", "male"];
+        assert_eq!((|| "male").select(&variants), Some(1));
+        assert_eq!((|| String::from("female")).select(&variants), Some(0));
+        assert_eq!((|| "unknown").select(&variants), None);
+        assert_eq!((|| Cow::Borrowed("male")).select(&[]), None);
+    }
+}
@@ -1,32 +1,75 @@
-use crate::locale_traits::*;
+use crate::{locale_traits::*, negotiation::RequestSources};
 use actix_web::http::header;
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 pub fn fetch_locale_server<T: Locale>() -> T {
     // when leptos_router inspect the routes it execute the code once but don't set an HttpRequest in the context,
     // so we can't expect it to be present.
     leptos::use_context::<actix_web::HttpRequest>()
-        .map(|req| from_req(&req))
+        .map(|req| request_locale(&req))
         .unwrap_or_default()
 }
 
-fn from_req<T: Locale>(req: &actix_web::HttpRequest) -> T {
-    #[cfg(feature = "cookie")]
-    if let Some(pref) = req
-        .cookie(crate::COOKIE_PREFERED_LANG)
-        .and_then(|ck| T::from_str(ck.value()))
-    {
-        return pref;
-    }
+pub fn fetch_requested_locales_server() -> Vec<String> {
+    leptos::use_context::<actix_web::HttpRequest>()
+        .and_then(|req| {
+            req.headers()
+                .get(header::ACCEPT_LANGUAGE)
+                .map(crate::parse_accept_language_header)
+        })
+        .unwrap_or_default()
+}
+
+/// Return the locale for the request, negotiated from the sources of `Locale::NEGOTIATION` in order:
+/// by default the cookie set by the client, then the `Accept-Language` header.
+///
+/// Useful to localize the responses of API routes, that are not rendered with the i18n context.
+pub fn request_locale<T: Locale>(req: &actix_web::HttpRequest) -> T {
+    let cookie = if cfg!(feature = "cookie") && T::STORAGE == crate::StorageKind::Cookie {
+        req.cookie(T::COOKIE_NAME)
+    } else {
+        None
+    };
 
-    let Some(header) = req
+    let accept_language = req
         .headers()
         .get(header::ACCEPT_LANGUAGE)
-        .and_then(|header| header.to_str().ok())
-    else {
-        return Default::default();
-    };
+        .map(crate::parse_accept_language_header);
+
+    #[cfg(feature = "tracing")]
+    tracing::trace!(accept_language = ?accept_language, "parsed Accept-Language header");
 
-    let langs = super::parse_header(header);
+    RequestSources {
+        cookie: cookie.as_ref().map(|ck| ck.value()),
+        accept_language,
+        path: Some(req.path()),
+    }
+    .negotiate()
+}
 
-    T::find_locale(&langs)
+/// Return a response with the localized HTML error page at the given key path, see `render_error_page`,
+/// with the locale negotiated for the request.
+///
+/// Meant for default services and the `ErrorHandlers` middleware, that run before the i18n context exists:
+///
+/// ```rust, ignore
+/// let error_handlers = ErrorHandlers::new().handler(StatusCode::NOT_FOUND, |res: ServiceResponse| {
+///     let (req, _) = res.into_parts();
+///     let res = leptos_i18n::error_page_response::<Locale>(&req, StatusCode::NOT_FOUND, "errors.404");
+///     Ok(ErrorHandlerResponse::Response(ServiceResponse::new(req, res).map_into_right_body()))
+/// });
+/// ```
+///
+/// If the keys don't exist the page only contains the status code and its reason.
+pub fn error_page_response<T: Locale>(
+    req: &actix_web::HttpRequest,
+    status: actix_web::http::StatusCode,
+    key_path: &str,
+) -> actix_web::HttpResponse {
+    let locale: T = request_locale(req);
+    let body = crate::render_error_page(locale, key_path).unwrap_or_else(|| status.to_string());
+    actix_web::HttpResponse::build(status)
+        .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+        .insert_header((header::CONTENT_LANGUAGE, locale.as_str()))
+        .body(body)
 }
@@ -1,50 +1,98 @@
-use crate::locale_traits::*;
-use axum::http::header;
+use crate::{locale_traits::*, negotiation::RequestSources};
+use axum::http::{header, HeaderMap};
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 pub fn fetch_locale_server<T: Locale>() -> T {
     // when leptos_router inspect the routes it execute the code once but don't set a RequestParts in the context,
     // so we can't expect it to be present.
     leptos::use_context::<leptos_axum::RequestParts>()
-        .map(|req| from_req(&req))
+        .map(|req| negotiate_request(&req.headers, Some(req.uri.path())))
         .unwrap_or_default()
 }
 
-fn from_req<T: Locale>(req: &leptos_axum::RequestParts) -> T {
-    #[cfg(feature = "cookie")]
-    if let Some(pref_lang_cookie) = get_prefered_lang_cookie::<T>(req) {
-        return pref_lang_cookie;
-    }
+pub fn fetch_requested_locales_server() -> Vec<String> {
+    leptos::use_context::<leptos_axum::RequestParts>()
+        .and_then(|req| {
+            req.headers
+                .get(header::ACCEPT_LANGUAGE)
+                .map(crate::parse_accept_language_header)
+        })
+        .unwrap_or_default()
+}
 
-    let Some(header) = req
-        .headers
+/// Return the locale for the request with the given headers, negotiated from the sources of `Locale::NEGOTIATION` in order:
+/// by default the cookie set by the client, then the `Accept-Language` header.
+///
+/// The URL of the request is not known from its headers, so the `url` source is skipped.
+///
+/// Useful to localize the responses of API routes, that are not rendered with the i18n context.
+pub fn request_locale<T: Locale>(headers: &HeaderMap) -> T {
+    negotiate_request(headers, None)
+}
+
+fn negotiate_request<T: Locale>(headers: &HeaderMap, path: Option<&str>) -> T {
+    let accept_language = headers
         .get(header::ACCEPT_LANGUAGE)
-        .and_then(|header| header.to_str().ok())
-    else {
-        return Default::default();
-    };
+        .map(crate::parse_accept_language_header);
 
-    let langs = super::parse_header(header);
+    #[cfg(feature = "tracing")]
+    tracing::trace!(accept_language = ?accept_language, "parsed Accept-Language header");
 
-    T::find_locale(&langs)
+    let cookie = if cfg!(feature = "cookie") && T::STORAGE == crate::StorageKind::Cookie {
+        get_cookie(headers, T::COOKIE_NAME)
+    } else {
+        None
+    };
+
+    RequestSources {
+        cookie,
+        accept_language,
+        path,
+    }
+    .negotiate()
 }
 
-#[cfg(feature = "cookie")]
-fn get_prefered_lang_cookie<T: Locale>(req: &leptos_axum::RequestParts) -> Option<T> {
-    req.headers
+fn get_cookie<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
         .get_all(header::COOKIE)
         .into_iter()
-        .filter_map(parse_cookie)
-        .filter_map(T::from_str)
-        .next()
+        .find_map(|cookie| parse_cookie(cookie, name))
 }
 
-#[cfg(feature = "cookie")]
-fn parse_cookie(cookie: &axum::http::HeaderValue) -> Option<&str> {
+fn parse_cookie<'a>(cookie: &'a axum::http::HeaderValue, name: &str) -> Option<&'a str> {
     std::str::from_utf8(cookie.as_bytes())
         .ok()?
         .split(';')
         .map(|s| s.trim())
         .filter_map(|s| s.split_once('='))
-        .find(|(name, _)| name == &crate::COOKIE_PREFERED_LANG)
+        .find(|(cookie_name, _)| *cookie_name == name)
         .map(|(_, value)| value)
 }
+
+/// Return a response with the localized HTML error page at the given key path, see `render_error_page`,
+/// with the locale negotiated for the request with the given headers.
+///
+/// Meant for fallback handlers and error layers, that run before the i18n context exists:
+///
+/// ```rust, ignore
+/// let app = Router::new().fallback(|headers: HeaderMap| async move {
+///     leptos_i18n::error_page_response::<Locale>(&headers, StatusCode::NOT_FOUND, "errors.404")
+/// });
+/// ```
+///
+/// If the keys don't exist the page only contains the status code and its reason.
+pub fn error_page_response<T: Locale>(
+    headers: &HeaderMap,
+    status: axum::http::StatusCode,
+    key_path: &str,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let locale: T = request_locale(headers);
+    let body = crate::render_error_page(locale, key_path).unwrap_or_else(|| status.to_string());
+    let headers = [
+        (header::CONTENT_TYPE, "text/html; charset=utf-8"),
+        (header::CONTENT_LANGUAGE, locale.as_str()),
+    ];
+    (status, headers, body).into_response()
+}
@@ -0,0 +1,167 @@
+use std::{marker::PhantomData, ops::Deref};
+
+use crate::{I18nContext, Locale};
+
+/// Trait implemented by the struct representing the translation keys of a shard, generated by `load_locales!(shard = "..")`.
+///
+/// It is the counterpart of `LocaleKeys` for the locale of the application, that is shared by all the shards.
+pub trait ShardKeys: 'static + Clone + Copy {
+    /// The enum representing the supported locales of the application.
+    type Locale: Locale;
+
+    /// Return the keys of the shard for the given locale.
+    fn from_variant(variant: Self::Locale) -> &'static Self;
+}
+
+/// Context giving access to the keys of a shard, returned by the `use_i18n` function generated by `load_locales!(shard = "..")`.
+///
+/// The locale is the one of the `I18nContext` of the application, that it derefs to.
+pub struct I18nShardContext<K: ShardKeys> {
+    context: I18nContext<K::Locale>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: ShardKeys> I18nShardContext<K> {
+    /// Wrap the context of the application.
+    #[inline]
+    pub fn new(context: I18nContext<K::Locale>) -> Self {
+        I18nShardContext {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the context of the application.
+    #[inline]
+    pub fn context(self) -> I18nContext<K::Locale> {
+        self.context
+    }
+
+    /// Return the keys of the shard for the current locale subscribing to any changes.
+    #[inline]
+    pub fn get_keys(self) -> &'static K {
+        K::from_variant(self.context.get_locale())
+    }
+
+    /// Return the keys of the shard for the current locale but does not subscribe to changes.
+    #[inline]
+    pub fn get_keys_untracked(self) -> &'static K {
+        K::from_variant(self.context.get_locale_untracked())
+    }
+}
+
+impl<K: ShardKeys> Deref for I18nShardContext<K> {
+    type Target = I18nContext<K::Locale>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+impl<K: ShardKeys> Clone for I18nShardContext<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: ShardKeys> Copy for I18nShardContext<K> {}
+
+impl<K: ShardKeys> std::fmt::Debug for I18nShardContext<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("I18nShardContext")
+            .field(&self.context.get_locale_untracked().as_str())
+            .finish()
+    }
+}
+
+/// A locale giving access to the keys of a shard, to be used with `td!`.
+///
+/// Returned by the `locale` function generated by `load_locales!(shard = "..")`.
+pub struct ShardLocale<K: ShardKeys> {
+    locale: K::Locale,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: ShardKeys> ShardLocale<K> {
+    /// Wrap the locale.
+    #[inline]
+    pub fn new(locale: K::Locale) -> Self {
+        ShardLocale {
+            locale,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the locale.
+    #[inline]
+    pub fn locale(self) -> K::Locale {
+        self.locale
+    }
+
+    /// Return the keys of the shard for the locale.
+    #[inline]
+    pub fn get_keys(self) -> &'static K {
+        K::from_variant(self.locale)
+    }
+}
+
+impl<K: ShardKeys> Clone for ShardLocale<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: ShardKeys> Copy for ShardLocale<K> {}
+
+impl<K: ShardKeys> std::fmt::Debug for ShardLocale<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ShardLocale")
+            .field(&self.locale.as_str())
+            .finish()
+    }
+}
+
+/// Access to the keys used by the `t!` and `td!` macros, implemented for the locales, the contexts, their shard counterparts and their scopes.
+pub trait GetKeys: Copy {
+    /// The struct containing the translations.
+    type Keys: 'static;
+
+    /// Return the keys, subscribing to the changes of the locale for the contexts.
+    fn get_keys(self) -> &'static Self::Keys;
+}
+
+impl<T: Locale> GetKeys for T {
+    type Keys = T::Keys;
+
+    #[inline]
+    fn get_keys(self) -> &'static Self::Keys {
+        Locale::get_keys(self)
+    }
+}
+
+impl<T: Locale> GetKeys for I18nContext<T> {
+    type Keys = T::Keys;
+
+    #[inline]
+    fn get_keys(self) -> &'static Self::Keys {
+        I18nContext::get_keys(self)
+    }
+}
+
+impl<K: ShardKeys> GetKeys for I18nShardContext<K> {
+    type Keys = K;
+
+    #[inline]
+    fn get_keys(self) -> &'static Self::Keys {
+        I18nShardContext::get_keys(self)
+    }
+}
+
+impl<K: ShardKeys> GetKeys for ShardLocale<K> {
+    type Keys = K;
+
+    #[inline]
+    fn get_keys(self) -> &'static Self::Keys {
+        ShardLocale::get_keys(self)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::Locale;
+
+/// Return the variant of every path for every locale, prefixed by the locale tag (`/about` give `/en/about`, `/fr/about`, ...).
+///
+/// Meant for static site generation, to emit every language variant of every page.
+pub fn localized_paths<T, I>(paths: I) -> Vec<String>
+where
+    T: Locale,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let paths = paths.into_iter().collect::<Vec<_>>();
+    T::get_all()
+        .iter()
+        .flat_map(|locale| {
+            paths
+                .iter()
+                .map(move |path| localized_path(*locale, path.as_ref()))
+        })
+        .collect()
+}
+
+/// Prefix the path with the locale tag.
+pub fn localized_path<T: Locale>(locale: T, path: &str) -> String {
+    match path.trim_start_matches('/') {
+        "" => format!("/{}", locale.as_str()),
+        path => format!("/{}/{}", locale.as_str(), path),
+    }
+}
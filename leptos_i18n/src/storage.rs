@@ -0,0 +1,394 @@
+use leptos::*;
+
+use crate::Locale;
+
+/// Trait for backends persisting the locale chosen by the user.
+///
+/// The stored locale is loaded when the context is created and takes precedence over the negotiated locale,
+/// and the locale is saved on the client each time it is set.
+///
+/// When hydrating, the locale rendered by the server is always used so the stored locale must also be readable on the server,
+/// for example from a cookie or from a server-side session.
+///
+/// Used with `provide_i18n_context_with_storage`, by default the storage is chosen by the `locale-storage` option of the configuration,
+/// see `StorageKind`.
+pub trait LocaleStorage: 'static {
+    /// Return the tag of the stored locale, if any.
+    fn load(&self) -> Option<String>;
+
+    /// Persist the tag of the chosen locale.
+    fn save(&self, locale: &str);
+}
+
+/// Where `provide_i18n_context` persists the locale chosen by the user.
+///
+/// Set with the `locale-storage` option of the configuration, see `Locale::STORAGE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StorageKind {
+    /// A cookie named by `Locale::COOKIE_NAME`, with the attributes of `Locale::COOKIE_ATTRIBUTES`. Requires the `cookie` feature.
+    ///
+    /// The server reads it, so the page is rendered in the chosen locale and hydrated without switching language.
+    #[default]
+    Cookie,
+    /// The `localStorage` of the browser, under the key named by `Locale::COOKIE_NAME`.
+    ///
+    /// The server can not read it, so it is only loaded with the `csr` feature.
+    LocalStorage,
+    /// The locale is not persisted.
+    None,
+}
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SameSite {
+    /// The cookie is only sent with requests from the same site.
+    Strict,
+    /// The cookie is also sent when navigating to the site from another one.
+    #[default]
+    Lax,
+    /// The cookie is sent with every request, it must be `Secure`.
+    None,
+}
+
+impl SameSite {
+    /// Return the value of the attribute.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// The attributes of the cookie saving the locale.
+///
+/// Set with the `cookie-max-age`, `cookie-same-site`, `cookie-secure` and `cookie-path` options of the configuration,
+/// see `Locale::COOKIE_ATTRIBUTES`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CookieAttributes {
+    /// Number of seconds the cookie is kept, one year by default.
+    pub max_age: u64,
+    /// `Lax` by default.
+    pub same_site: SameSite,
+    /// Whether the cookie is only sent over HTTPS, `true` by default.
+    pub secure: bool,
+    /// The path the cookie is sent for, `"/"` by default.
+    pub path: &'static str,
+}
+
+impl CookieAttributes {
+    /// The attributes used when none are configured.
+    pub const DEFAULT: Self = CookieAttributes {
+        max_age: 60 * 60 * 24 * 365,
+        same_site: SameSite::Lax,
+        secure: true,
+        path: "/",
+    };
+
+    /// Return the cookie with the given name and value and these attributes,
+    /// as set with `document.cookie` or in a `Set-Cookie` header.
+    pub fn cookie(&self, name: &str, value: &str) -> String {
+        let secure = if self.secure { "; Secure" } else { "" };
+        format!(
+            "{}={}; SameSite={}{}; Path={}; Max-Age={}",
+            name,
+            value,
+            self.same_site.as_str(),
+            secure,
+            self.path,
+            self.max_age
+        )
+    }
+}
+
+impl Default for CookieAttributes {
+    fn default() -> Self {
+        CookieAttributes::DEFAULT
+    }
+}
+
+/// Storage that does not persist the locale.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoStorage;
+
+impl LocaleStorage for NoStorage {
+    fn load(&self) -> Option<String> {
+        None
+    }
+
+    fn save(&self, _locale: &str) {}
+}
+
+/// Storage saving the locale only once the user consented to it, for example to a cookie banner.
+///
+/// Until consent is given the chosen locale is kept in memory, and it is saved with the wrapped storage as soon as consent is granted.
+/// The stored locale is always loaded, and the negotiation of the locale (such as with the `Accept-Language` header) is not affected.
+///
+/// It is `Copy`, so the handle given to `provide_i18n_context_with_storage` can be kept to grant the consent later:
+///
+/// ```rust, ignore
+/// let consent = ConsentStorage::new(CookieStorage::for_locale::<Locale>(), false);
+/// provide_i18n_context_with_storage(consent);
+///
+/// view! {
+///     <button on:click=move |_| consent.grant()>"Accept cookies"</button>
+/// }
+/// ```
+pub struct ConsentStorage<S: LocaleStorage> {
+    storage: StoredValue<S>,
+    granted: RwSignal<bool>,
+    pending: StoredValue<Option<String>>,
+}
+
+impl<S: LocaleStorage> ConsentStorage<S> {
+    /// Wrap the storage, with the consent initially granted or not.
+    pub fn new(storage: S, granted: bool) -> Self {
+        ConsentStorage {
+            storage: store_value(storage),
+            granted: create_rw_signal(granted),
+            pending: store_value(None),
+        }
+    }
+
+    /// Grant the consent, saving the locale chosen in the meantime if any.
+    pub fn grant(self) {
+        self.granted.set(true);
+        if let Some(locale) = self.pending.try_update_value(Option::take).flatten() {
+            self.storage.with_value(|storage| storage.save(&locale));
+        }
+    }
+
+    /// Revoke the consent, the next chosen locales are kept in memory.
+    ///
+    /// The locale already saved is not removed from the storage.
+    pub fn revoke(self) {
+        self.granted.set(false);
+    }
+
+    /// Return whether the consent is granted, subscribing to any changes.
+    pub fn is_granted(self) -> bool {
+        self.granted.get()
+    }
+}
+
+impl<S: LocaleStorage> Clone for ConsentStorage<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: LocaleStorage> Copy for ConsentStorage<S> {}
+
+impl<S: LocaleStorage> std::fmt::Debug for ConsentStorage<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsentStorage")
+            .field("granted", &self.granted.get_untracked())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: LocaleStorage> LocaleStorage for ConsentStorage<S> {
+    fn load(&self) -> Option<String> {
+        self.storage.with_value(LocaleStorage::load)
+    }
+
+    fn save(&self, locale: &str) {
+        if self.granted.get_untracked() {
+            self.storage.with_value(|storage| storage.save(locale));
+        } else {
+            self.pending.set_value(Some(locale.to_string()));
+        }
+    }
+}
+
+/// Storage persisting the locale in a cookie on the client.
+///
+/// On the server the cookie is read from the request by the `actix` or `axum` integration, with the name given by the `cookie-name` option
+/// of the configuration, so use `CookieStorage::for_locale` to keep the same name on the client.
+#[cfg(feature = "cookie")]
+#[derive(Debug, Clone, Copy)]
+pub struct CookieStorage {
+    name: &'static str,
+    attributes: CookieAttributes,
+}
+
+#[cfg(feature = "cookie")]
+impl CookieStorage {
+    /// Storage using the cookie with the given name, with the default attributes.
+    pub const fn new(name: &'static str) -> Self {
+        CookieStorage {
+            name,
+            attributes: CookieAttributes::DEFAULT,
+        }
+    }
+
+    /// Storage using the cookie named by the `cookie-name` option of the configuration, see `Locale::COOKIE_NAME`,
+    /// with the attributes of `Locale::COOKIE_ATTRIBUTES`.
+    pub const fn for_locale<T: Locale>() -> Self {
+        CookieStorage::new(T::COOKIE_NAME).with_attributes(T::COOKIE_ATTRIBUTES)
+    }
+
+    /// Set the attributes of the cookie.
+    pub const fn with_attributes(mut self, attributes: CookieAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Return the name of the cookie.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the attributes of the cookie.
+    pub const fn attributes(&self) -> CookieAttributes {
+        self.attributes
+    }
+}
+
+/// Storage using the cookie with the default name, `"i18n_pref_locale"`.
+#[cfg(feature = "cookie")]
+impl Default for CookieStorage {
+    fn default() -> Self {
+        CookieStorage::new(crate::COOKIE_PREFERED_LANG)
+    }
+}
+
+#[cfg(feature = "cookie")]
+impl LocaleStorage for CookieStorage {
+    #[cfg(any(feature = "hydrate", feature = "csr"))]
+    fn load(&self) -> Option<String> {
+        let document = crate::get_html_document()?;
+        let cookies = document.cookie().ok()?;
+        cookies.split(';').find_map(|cookie| {
+            let (key, value) = cookie.split_once('=')?;
+            (key.trim() == self.name).then(|| value.to_string())
+        })
+    }
+
+    #[cfg(not(any(feature = "hydrate", feature = "csr")))]
+    fn load(&self) -> Option<String> {
+        None
+    }
+
+    #[cfg(any(feature = "hydrate", feature = "csr"))]
+    fn save(&self, locale: &str) {
+        let Some(document) = crate::get_html_document() else {
+            return;
+        };
+        let _ = document.set_cookie(&self.attributes.cookie(self.name, locale));
+    }
+
+    #[cfg(not(any(feature = "hydrate", feature = "csr")))]
+    fn save(&self, _locale: &str) {}
+}
+
+/// Storage persisting the locale in the `localStorage` of the browser.
+///
+/// The server can not read it, so it is meant for client side rendered applications:
+/// the locale is only loaded with the `csr` feature, when hydrating the locale rendered by the server is kept.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalStorage {
+    key: &'static str,
+}
+
+impl LocalStorage {
+    /// Storage using the given key.
+    pub const fn new(key: &'static str) -> Self {
+        LocalStorage { key }
+    }
+
+    /// Storage using the key named by the `cookie-name` option of the configuration, see `Locale::COOKIE_NAME`.
+    pub const fn for_locale<T: Locale>() -> Self {
+        LocalStorage::new(T::COOKIE_NAME)
+    }
+
+    /// Return the key of the locale in the `localStorage`.
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+}
+
+impl LocaleStorage for LocalStorage {
+    #[cfg(feature = "csr")]
+    fn load(&self) -> Option<String> {
+        let storage = leptos::window().local_storage().ok()??;
+        storage.get_item(self.key).ok()?
+    }
+
+    #[cfg(not(feature = "csr"))]
+    fn load(&self) -> Option<String> {
+        None
+    }
+
+    #[cfg(feature = "csr")]
+    fn save(&self, locale: &str) {
+        if let Ok(Some(storage)) = leptos::window().local_storage() {
+            let _ = storage.set_item(self.key, locale);
+        }
+    }
+
+    #[cfg(not(feature = "csr"))]
+    fn save(&self, _locale: &str) {}
+}
+
+/// The storage used by `provide_i18n_context`, chosen by `Locale::STORAGE`.
+pub(crate) enum DefaultStorage {
+    #[cfg(feature = "cookie")]
+    Cookie(CookieStorage),
+    LocalStorage(LocalStorage),
+    None,
+}
+
+impl LocaleStorage for DefaultStorage {
+    fn load(&self) -> Option<String> {
+        match self {
+            #[cfg(feature = "cookie")]
+            DefaultStorage::Cookie(storage) => storage.load(),
+            DefaultStorage::LocalStorage(storage) => storage.load(),
+            DefaultStorage::None => None,
+        }
+    }
+
+    fn save(&self, locale: &str) {
+        match self {
+            #[cfg(feature = "cookie")]
+            DefaultStorage::Cookie(storage) => storage.save(locale),
+            DefaultStorage::LocalStorage(storage) => storage.save(locale),
+            DefaultStorage::None => {}
+        }
+    }
+}
+
+/// Return the storage used by `provide_i18n_context`, the cookie being only used with the `cookie` feature.
+pub(crate) fn default_storage<T: Locale>() -> DefaultStorage {
+    match T::STORAGE {
+        #[cfg(feature = "cookie")]
+        StorageKind::Cookie => DefaultStorage::Cookie(CookieStorage::for_locale::<T>()),
+        StorageKind::LocalStorage => DefaultStorage::LocalStorage(LocalStorage::for_locale::<T>()),
+        _ => DefaultStorage::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cookie_attributes() {
+        assert_eq!(
+            CookieAttributes::DEFAULT.cookie("lang", "fr"),
+            "lang=fr; SameSite=Lax; Secure; Path=/; Max-Age=31536000"
+        );
+        let attributes = CookieAttributes {
+            max_age: 3600,
+            same_site: SameSite::Strict,
+            secure: false,
+            path: "/app",
+        };
+        assert_eq!(
+            attributes.cookie("lang", "en-GB"),
+            "lang=en-GB; SameSite=Strict; Path=/app; Max-Age=3600"
+        );
+    }
+}
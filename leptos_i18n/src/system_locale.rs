@@ -0,0 +1,104 @@
+use crate::Locale;
+
+/// Return the locales of the system, sorted in preferred order.
+///
+/// On `wasm32` targets (such as the webview of a Tauri application) this read `navigator.languages`,
+/// on other targets this ask the OS with [`sys-locale`](https://docs.rs/sys-locale) (the user languages on Windows and macOS),
+/// falling back to the POSIX environment variables `LANGUAGE`, `LC_ALL`, `LC_MESSAGES` and `LANG`.
+///
+/// For each regional locale (`fr-FR`) the bare language (`fr`) is added at the end of the list as a fallback.
+pub fn get_system_locales() -> Vec<String> {
+    let mut locales = get_system_locales_inner();
+    let languages = locales
+        .iter()
+        .filter_map(|locale| locale.split_once('-'))
+        .map(|(language, _)| language.to_string())
+        .collect::<Vec<_>>();
+    for language in languages {
+        if !locales.contains(&language) {
+            locales.push(language);
+        }
+    }
+    locales
+}
+
+/// Return the locale that match the best the locales of the system.
+pub fn fetch_system_locale<T: Locale>() -> T {
+    T::find_locale(&get_system_locales())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_system_locales_inner() -> Vec<String> {
+    let Some(window) = web_sys::window() else {
+        return vec![];
+    };
+    window
+        .navigator()
+        .languages()
+        .iter()
+        .filter_map(|lang| lang.as_string())
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_system_locales_inner() -> Vec<String> {
+    let mut locales = vec![];
+    for locale in sys_locale::get_locales().filter_map(|locale| parse_posix_locale(&locale)) {
+        if !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+    if locales.is_empty() {
+        locales = get_env_locales();
+    }
+    locales
+}
+
+/// Read the locales from the POSIX environment variables, in case the OS don't give any.
+#[cfg(not(target_arch = "wasm32"))]
+fn get_env_locales() -> Vec<String> {
+    let mut locales = vec![];
+    if let Ok(languages) = std::env::var("LANGUAGE") {
+        locales.extend(languages.split(':').filter_map(parse_posix_locale));
+    }
+    for var in ["LC_ALL", "LC_MESSAGES", "LANG"] {
+        let Some(locale) = std::env::var(var)
+            .ok()
+            .as_deref()
+            .and_then(parse_posix_locale)
+        else {
+            continue;
+        };
+        if !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+    locales
+}
+
+/// Convert a POSIX locale (`language[_territory][.codeset][@modifier]`) to a BCP-47 like tag.
+#[cfg(any(not(target_arch = "wasm32"), test))]
+fn parse_posix_locale(locale: &str) -> Option<String> {
+    let locale = locale.trim();
+    let locale = locale.split_once('@').map_or(locale, |(locale, _)| locale);
+    let locale = locale.split_once('.').map_or(locale, |(locale, _)| locale);
+    match locale {
+        "" | "C" | "POSIX" => None,
+        locale => Some(locale.replace('_', "-")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_posix_locale() {
+        assert_eq!(parse_posix_locale("fr_FR.UTF-8"), Some("fr-FR".to_string()));
+        assert_eq!(parse_posix_locale("de_DE@euro"), Some("de-DE".to_string()));
+        assert_eq!(parse_posix_locale("en"), Some("en".to_string()));
+        assert_eq!(parse_posix_locale("C.UTF-8"), None);
+        assert_eq!(parse_posix_locale("POSIX"), None);
+        assert_eq!(parse_posix_locale(""), None);
+    }
+}
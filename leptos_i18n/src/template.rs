@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+use crate::{Locale, LocaleKeys};
+
+/// Part of a value without plurals, used to render a translation to a `String` at runtime.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemplatePart {
+    Str(&'static str),
+    Var(&'static str),
+    Comp(&'static str, &'static [TemplatePart]),
+}
+
+/// Customize how each part of a template is rendered.
+pub(crate) trait TemplateRenderer {
+    /// Push a string of the template to the output.
+    fn render_str(&mut self, s: &str, output: &mut String) {
+        output.push_str(s)
+    }
+
+    /// Return the value of a variable.
+    fn get_var(&mut self, name: &str) -> Option<String>;
+
+    /// Render a component with its rendered children, `None` if components are not supported.
+    fn render_comp(&mut self, _name: &str, _children: String) -> Option<String> {
+        None
+    }
+}
+
+/// Render the template with the given renderer.
+///
+/// Variables without a value are rendered as `{{ name }}`, and `None` is returned if a component can't be rendered.
+pub(crate) fn render_template<R: TemplateRenderer>(
+    parts: &[TemplatePart],
+    renderer: &mut R,
+) -> Option<String> {
+    let mut output = String::new();
+    for part in parts {
+        match part {
+            TemplatePart::Str(s) => renderer.render_str(s, &mut output),
+            TemplatePart::Var(name) => match renderer.get_var(name) {
+                Some(value) => output.push_str(&value),
+                None => {
+                    output.push_str("{{ ");
+                    output.push_str(name);
+                    output.push_str(" }}");
+                }
+            },
+            TemplatePart::Comp(name, children) => {
+                let children = render_template(children, renderer)?;
+                output.push_str(&renderer.render_comp(name, children)?);
+            }
+        }
+    }
+    Some(output)
+}
+
+/// The value of a key found at runtime, a plain string or a template.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Translation {
+    Str(&'static str),
+    Template(&'static [TemplatePart]),
+}
+
+impl Translation {
+    /// Render the value with the given renderer, see `render_template`.
+    pub(crate) fn render<R: TemplateRenderer>(self, renderer: &mut R) -> Option<String> {
+        match self {
+            Translation::Str(s) => {
+                let mut output = String::new();
+                renderer.render_str(s, &mut output);
+                Some(output)
+            }
+            Translation::Template(template) => render_template(template, renderer),
+        }
+    }
+}
+
+/// Return the path of the key in the key table, accepting `namespace.key` for `namespace::key`.
+fn resolve_key_path<T: Locale>(key_path: &str) -> Option<Cow<'_, str>> {
+    let key_paths = T::key_paths();
+    if key_paths.binary_search(&key_path).is_ok() {
+        return Some(Cow::Borrowed(key_path));
+    }
+    if key_path.contains("::") {
+        return None;
+    }
+    let (namespace, rest) = key_path.split_once('.')?;
+    let key_path = format!("{}::{}", namespace, rest);
+    key_paths
+        .binary_search(&key_path.as_str())
+        .is_ok()
+        .then_some(Cow::Owned(key_path))
+}
+
+/// Find the value of the key at the given path for the locale, the key path using the same syntax as foreign keys,
+/// `namespace.key.subkey` being also accepted for `namespace::key.subkey`.
+///
+/// Return `None` if the key does not exist or if its value contains plurals.
+pub(crate) fn lookup<T: Locale>(locale: T, key_path: &str) -> Option<Translation> {
+    let key_path = resolve_key_path::<T>(key_path)?;
+    let keys = locale.get_keys();
+    if let Some(s) = keys.get_string(&key_path) {
+        return Some(Translation::Str(s));
+    }
+    keys.get_template(&key_path).map(Translation::Template)
+}
+
+/// Find the value of the key at the given path for the locale and render it with the given renderer,
+/// shared by every function translating keys known at runtime.
+pub(crate) fn render_key<T: Locale, R: TemplateRenderer>(
+    locale: T,
+    key_path: &str,
+    renderer: &mut R,
+) -> Option<String> {
+    lookup(locale, key_path)?.render(renderer)
+}
+
+pub(crate) struct PlainRenderer<V>(pub V);
+
+impl<V: FnMut(&str) -> Option<String>> TemplateRenderer for PlainRenderer<V> {
+    fn get_var(&mut self, name: &str) -> Option<String> {
+        (self.0)(name)
+    }
+}
+
+/// Render the template to a plain string, returning `None` if it contains components.
+pub(crate) fn render_plain_template<V>(parts: &[TemplatePart], get_var: V) -> Option<String>
+where
+    V: FnMut(&str) -> Option<String>,
+{
+    render_template(parts, &mut PlainRenderer(get_var))
+}
+
+/// Write a component of an interpolation with its children to the formatter, used by the `Display` implementation of the interpolations.
+///
+/// Only the children are written, unless the alternate flag is set (`{:#}`), then the component is written as a `<name>` tag.
+pub fn display_component<F>(
+    formatter: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    children: F,
+) -> std::fmt::Result
+where
+    F: FnOnce(&mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+{
+    if formatter.alternate() {
+        write!(formatter, "<{}>", name)?;
+        children(formatter)?;
+        write!(formatter, "</{}>", name)
+    } else {
+        children(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let parts = [
+            TemplatePart::Str("must be at least "),
+            TemplatePart::Var("min"),
+            TemplatePart::Str(" characters, "),
+            TemplatePart::Var("missing"),
+        ];
+        let rendered =
+            render_plain_template(&parts, |name| (name == "min").then(|| "8".to_string()));
+        assert_eq!(
+            rendered.as_deref(),
+            Some("must be at least 8 characters, {{ missing }}")
+        );
+    }
+
+    #[test]
+    fn test_render_template_components() {
+        let parts = [
+            TemplatePart::Str("click "),
+            TemplatePart::Comp("b", &[TemplatePart::Str("here")]),
+        ];
+        assert_eq!(render_plain_template(&parts, |_| None), None);
+        struct TagRenderer;
+        impl TemplateRenderer for TagRenderer {
+            fn get_var(&mut self, _name: &str) -> Option<String> {
+                None
+            }
+
+            fn render_comp(&mut self, name: &str, children: String) -> Option<String> {
+                Some(format!("<{name}>{children}</{name}>"))
+            }
+        }
+        let rendered = render_template(&parts, &mut TagRenderer);
+        assert_eq!(rendered.as_deref(), Some("click <b>here</b>"));
+    }
+}
@@ -0,0 +1,214 @@
+//! Helpers to unit test components using the i18n context, without a browser or a server.
+//!
+//! ```rust, ignore
+//! use leptos_i18n::testing::TestI18n;
+//!
+//! #[test]
+//! fn counter() {
+//!     let i18n = TestI18n::new(Locale::en);
+//!     i18n.assert_rendered(|| view! { <Counter /> }, "<p>You clicked 0 times</p>");
+//!     i18n.set_locale(Locale::fr);
+//!     i18n.assert_rendered(|| view! { <Counter /> }, "<p>Vous avez cliqué 0 fois</p>");
+//! }
+//! ```
+
+use leptos::*;
+
+use crate::{I18nContext, Locale, LocaleKeys};
+
+/// A reactive runtime with an `I18nContext` set to a chosen locale, for tests.
+///
+/// Components calling `use_i18n` can be rendered with it, the runtime is disposed when it is dropped.
+#[derive(Debug)]
+pub struct TestI18n<T: Locale> {
+    runtime: RuntimeId,
+    context: I18nContext<T>,
+}
+
+impl<T: Locale> TestI18n<T> {
+    /// Create a runtime and provide a context set to the given locale.
+    ///
+    /// The locale is not loaded from a storage nor negotiated, see `provide_i18n_context_with`.
+    pub fn new(locale: T) -> Self {
+        let runtime = create_runtime();
+        leptos_meta::provide_meta_context();
+        let context = crate::provide_i18n_context_with(locale);
+        TestI18n { runtime, context }
+    }
+
+    /// Return the context.
+    pub fn context(&self) -> I18nContext<T> {
+        self.context
+    }
+
+    /// Return the current locale.
+    pub fn locale(&self) -> T {
+        self.context.get_locale_untracked()
+    }
+
+    /// Set the locale, notifying the subscribers.
+    pub fn set_locale(&self, locale: T) {
+        self.context.set_locale(locale)
+    }
+
+    /// Set the locale to the next one in the order of the configuration, going back to the first after the last one,
+    /// and return it.
+    pub fn next_locale(&self) -> T {
+        let all = T::get_all();
+        let current = self.locale().as_str();
+        let index = all
+            .iter()
+            .position(|locale| locale.as_str() == current)
+            .map_or(0, |index| (index + 1) % all.len());
+        let next = all[index];
+        self.set_locale(next);
+        next
+    }
+
+    /// Render the view to HTML, without the hydration markers added by Leptos.
+    pub fn render<F, N>(&self, view: F) -> String
+    where
+        F: FnOnce() -> N,
+        N: IntoView,
+    {
+        clean_rendered(&view().into_view().render_to_string())
+    }
+
+    /// Assert that the view renders to the expected HTML, see `render`.
+    #[track_caller]
+    pub fn assert_rendered<F, N>(&self, view: F, expected: &str)
+    where
+        F: FnOnce() -> N,
+        N: IntoView,
+    {
+        let rendered = self.render(view);
+        assert_eq!(
+            rendered,
+            expected,
+            "rendered view does not match in locale {:?}",
+            self.locale().as_str()
+        );
+    }
+
+    /// Render the view in every locale, in the order of the configuration, and return the locale with the rendered HTML.
+    ///
+    /// The view is created again for each locale, and the locale is restored afterward.
+    pub fn render_all<F, N>(&self, view: F) -> Vec<(T, String)>
+    where
+        F: Fn() -> N,
+        N: IntoView,
+    {
+        let current = self.locale();
+        let rendered = T::get_all()
+            .iter()
+            .map(|locale| {
+                self.set_locale(*locale);
+                (*locale, self.render(&view))
+            })
+            .collect();
+        self.set_locale(current);
+        rendered
+    }
+
+    /// Render every string and interpolated key of the current locale with placeholder values,
+    /// to check that none of them panics and to review the rendered HTML with snapshot tests.
+    ///
+    /// There is one `path = html` line per key, sorted by path. Variables are rendered as `{{ name }}`,
+    /// components as a `<name>` tag around their children, counts and formatted numbers as `0`,
+    /// formatted dates as January 1st 1970 at midnight and selects as their `other` value.
+    /// The keys with a typed variable of a type other than a number, a `bool`, a `char` or a string are skipped.
+    pub fn render_keys(&self) -> String {
+        let mut rendered = self
+            .locale()
+            .get_keys()
+            .render_keys()
+            .into_iter()
+            .map(|(path, view)| (path, clean_rendered(&view.render_to_string())))
+            .collect::<Vec<_>>();
+        rendered.sort_unstable();
+        let mut output = String::new();
+        for (path, html) in rendered {
+            output.push_str(&path);
+            output.push_str(" = ");
+            output.push_str(&html);
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Render every key in every locale, see `render_keys`, and return the locale with the rendered keys.
+    ///
+    /// The locale is restored afterward.
+    pub fn render_all_keys(&self) -> Vec<(T, String)> {
+        let current = self.locale();
+        let rendered = T::get_all()
+            .iter()
+            .map(|locale| {
+                self.set_locale(*locale);
+                (*locale, self.render_keys())
+            })
+            .collect();
+        self.set_locale(current);
+        rendered
+    }
+}
+
+impl<T: Locale> Drop for TestI18n<T> {
+    fn drop(&mut self) {
+        self.runtime.dispose();
+    }
+}
+
+/// Remove the comments and the `data-hk` attributes used for hydration, and decode the characters escaped by Leptos.
+fn clean_rendered(html: &str) -> String {
+    let html = remove_delimited(html, "<!--", "-->");
+    let html = remove_delimited(&html, " data-hk=\"", "\"");
+    decode_escaped_chars(&html)
+}
+
+fn remove_delimited(s: &str, start_delim: &str, end_delim: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some((before, after)) = rest.split_once(start_delim) {
+        output.push_str(before);
+        rest = after.split_once(end_delim).map_or("", |(_, after)| after);
+    }
+    output.push_str(rest);
+    output
+}
+
+fn decode_escaped_chars(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some((before, after)) = rest.split_once("&#x") {
+        output.push_str(before);
+        let decoded = after.split_once(';').and_then(|(code, after)| {
+            let c = char::from_u32(u32::from_str_radix(code, 16).ok()?)?;
+            Some((c, after))
+        });
+        match decoded {
+            Some((c, after)) => {
+                output.push(c);
+                rest = after;
+            }
+            None => {
+                output.push_str("&#x");
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_rendered() {
+        let html = "<!--hk=0-0|leptos-view|open--><p data-hk=\"0-1\">l&#x27;été</p><!--hk=0-0|leptos-view|close-->";
+        assert_eq!(clean_rendered(html), "<p>l'été</p>");
+        assert_eq!(clean_rendered("a &#xZZ; b"), "a &#xZZ; b");
+    }
+}
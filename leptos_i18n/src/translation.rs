@@ -0,0 +1,192 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Display};
+
+use crate::{
+    template::{lookup, render_plain_template, TemplatePart, Translation},
+    Locale,
+};
+
+/// Value of a variable, given to a `TranslationBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A string.
+    Str(Cow<'static, str>),
+    /// A signed integer.
+    Int(i64),
+    /// An unsigned integer.
+    UInt(u64),
+    /// A floating point number.
+    Float(f64),
+    /// A boolean.
+    Bool(bool),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Str(s) => f.write_str(s),
+            Value::Int(v) => Display::fmt(v, f),
+            Value::UInt(v) => Display::fmt(v, f),
+            Value::Float(v) => Display::fmt(v, f),
+            Value::Bool(v) => Display::fmt(v, f),
+        }
+    }
+}
+
+macro_rules! impl_from_for_value {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_value!(Str: &'static str, String, Cow<'static, str>);
+impl_from_for_value!(Int: i8, i16, i32, i64);
+impl_from_for_value!(UInt: u8, u16, u32, u64);
+impl_from_for_value!(Float: f32, f64);
+impl_from_for_value!(Bool: bool);
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::UInt(value as u64)
+    }
+}
+
+impl From<isize> for Value {
+    fn from(value: isize) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+/// Find the value of a key for `get_translation` and `translation_builder`, the values with components being excluded.
+fn find_translation<T: Locale>(locale: T, key_path: &str) -> Option<Translation> {
+    let translation = lookup(locale, key_path)?;
+    let has_components = match translation {
+        Translation::Str(_) => false,
+        Translation::Template(template) => template
+            .iter()
+            .any(|part| matches!(part, TemplatePart::Comp(..))),
+    };
+    (!has_components).then_some(translation)
+}
+
+pub(crate) fn get_translation<T: Locale>(locale: T, key_path: &str) -> Option<Cow<'static, str>> {
+    find_translation(locale, key_path)
+        .map(|translation| TranslationBuilder::new(translation).build())
+}
+
+pub(crate) fn translation_builder<'a, T: Locale>(
+    locale: T,
+    key_path: &str,
+) -> Option<TranslationBuilder<'a>> {
+    find_translation(locale, key_path).map(TranslationBuilder::new)
+}
+
+/// Builder rendering the value of a key found at runtime with the values of its variables,
+/// returned by `Locale::translation_builder`.
+///
+/// ```rust, ignore
+/// // "not_found": "No item with id {{ id }}"
+/// let message = Locale::en
+///     .translation_builder("errors.not_found")
+///     .map(|builder| builder.arg("id", item_id).build());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TranslationBuilder<'a> {
+    translation: Translation,
+    args: HashMap<&'a str, Value>,
+}
+
+impl<'a> TranslationBuilder<'a> {
+    fn new(translation: Translation) -> Self {
+        TranslationBuilder {
+            translation,
+            args: HashMap::new(),
+        }
+    }
+
+    /// Set the value of the variable with the given name.
+    pub fn arg(mut self, name: &'a str, value: impl Into<Value>) -> Self {
+        self.args.insert(name, value.into());
+        self
+    }
+
+    /// Set the values of the variables by name, such as a `HashMap<&str, Value>`.
+    pub fn args<V: Into<Value>>(mut self, args: impl IntoIterator<Item = (&'a str, V)>) -> Self {
+        self.args
+            .extend(args.into_iter().map(|(name, value)| (name, value.into())));
+        self
+    }
+
+    /// Return the names of the variables of the value, in the order they appear.
+    pub fn variables(&self) -> Vec<&'static str> {
+        let Translation::Template(template) = self.translation else {
+            return vec![];
+        };
+        let mut variables = vec![];
+        for part in template {
+            if let TemplatePart::Var(name) = part {
+                if !variables.contains(name) {
+                    variables.push(*name);
+                }
+            }
+        }
+        variables
+    }
+
+    /// Render the value, the variables without a value being rendered as `{{ name }}`.
+    pub fn build(&self) -> Cow<'static, str> {
+        match self.translation {
+            Translation::Str(s) => Cow::Borrowed(s),
+            Translation::Template(template) => {
+                let rendered = render_plain_template(template, |name| {
+                    self.args.get(name).map(ToString::to_string)
+                });
+                Cow::Owned(rendered.unwrap_or_default())
+            }
+        }
+    }
+}
+
+impl Display for TranslationBuilder<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &[TemplatePart] = &[
+        TemplatePart::Str("No item with id "),
+        TemplatePart::Var("id"),
+        TemplatePart::Str(" in "),
+        TemplatePart::Var("list"),
+        TemplatePart::Str(", "),
+        TemplatePart::Var("id"),
+    ];
+
+    #[test]
+    fn build_translation() {
+        let builder = TranslationBuilder::new(Translation::Template(TEMPLATE));
+        assert_eq!(builder.variables(), ["id", "list"]);
+        assert_eq!(
+            builder.build(),
+            "No item with id {{ id }} in {{ list }}, {{ id }}"
+        );
+        let args = HashMap::from([("id", Value::from(3)), ("list", Value::from("cart"))]);
+        let builder = builder.args(args);
+        assert_eq!(builder.to_string(), "No item with id 3 in cart, 3");
+        let builder = builder.arg("id", 2.5).arg("list", false);
+        assert_eq!(builder.to_string(), "No item with id 2.5 in false, 2.5");
+
+        let builder = TranslationBuilder::new(Translation::Str("plain")).arg("id", 3);
+        assert!(builder.variables().is_empty());
+        assert!(matches!(builder.build(), Cow::Borrowed("plain")));
+    }
+}
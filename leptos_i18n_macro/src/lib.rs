@@ -21,9 +21,19 @@ pub(crate) mod t_macro;
 ///
 /// - `Locale`: an enum representing the available locales of the application.
 /// - `I18nKeys`: a struct representing the translation keys.
+///
+/// With the `shards` configuration, `load_locales!(shard = "name")` only load the namespaces of the shard,
+/// using the `Locale` generated by the main invocation at `crate::i18n::Locale`, or at the path given with `locale = path::to::Locale`.
+///
+/// With `load_locales!(dynamic)` only the default locale is compiled in, the values of the other locales are written
+/// to files loaded at runtime (the keys with variables or components are still compiled in for every locale).
+///
+/// With `load_locales!(export = "path")` a JSON manifest of the keys, with their value in the default locale,
+/// their variables and plural forms and the keys missing in each locale, is written to the path relative to `Cargo.toml`.
 #[proc_macro]
-pub fn load_locales(_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match load_locales::load_locales() {
+pub fn load_locales(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(tokens as load_locales::shard::LoadLocalesArgs);
+    match load_locales::load_locales(args) {
         Ok(ts) => ts.into(),
         Err(err) => err.into(),
     }
@@ -81,3 +91,146 @@ pub fn t(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn td(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     t_macro::t_macro(tokens, true)
 }
+
+/// Set the document title to the value of a string key, staying reactive to locale changes.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+///
+/// view! {
+///     {t_title!(i18n, $key)}
+/// }
+///```
+///
+/// The title is rendered during SSR like the `Title` component of `leptos_meta`.
+/// Only string keys are supported, interpolations are not allowed.
+#[proc_macro]
+pub fn t_title(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::meta::t_title(tokens)
+}
+
+/// Add a `<meta>` tag with the value of a string key as content, staying reactive to locale changes.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+///
+/// view! {
+///     {t_meta!(i18n, name = "description", $key)}
+///     {t_meta!(i18n, property = "og:title", $key)}
+/// }
+///```
+///
+/// The attribute can be either `name`, `property` or `http_equiv`.
+/// Only string keys are supported, interpolations are not allowed.
+#[proc_macro]
+pub fn t_meta(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::meta::t_meta(tokens)
+}
+
+/// Return the path of a localized asset (screenshots, PDFs, legal documents, ...) declared as a string key, staying reactive to locale changes.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+///
+/// view! {
+///     <img src=t_asset!(i18n, $key) />
+/// }
+///```
+///
+/// The asset paths follow the same fallback as other keys: if it is missing in a locale, the path of the default locale is used.
+/// Only string keys are supported, interpolations are not allowed.
+#[proc_macro]
+pub fn t_asset(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::asset::t_asset(tokens)
+}
+
+/// Just like the `t!` macro but return the translation as a `Cow<'static, str>` instead of a view,
+/// for the places where a view can't be used: attributes, emails, logs, ...
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+///
+/// view! {
+///     <input placeholder=move || t_string!(i18n, $key) />
+///     <button aria-label=move || t_string!(i18n, $key, $variable = $value) />
+/// }
+///```
+///
+/// The value is read once, wrap it in a closure to stay reactive to locale changes.
+/// Variables must implement `Display`, and components are replaced by their children as they can't be rendered to a string.
+#[proc_macro]
+pub fn t_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::string::t_string(tokens)
+}
+
+/// Just like the `t_string!` macro but instead of taking `I18nContext` as the first argument it takes the desired locale.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::Locale;
+///
+/// let subject = td_string!(Locale::fr, $key, $variable = $value);
+///```
+#[proc_macro]
+pub fn td_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::string::t_string(tokens)
+}
+
+/// Scope a context, or a locale, to the subkeys or the namespace at the given path, for the `t!` and `td!` macros
+/// to take the keys relative to it.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+/// let settings = scope_i18n!(i18n, $namespace::$subkeys);
+///
+/// view! {
+///     // same as `t!(i18n, $namespace::$subkeys.$key)`
+///     <p>{t!(settings, $key)}</p>
+/// }
+///```
+///
+/// The path must be subkeys or a namespace, it is checked at compile time.
+/// The scope derefs to the context, to get or set the locale, and can be scoped again.
+#[proc_macro]
+pub fn scope_i18n(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::scope::scope_i18n(tokens)
+}
+
+/// Shorthand for `scope_i18n!(use_i18n(), $path)`, using the `use_i18n` function in scope.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n_scoped!($namespace::$subkeys);
+///
+/// view! {
+///     <p>{t!(i18n, $key)}</p>
+/// }
+///```
+#[proc_macro]
+pub fn use_i18n_scoped(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::scope::use_i18n_scoped(tokens)
+}
@@ -3,8 +3,14 @@ use serde::de::DeserializeOwned;
 use super::{
     error::{Error, Result},
     key::Key,
+    pseudo::PSEUDO_LOCALE,
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    rc::Rc,
 };
-use std::{borrow::Cow, collections::HashSet, path::PathBuf, rc::Rc};
 
 #[derive(Debug)]
 pub struct ConfigFile {
@@ -12,6 +18,280 @@ pub struct ConfigFile {
     pub locales: Vec<Rc<Key>>,
     pub name_spaces: Option<Vec<Rc<Key>>>,
     pub locales_dir: Cow<'static, str>,
+    pub value_options: ValueOptions,
+    /// Map a locale to the locale it extends.
+    pub inherits: HashMap<Rc<Key>, Rc<Key>>,
+    /// Whether the locales not in `inherits` extend the locale of their tag without the last subtags, if declared.
+    pub subtag_fallback: bool,
+    /// Constants that can be used in the values with `{{ $name }}`.
+    pub constants: HashMap<String, String>,
+    /// Map the name of a shard to the namespaces loaded by its `load_locales!(shard = "..")` invocation.
+    pub shards: HashMap<String, Vec<Rc<Key>>>,
+    /// Directory, relative to the manifest, the files of the locales are written to with `load_locales!(dynamic)`.
+    pub dynamic_dir: Cow<'static, str>,
+    /// URL the files of the locales are fetched from in the browser with `load_locales!(dynamic)`.
+    pub dynamic_url: Cow<'static, str>,
+    /// Sources the locale of a request is negotiated from on the server, in the order they are consulted.
+    pub negotiation: Vec<NegotiationSource>,
+    /// Name of the cookie saving the locale chosen by the user.
+    pub cookie_name: String,
+    /// Attributes of the cookie saving the locale chosen by the user.
+    pub cookie_attributes: CookieAttributes,
+    /// Where the locale chosen by the user is persisted.
+    pub locale_storage: StorageKind,
+    /// Locale generated from the default one with its values pseudo-localized, with `generate-pseudo-locale`.
+    pub pseudo_locale: Option<Rc<Key>>,
+    /// What is done for the keys missing in a locale, by profile.
+    pub missing_keys: MissingKeys,
+    /// Directories, relative to the manifest, scanned for the keys used with the `t!` macros, with `check-key-usage`.
+    pub key_usage_dirs: Vec<String>,
+    /// Direction of the text of the locales, overriding the one derived from their tag.
+    pub directions: HashMap<Rc<Key>, TextDirection>,
+    /// Locale files shipped by other crates, sorted by prefix.
+    pub locale_packages: Vec<LocalePackage>,
+}
+
+/// Locale files of a local directory, such as the one of a component library of the workspace, declared in `locale-packages`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalePackage {
+    /// The namespace the keys of the package are in with namespaces, else the key they are the subkeys of.
+    pub prefix: Rc<Key>,
+    /// Directory of the files of the package, relative to the manifest, with one file per locale.
+    pub dir: String,
+}
+
+/// Direction of the text of a locale, declared in `directions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+/// A source the locale of a request is negotiated from on the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NegotiationSource {
+    Cookie,
+    AcceptLanguage,
+    Url,
+    Default,
+}
+
+impl NegotiationSource {
+    const NAMES: &'static [&'static str] = &["cookie", "accept-language", "url", "default"];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            NegotiationSource::Cookie => "cookie",
+            NegotiationSource::AcceptLanguage => "accept-language",
+            NegotiationSource::Url => "url",
+            NegotiationSource::Default => "default",
+        }
+    }
+}
+
+/// Where the locale chosen by the user is persisted, declared with `locale-storage`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageKind {
+    #[default]
+    Cookie,
+    LocalStorage,
+    None,
+}
+
+impl StorageKind {
+    const NAMES: &'static [&'static str] = &["cookie", "local-storage", "none"];
+}
+
+/// The `SameSite` attribute of the cookie, declared with `cookie-same-site`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    #[default]
+    Lax,
+    None,
+}
+
+impl SameSite {
+    const NAMES: &'static [&'static str] = &["strict", "lax", "none"];
+}
+
+/// The attributes of the cookie saving the locale chosen by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CookieAttributes {
+    /// Number of seconds the cookie is kept, with `cookie-max-age`.
+    pub max_age: u64,
+    pub same_site: SameSite,
+    /// Whether the cookie is only sent over HTTPS, with `cookie-secure`.
+    pub secure: bool,
+    /// The path the cookie is sent for, with `cookie-path`.
+    pub path: String,
+}
+
+impl Default for CookieAttributes {
+    fn default() -> Self {
+        CookieAttributes {
+            max_age: 60 * 60 * 24 * 365,
+            same_site: SameSite::Lax,
+            secure: true,
+            path: "/".to_string(),
+        }
+    }
+}
+
+/// What is done for a key missing in a locale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingKeyPolicy {
+    /// Fail the compilation.
+    Error,
+    /// Emit a warning and use the value of the default locale.
+    #[default]
+    Warn,
+    /// Use the path of the key as the value, so the missing translations are visible when rendered.
+    FallbackWithMarker,
+}
+
+impl MissingKeyPolicy {
+    const NAMES: &'static [&'static str] = &["error", "warn", "fallback-with-marker"];
+}
+
+/// The policy for the missing keys in debug and release builds, told apart by the debug assertions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MissingKeys {
+    pub debug: MissingKeyPolicy,
+    pub release: MissingKeyPolicy,
+}
+
+impl MissingKeys {
+    /// Return the policy of the profile the macro is compiled with, the same as the crate calling it.
+    pub fn policy(self) -> MissingKeyPolicy {
+        if cfg!(debug_assertions) {
+            self.debug
+        } else {
+            self.release
+        }
+    }
+}
+
+/// Options applied to every key and string value when the locales are loaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValueOptions {
+    pub whitespace: Whitespace,
+    pub escapes: Escapes,
+    pub dotted_keys: DottedKeys,
+    /// Convert the i18next dialect of the JSON files, see the `i18next` module.
+    pub i18next_compat: bool,
+}
+
+impl ValueOptions {
+    /// Normalize the whitespace of the value, then interpret the escape sequences if enabled.
+    ///
+    /// The whitespace is normalized first so escaped newlines survive `"collapse"`.
+    pub fn normalize(self, value: &str) -> Cow<'_, str> {
+        match self.whitespace.normalize(value) {
+            Cow::Borrowed(value) => self.escapes.apply(value),
+            Cow::Owned(value) => Cow::Owned(self.escapes.apply(&value).into_owned()),
+        }
+    }
+}
+
+/// How the dots inside the keys are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DottedKeys {
+    /// The dots are part of the key, and converted to `_` in the identifier.
+    #[default]
+    Literal,
+    /// `"home.title"` is the key `title` in the subkeys of `home`.
+    Nested,
+}
+
+/// How the whitespace of the values is handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Whitespace {
+    /// Keep the values as they are written.
+    #[default]
+    Preserve,
+    /// Remove leading and trailing whitespace.
+    Trim,
+    /// Remove leading and trailing whitespace and replace every run of whitespace, newlines included, by a single space.
+    Collapse,
+}
+
+impl Whitespace {
+    pub fn normalize(self, value: &str) -> Cow<'_, str> {
+        match self {
+            Whitespace::Preserve => Cow::Borrowed(value),
+            Whitespace::Trim => Cow::Borrowed(value.trim()),
+            Whitespace::Collapse => {
+                let mut collapsed = String::with_capacity(value.len());
+                for word in value.split_whitespace() {
+                    if !collapsed.is_empty() {
+                        collapsed.push(' ');
+                    }
+                    collapsed.push_str(word);
+                }
+                Cow::Owned(collapsed)
+            }
+        }
+    }
+}
+
+/// How the backslash escape sequences remaining in the values after the file format parsing are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Escapes {
+    /// Keep the values as the file format parsed them.
+    #[default]
+    Preserve,
+    /// Interpret `\n`, `\r`, `\t`, `\\`, `\"`, `\'`, `\uXXXX` and `\u{X..}`, unknown sequences are kept as is.
+    Interpret,
+}
+
+impl Escapes {
+    pub fn apply(self, value: &str) -> Cow<'_, str> {
+        if self == Escapes::Preserve || !value.contains('\\') {
+            return Cow::Borrowed(value);
+        }
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(i) = rest.find('\\') {
+            result.push_str(&rest[..i]);
+            rest = &rest[i..];
+            let (c, len) = Self::parse_escape(rest).unwrap_or(('\\', 1));
+            result.push(c);
+            rest = &rest[len..];
+        }
+        result.push_str(rest);
+        Cow::Owned(result)
+    }
+
+    /// Parse the escape sequence at the start of `s`, returning the char and the length of the sequence.
+    fn parse_escape(s: &str) -> Option<(char, usize)> {
+        let mut chars = s.chars().skip(1);
+        let c = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'u' => return Self::parse_unicode(&s[2..]).map(|(c, len)| (c, len + 2)),
+            _ => return None,
+        };
+        Some((c, 2))
+    }
+
+    fn parse_unicode(s: &str) -> Option<(char, usize)> {
+        let (hex, len) = if let Some(braced) = s.strip_prefix('{') {
+            let end = braced.find('}')?;
+            (&braced[..end], end + 2)
+        } else {
+            (s.get(..4)?, 4)
+        };
+        if hex.is_empty() || hex.len() > 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let c = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)?;
+        Some((c, len))
+    }
 }
 
 impl ConfigFile {
@@ -33,6 +313,185 @@ impl ConfigFile {
         duplicates
     }
 
+    /// Make the locales with subtags that are not in `inherits` extend the locale of their tag without the last subtags,
+    /// `fr-CA` extending `fr`, or `zh-Hant-TW` extending `zh-Hant` or else `zh`.
+    ///
+    /// The default locale never extends another locale.
+    fn add_subtag_inheritance(&mut self) {
+        if !self.subtag_fallback {
+            return;
+        }
+        for locale in self.locales.iter().skip(1) {
+            if self.inherits.contains_key(locale) {
+                continue;
+            }
+            let mut tag = parent_tag(&locale.name);
+            while !tag.is_empty() {
+                if let Some(parent) = self.locales.iter().find(|l| tag_eq(&l.name, tag)) {
+                    self.inherits.insert(Rc::clone(locale), Rc::clone(parent));
+                    break;
+                }
+                tag = parent_tag(tag);
+            }
+        }
+    }
+
+    /// Return the locale followed by the locales its missing keys are taken from, ending with the default locale.
+    pub fn fallback_chain(&self, locale: &Rc<Key>) -> Vec<Rc<Key>> {
+        let mut chain = vec![Rc::clone(locale)];
+        // cycles are rejected with the config.
+        while let Some(parent) = self.inherits.get(chain.last().unwrap_or(locale)) {
+            chain.push(Rc::clone(parent));
+        }
+        if !chain.contains(&self.default) {
+            chain.push(Rc::clone(&self.default));
+        }
+        chain
+    }
+
+    fn check_inheritance(&self) -> Result<()> {
+        for (locale, parent) in &self.inherits {
+            for key in [locale, parent] {
+                if !self.locales.contains(key) {
+                    return Err(Error::UnknownLocaleInInheritance(Rc::clone(key)));
+                }
+            }
+            // following the chain from the parent, a cycle going through this locale comes back to it.
+            let mut ancestor = parent;
+            for _ in 0..self.inherits.len() {
+                if ancestor == locale {
+                    return Err(Error::InheritanceCycle(Rc::clone(locale)));
+                }
+                match self.inherits.get(ancestor) {
+                    Some(next) => ancestor = next,
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_directions(&self) -> Result<()> {
+        match self
+            .directions
+            .keys()
+            .find(|locale| !self.locales.contains(locale))
+        {
+            Some(locale) => Err(Error::UnknownLocaleInDirections(Rc::clone(locale))),
+            None => Ok(()),
+        }
+    }
+
+    fn check_negotiation(&self) -> Result<()> {
+        let mut seen = HashSet::new();
+        for (i, source) in self.negotiation.iter().enumerate() {
+            if !seen.insert(source) {
+                return Err(Error::DuplicateNegotiationSource(*source));
+            }
+            if *source == NegotiationSource::Default && i + 1 != self.negotiation.len() {
+                return Err(Error::NegotiationDefaultNotLast);
+            }
+        }
+        let is_token_char = |c: char| c.is_ascii_graphic() && !"()<>@,;:\\\"/[]?={}".contains(c);
+        if self.cookie_name.is_empty() || !self.cookie_name.chars().all(is_token_char) {
+            return Err(Error::InvalidCookieName(self.cookie_name.clone()));
+        }
+        Ok(())
+    }
+
+    fn check_cookie_attributes(&self) -> Result<()> {
+        let CookieAttributes {
+            max_age,
+            same_site,
+            secure,
+            path,
+        } = &self.cookie_attributes;
+        if *max_age == 0 {
+            Err(Error::CookieMaxAgeZero)
+        } else if *same_site == SameSite::None && !secure {
+            Err(Error::SameSiteNoneWithoutSecure)
+        } else if !path.starts_with('/') || path.contains(|c: char| c == ';' || c.is_control()) {
+            Err(Error::InvalidCookiePath(path.clone()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_locale_packages(&self) -> Result<()> {
+        let namespaces = self.name_spaces.as_deref().unwrap_or_default();
+        match self
+            .locale_packages
+            .iter()
+            .find(|package| namespaces.contains(&package.prefix))
+        {
+            Some(package) => Err(Error::LocalePackageNamespaceConflict(Rc::clone(
+                &package.prefix,
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Return the declared namespaces followed by the namespaces of the locale packages, or nothing without namespaces.
+    pub fn namespaces_with_packages(&self) -> Vec<Rc<Key>> {
+        let Some(namespaces) = &self.name_spaces else {
+            return vec![];
+        };
+        let packages = self.locale_packages.iter().map(|package| &package.prefix);
+        namespaces.iter().chain(packages).cloned().collect()
+    }
+
+    fn check_shards(&self) -> Result<()> {
+        if self.shards.is_empty() {
+            return Ok(());
+        }
+        let Some(namespaces) = &self.name_spaces else {
+            return Err(Error::ShardsWithoutNamespaces);
+        };
+        let mut sharded = HashSet::new();
+        for (shard, shard_namespaces) in &self.shards {
+            for namespace in shard_namespaces {
+                if !namespaces.contains(namespace) {
+                    return Err(Error::UnknownNamespaceInShard {
+                        shard: shard.clone(),
+                        namespace: Rc::clone(namespace),
+                    });
+                }
+                if !sharded.insert(namespace) {
+                    return Err(Error::NamespaceInSeveralShards(Rc::clone(namespace)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep only the namespaces of the given shard, or the ones not in any shard if `None`.
+    ///
+    /// The locale packages are only loaded by the main invocation.
+    pub fn select_shard(&mut self, shard: Option<&str>) -> Result<()> {
+        let namespaces = match shard {
+            Some(shard) => {
+                self.locale_packages.clear();
+                self.shards
+                    .get(shard)
+                    .ok_or_else(|| Error::UnknownShard(shard.to_string()))?
+                    .clone()
+            }
+            None => {
+                let sharded = self.shards.values().flatten().collect::<HashSet<_>>();
+                let Some(namespaces) = &self.name_spaces else {
+                    return Ok(());
+                };
+                namespaces
+                    .iter()
+                    .filter(|namespace| !sharded.contains(namespace))
+                    .cloned()
+                    .collect()
+            }
+        };
+        self.name_spaces = Some(namespaces);
+        Ok(())
+    }
+
     pub fn new(manifest_dir_path: &mut PathBuf) -> Result<ConfigFile> {
         manifest_dir_path.push("Cargo.toml");
 
@@ -65,8 +524,31 @@ impl ConfigFile {
             cfg.locales.swap(0, len);
         }
 
+        if let Some(pseudo) = &cfg.pseudo_locale {
+            // the pseudo locale has no file, it inherits every key of the default locale.
+            cfg.locales.push(Rc::clone(pseudo));
+            cfg.inherits
+                .insert(Rc::clone(pseudo), Rc::clone(&cfg.default));
+        }
+
+        cfg.add_subtag_inheritance();
+
         if let Some(duplicates) = Self::contain_duplicates(&cfg.locales) {
             Err(Error::DuplicateLocalesInConfig(duplicates))
+        } else if let Err(err) = cfg.check_inheritance() {
+            Err(err)
+        } else if let Err(err) = cfg.check_shards() {
+            Err(err)
+        } else if let Err(err) = cfg.check_negotiation() {
+            Err(err)
+        } else if let Err(err) = cfg.check_cookie_attributes() {
+            Err(err)
+        } else if let Err(err) = cfg.check_locale_packages() {
+            Err(err)
+        } else if let Err(err) = cfg.check_directions() {
+            Err(err)
+        } else if cfg.value_options.i18next_compat && !cfg!(feature = "json_files") {
+            Err(Error::I18nextCompatWithoutJson)
         } else if let Some(duplicates) = cfg
             .name_spaces
             .as_deref()
@@ -79,9 +561,32 @@ impl ConfigFile {
     }
 }
 
-/// -----------------------------------------
-/// Deserialization
-/// -----------------------------------------
+/// Remove the last subtag of the tag, and the singleton before it if any (`"en-a-bbb"` become `"en"`).
+fn parent_tag(tag: &str) -> &str {
+    let tag = tag.rfind(['-', '_']).map_or("", |i| &tag[..i]);
+    match tag.rfind(['-', '_']) {
+        Some(i) if tag.len() - i == 2 => &tag[..i],
+        _ => tag,
+    }
+}
+
+/// Compare two locale tags ignoring ASCII case and treating `_` and `-` as the same separator.
+fn tag_eq(a: &str, b: &str) -> bool {
+    fn normalize(b: u8) -> u8 {
+        match b {
+            b'_' => b'-',
+            b => b.to_ascii_lowercase(),
+        }
+    }
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+// -----------------------------------------
+// Deserialization
+// -----------------------------------------
 
 struct CfgFileVisitor;
 
@@ -99,11 +604,60 @@ enum Field {
     Locales,
     Namespaces,
     LocalesDir,
+    Whitespace,
+    Escapes,
+    Inherits,
+    Constants,
+    DottedKeys,
+    Shards,
+    SubtagFallback,
+    DynamicDir,
+    DynamicUrl,
+    Negotiation,
+    CookieName,
+    CookieMaxAge,
+    CookieSameSite,
+    CookieSecure,
+    CookiePath,
+    LocaleStorage,
+    GeneratePseudoLocale,
+    MissingKeys,
+    CheckKeyUsage,
+    Directions,
+    I18nextCompat,
+    LocalePackages,
     Unknown,
 }
 
 impl Field {
-    const FIELDS: &'static [&'static str] = &["default", "locales", "namespaces", "locales-dir"];
+    const FIELDS: &'static [&'static str] = &[
+        "default",
+        "locales",
+        "namespaces",
+        "locales-dir",
+        "whitespace",
+        "escapes",
+        "inherits",
+        "constants",
+        "dotted-keys",
+        "shards",
+        "subtag-fallback",
+        "dynamic-dir",
+        "dynamic-url",
+        "negotiation",
+        "cookie-name",
+        "cookie-max-age",
+        "cookie-same-site",
+        "cookie-secure",
+        "cookie-path",
+        "locale-storage",
+        "generate-pseudo-locale",
+        "missing-keys",
+        "check-key-usage",
+        "directions",
+        "i18next-compat",
+        "locale-packages",
+    ];
 }
 
 struct FieldVisitor;
@@ -137,6 +691,28 @@ impl<'de> serde::de::Visitor<'de> for FieldVisitor {
             "locales" => Ok(Field::Locales),
             "namespaces" => Ok(Field::Namespaces),
             "locales-dir" => Ok(Field::LocalesDir),
+            "whitespace" => Ok(Field::Whitespace),
+            "escapes" => Ok(Field::Escapes),
+            "inherits" => Ok(Field::Inherits),
+            "constants" => Ok(Field::Constants),
+            "dotted-keys" => Ok(Field::DottedKeys),
+            "shards" => Ok(Field::Shards),
+            "subtag-fallback" => Ok(Field::SubtagFallback),
+            "dynamic-dir" => Ok(Field::DynamicDir),
+            "dynamic-url" => Ok(Field::DynamicUrl),
+            "negotiation" => Ok(Field::Negotiation),
+            "cookie-name" => Ok(Field::CookieName),
+            "cookie-max-age" => Ok(Field::CookieMaxAge),
+            "cookie-same-site" => Ok(Field::CookieSameSite),
+            "cookie-secure" => Ok(Field::CookieSecure),
+            "cookie-path" => Ok(Field::CookiePath),
+            "locale-storage" => Ok(Field::LocaleStorage),
+            "generate-pseudo-locale" => Ok(Field::GeneratePseudoLocale),
+            "missing-keys" => Ok(Field::MissingKeys),
+            "check-key-usage" => Ok(Field::CheckKeyUsage),
+            "directions" => Ok(Field::Directions),
+            "i18next-compat" => Ok(Field::I18nextCompat),
+            "locale-packages" => Ok(Field::LocalePackages),
             _ => Ok(Field::Unknown), // skip unknown fields
         }
     }
@@ -168,12 +744,74 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         let mut locales = None;
         let mut name_spaces = None;
         let mut locales_dir = None;
+        let mut whitespace = None;
+        let mut escapes = None;
+        let mut inherits = None;
+        let mut constants = None;
+        let mut dotted_keys = None;
+        let mut shards = None;
+        let mut subtag_fallback = None;
+        let mut dynamic_dir = None;
+        let mut dynamic_url = None;
+        let mut negotiation = None;
+        let mut cookie_name = None;
+        let mut cookie_max_age = None;
+        let mut cookie_same_site = None;
+        let mut cookie_secure = None;
+        let mut cookie_path = None;
+        let mut locale_storage = None;
+        let mut generate_pseudo_locale = None;
+        let mut missing_keys = None;
+        let mut check_key_usage: Option<CheckKeyUsage> = None;
+        let mut directions = None;
+        let mut i18next_compat = None;
+        let mut locale_packages: Option<HashMap<Rc<Key>, String>> = None;
         while let Some(field) = map.next_key::<Field>()? {
             match field {
                 Field::Default => deser_field(&mut default, &mut map, "default")?,
                 Field::Locales => deser_field(&mut locales, &mut map, "locales")?,
                 Field::Namespaces => deser_field(&mut name_spaces, &mut map, "namespaces")?,
                 Field::LocalesDir => deser_field(&mut locales_dir, &mut map, "locales-dir")?,
+                Field::Whitespace => deser_field(&mut whitespace, &mut map, "whitespace")?,
+                Field::Escapes => deser_field(&mut escapes, &mut map, "escapes")?,
+                Field::Inherits => deser_field(&mut inherits, &mut map, "inherits")?,
+                Field::Constants => deser_field(&mut constants, &mut map, "constants")?,
+                Field::DottedKeys => deser_field(&mut dotted_keys, &mut map, "dotted-keys")?,
+                Field::Shards => deser_field(&mut shards, &mut map, "shards")?,
+                Field::SubtagFallback => {
+                    deser_field(&mut subtag_fallback, &mut map, "subtag-fallback")?
+                }
+                Field::DynamicDir => deser_field(&mut dynamic_dir, &mut map, "dynamic-dir")?,
+                Field::DynamicUrl => deser_field(&mut dynamic_url, &mut map, "dynamic-url")?,
+                Field::Negotiation => deser_field(&mut negotiation, &mut map, "negotiation")?,
+                Field::CookieName => deser_field(&mut cookie_name, &mut map, "cookie-name")?,
+                Field::CookieMaxAge => {
+                    deser_field(&mut cookie_max_age, &mut map, "cookie-max-age")?
+                }
+                Field::CookieSameSite => {
+                    deser_field(&mut cookie_same_site, &mut map, "cookie-same-site")?
+                }
+                Field::CookieSecure => deser_field(&mut cookie_secure, &mut map, "cookie-secure")?,
+                Field::CookiePath => deser_field(&mut cookie_path, &mut map, "cookie-path")?,
+                Field::LocaleStorage => {
+                    deser_field(&mut locale_storage, &mut map, "locale-storage")?
+                }
+                Field::GeneratePseudoLocale => deser_field(
+                    &mut generate_pseudo_locale,
+                    &mut map,
+                    "generate-pseudo-locale",
+                )?,
+                Field::MissingKeys => deser_field(&mut missing_keys, &mut map, "missing-keys")?,
+                Field::CheckKeyUsage => {
+                    deser_field(&mut check_key_usage, &mut map, "check-key-usage")?
+                }
+                Field::Directions => deser_field(&mut directions, &mut map, "directions")?,
+                Field::I18nextCompat => {
+                    deser_field(&mut i18next_compat, &mut map, "i18next-compat")?
+                }
+                Field::LocalePackages => {
+                    deser_field(&mut locale_packages, &mut map, "locale-packages")?
+                }
                 Field::Unknown => continue,
             }
         }
@@ -194,6 +832,51 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
             locales,
             name_spaces,
             locales_dir,
+            value_options: ValueOptions {
+                whitespace: whitespace.unwrap_or_default(),
+                escapes: escapes.unwrap_or_default(),
+                dotted_keys: dotted_keys.unwrap_or_default(),
+                i18next_compat: i18next_compat.unwrap_or(false),
+            },
+            inherits: inherits.unwrap_or_default(),
+            subtag_fallback: subtag_fallback.unwrap_or(true),
+            constants: constants.unwrap_or_default(),
+            shards: shards.unwrap_or_default(),
+            dynamic_dir: dynamic_dir
+                .map(Cow::Owned)
+                .unwrap_or(Cow::Borrowed("target/site/i18n")),
+            dynamic_url: dynamic_url
+                .map(Cow::Owned)
+                .unwrap_or(Cow::Borrowed("/i18n")),
+            negotiation: negotiation.unwrap_or_else(|| {
+                vec![NegotiationSource::Cookie, NegotiationSource::AcceptLanguage]
+            }),
+            cookie_name: cookie_name.unwrap_or_else(|| "i18n_pref_locale".to_string()),
+            cookie_attributes: {
+                let default = CookieAttributes::default();
+                CookieAttributes {
+                    max_age: cookie_max_age.unwrap_or(default.max_age),
+                    same_site: cookie_same_site.unwrap_or(default.same_site),
+                    secure: cookie_secure.unwrap_or(default.secure),
+                    path: cookie_path.unwrap_or(default.path),
+                }
+            },
+            locale_storage: locale_storage.unwrap_or_default(),
+            pseudo_locale: generate_pseudo_locale
+                .unwrap_or(false)
+                .then(|| Rc::new(Key::new(PSEUDO_LOCALE).unwrap())),
+            missing_keys: missing_keys.unwrap_or_default(),
+            key_usage_dirs: check_key_usage.map(|dirs| dirs.0).unwrap_or_default(),
+            directions: directions.unwrap_or_default(),
+            locale_packages: {
+                let mut packages = locale_packages
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(prefix, dir)| LocalePackage { prefix, dir })
+                    .collect::<Vec<_>>();
+                packages.sort_unstable_by(|a, b| a.prefix.name.cmp(&b.prefix.name));
+                packages
+            },
         })
     }
 
@@ -204,3 +887,724 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         )
     }
 }
+
+impl<'de> serde::Deserialize<'de> for Whitespace {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(WhitespaceVisitor)
+    }
+}
+
+struct WhitespaceVisitor;
+
+impl<'de> serde::de::Visitor<'de> for WhitespaceVisitor {
+    type Value = Whitespace;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"preserve\", \"trim\" or \"collapse\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "preserve" => Ok(Whitespace::Preserve),
+            "trim" => Ok(Whitespace::Trim),
+            "collapse" => Ok(Whitespace::Collapse),
+            _ => Err(serde::de::Error::unknown_variant(
+                v,
+                &["preserve", "trim", "collapse"],
+            )),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TextDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TextDirectionVisitor)
+    }
+}
+
+struct TextDirectionVisitor;
+
+impl<'de> serde::de::Visitor<'de> for TextDirectionVisitor {
+    type Value = TextDirection;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"ltr\" or \"rtl\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "ltr" => Ok(TextDirection::Ltr),
+            "rtl" => Ok(TextDirection::Rtl),
+            _ => Err(serde::de::Error::unknown_variant(v, &["ltr", "rtl"])),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DottedKeys {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DottedKeysVisitor)
+    }
+}
+
+struct DottedKeysVisitor;
+
+impl<'de> serde::de::Visitor<'de> for DottedKeysVisitor {
+    type Value = DottedKeys;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"literal\" or \"nested\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "literal" => Ok(DottedKeys::Literal),
+            "nested" => Ok(DottedKeys::Nested),
+            _ => Err(serde::de::Error::unknown_variant(v, &["literal", "nested"])),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Escapes {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(EscapesVisitor)
+    }
+}
+
+struct EscapesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for EscapesVisitor {
+    type Value = Escapes;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"preserve\" or \"interpret\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "preserve" => Ok(Escapes::Preserve),
+            "interpret" => Ok(Escapes::Interpret),
+            _ => Err(serde::de::Error::unknown_variant(
+                v,
+                &["preserve", "interpret"],
+            )),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NegotiationSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(NegotiationSourceVisitor)
+    }
+}
+
+struct NegotiationSourceVisitor;
+
+impl<'de> serde::de::Visitor<'de> for NegotiationSourceVisitor {
+    type Value = NegotiationSource;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either \"cookie\", \"accept-language\", \"url\" or \"default\""
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "cookie" => Ok(NegotiationSource::Cookie),
+            "accept-language" => Ok(NegotiationSource::AcceptLanguage),
+            "url" => Ok(NegotiationSource::Url),
+            "default" => Ok(NegotiationSource::Default),
+            _ => Err(serde::de::Error::unknown_variant(
+                v,
+                NegotiationSource::NAMES,
+            )),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StorageKind {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StorageKindVisitor)
+    }
+}
+
+struct StorageKindVisitor;
+
+impl<'de> serde::de::Visitor<'de> for StorageKindVisitor {
+    type Value = StorageKind;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either \"cookie\", \"local-storage\" or \"none\""
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "cookie" => Ok(StorageKind::Cookie),
+            "local-storage" => Ok(StorageKind::LocalStorage),
+            "none" => Ok(StorageKind::None),
+            _ => Err(serde::de::Error::unknown_variant(v, StorageKind::NAMES)),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SameSite {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(SameSiteVisitor)
+    }
+}
+
+struct SameSiteVisitor;
+
+impl<'de> serde::de::Visitor<'de> for SameSiteVisitor {
+    type Value = SameSite;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"strict\", \"lax\" or \"none\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "strict" => Ok(SameSite::Strict),
+            "lax" => Ok(SameSite::Lax),
+            "none" => Ok(SameSite::None),
+            _ => Err(serde::de::Error::unknown_variant(v, SameSite::NAMES)),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MissingKeyPolicy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(MissingKeyPolicyVisitor)
+    }
+}
+
+struct MissingKeyPolicyVisitor;
+
+impl<'de> serde::de::Visitor<'de> for MissingKeyPolicyVisitor {
+    type Value = MissingKeyPolicy;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either \"error\", \"warn\" or \"fallback-with-marker\""
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "error" => Ok(MissingKeyPolicy::Error),
+            "warn" => Ok(MissingKeyPolicy::Warn),
+            "fallback-with-marker" => Ok(MissingKeyPolicy::FallbackWithMarker),
+            _ => Err(serde::de::Error::unknown_variant(
+                v,
+                MissingKeyPolicy::NAMES,
+            )),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MissingKeys {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MissingKeysVisitor)
+    }
+}
+
+struct MissingKeysVisitor;
+
+impl<'de> serde::de::Visitor<'de> for MissingKeysVisitor {
+    type Value = MissingKeys;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either a policy or a table with the policies of the \"debug\" and \"release\" profiles"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let policy = MissingKeyPolicyVisitor.visit_str(v)?;
+        Ok(MissingKeys {
+            debug: policy,
+            release: policy,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        const PROFILES: &[&str] = &["debug", "release"];
+        let mut missing_keys = MissingKeys::default();
+        while let Some(profile) = map.next_key::<String>()? {
+            match profile.as_str() {
+                "debug" => missing_keys.debug = map.next_value()?,
+                "release" => missing_keys.release = map.next_value()?,
+                _ => return Err(serde::de::Error::unknown_field(&profile, PROFILES)),
+            }
+        }
+        Ok(missing_keys)
+    }
+}
+
+/// `check-key-usage`, either `true` to scan the `src` directory or the list of the directories to scan.
+struct CheckKeyUsage(Vec<String>);
+
+impl<'de> serde::Deserialize<'de> for CheckKeyUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(CheckKeyUsageVisitor)
+    }
+}
+
+struct CheckKeyUsageVisitor;
+
+impl<'de> serde::de::Visitor<'de> for CheckKeyUsageVisitor {
+    type Value = CheckKeyUsage;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either a boolean or a list of the directories to scan"
+        )
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let dirs = if v { vec!["src".to_string()] } else { vec![] };
+        Ok(CheckKeyUsage(dirs))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut dirs = vec![];
+        while let Some(dir) = seq.next_element()? {
+            dirs.push(dir);
+        }
+        Ok(CheckKeyUsage(dirs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_whitespace() {
+        let value = "  first line\n    second   line\n";
+        assert_eq!(Whitespace::Preserve.normalize(value), value);
+        assert_eq!(
+            Whitespace::Trim.normalize(value),
+            "first line\n    second   line"
+        );
+        assert_eq!(
+            Whitespace::Collapse.normalize(value),
+            "first line second line"
+        );
+    }
+
+    #[test]
+    fn interpret_escapes() {
+        let value = r#"a\nb\tc \\n \"q\" \u00e9 \u{1F600} \x"#;
+        assert_eq!(Escapes::Preserve.apply(value), value);
+        assert_eq!(
+            Escapes::Interpret.apply(value),
+            "a\nb\tc \\n \"q\" \u{e9} \u{1F600} \\x"
+        );
+    }
+
+    #[test]
+    fn select_shard() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en"]
+            namespaces = ["common", "admin", "reports"]
+            shards = { back_office = ["admin", "reports"] }
+        "#;
+        let names = |cfg: &ConfigFile| {
+            cfg.name_spaces
+                .iter()
+                .flatten()
+                .map(|namespace| namespace.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut main: ConfigFile = toml::de::from_str(cfg).unwrap();
+        main.check_shards().unwrap();
+        main.select_shard(None).unwrap();
+        assert_eq!(names(&main), ["common"]);
+
+        let mut shard: ConfigFile = toml::de::from_str(cfg).unwrap();
+        shard.select_shard(Some("back_office")).unwrap();
+        assert_eq!(names(&shard), ["admin", "reports"]);
+        assert!(matches!(
+            shard.select_shard(Some("unknown")),
+            Err(Error::UnknownShard(_))
+        ));
+
+        let cfg = cfg.replace(r#"["admin", "reports"]"#, r#"["admin"], other = ["admin"]"#);
+        let cfg: ConfigFile = toml::de::from_str(&cfg).unwrap();
+        assert!(matches!(
+            cfg.check_shards(),
+            Err(Error::NamespaceInSeveralShards(_))
+        ));
+    }
+
+    #[test]
+    fn subtag_inheritance() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr", "fr-CA", "zh", "zh-Hant-TW", "pt-BR", "pt-PT"]
+            inherits = { pt-BR = "pt-PT" }
+        "#;
+        let key = |name: &str| Rc::new(Key::new(name).unwrap());
+        let names =
+            |chain: Vec<Rc<Key>>| chain.iter().map(|key| key.name.clone()).collect::<Vec<_>>();
+
+        let mut with_subtags: ConfigFile = toml::de::from_str(cfg).unwrap();
+        with_subtags.add_subtag_inheritance();
+        assert_eq!(
+            names(with_subtags.fallback_chain(&key("fr-CA"))),
+            ["fr-CA", "fr", "en"]
+        );
+        assert_eq!(
+            names(with_subtags.fallback_chain(&key("zh-Hant-TW"))),
+            ["zh-Hant-TW", "zh", "en"]
+        );
+        assert_eq!(
+            names(with_subtags.fallback_chain(&key("pt-BR"))),
+            ["pt-BR", "pt-PT", "en"]
+        );
+        assert_eq!(names(with_subtags.fallback_chain(&key("en"))), ["en"]);
+
+        let cfg = format!("{}subtag-fallback = false", cfg);
+        let mut without_subtags: ConfigFile = toml::de::from_str(&cfg).unwrap();
+        without_subtags.add_subtag_inheritance();
+        assert_eq!(
+            names(without_subtags.fallback_chain(&key("fr-CA"))),
+            ["fr-CA", "en"]
+        );
+        assert_eq!(without_subtags.inherits.len(), 1);
+    }
+
+    #[test]
+    fn escaped_newlines_survive_collapse() {
+        let options = ValueOptions {
+            whitespace: Whitespace::Collapse,
+            escapes: Escapes::Interpret,
+            ..Default::default()
+        };
+        assert_eq!(options.normalize("  a\n   b\\nc "), "a b\nc");
+    }
+
+    #[test]
+    fn negotiation() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+        "#;
+        let parse = |options: &str| -> ConfigFile {
+            toml::de::from_str(&format!("{}{}", cfg, options)).unwrap()
+        };
+
+        let default = parse("");
+        assert_eq!(
+            default.negotiation,
+            [NegotiationSource::Cookie, NegotiationSource::AcceptLanguage]
+        );
+        assert_eq!(default.cookie_name, "i18n_pref_locale");
+        default.check_negotiation().unwrap();
+
+        let custom = parse(
+            r#"
+            negotiation = ["url", "accept-language", "default"]
+            cookie-name = "lang"
+        "#,
+        );
+        assert_eq!(
+            custom.negotiation,
+            [
+                NegotiationSource::Url,
+                NegotiationSource::AcceptLanguage,
+                NegotiationSource::Default
+            ]
+        );
+        assert_eq!(custom.cookie_name, "lang");
+        custom.check_negotiation().unwrap();
+
+        assert!(matches!(
+            parse(r#"negotiation = ["cookie", "url", "cookie"]"#).check_negotiation(),
+            Err(Error::DuplicateNegotiationSource(NegotiationSource::Cookie))
+        ));
+        assert!(matches!(
+            parse(r#"negotiation = ["default", "cookie"]"#).check_negotiation(),
+            Err(Error::NegotiationDefaultNotLast)
+        ));
+        assert!(matches!(
+            parse(r#"cookie-name = "my lang""#).check_negotiation(),
+            Err(Error::InvalidCookieName(_))
+        ));
+        assert!(
+            toml::de::from_str::<ConfigFile>(&format!("{}negotiation = [\"session\"]", cfg))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn cookie_attributes() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+        "#;
+        let parse = |options: &str| -> ConfigFile {
+            toml::de::from_str(&format!("{}{}", cfg, options)).unwrap()
+        };
+
+        let default = parse("");
+        assert_eq!(default.cookie_attributes, CookieAttributes::default());
+        assert_eq!(default.locale_storage, StorageKind::Cookie);
+        default.check_cookie_attributes().unwrap();
+
+        let custom = parse(
+            r#"
+            cookie-max-age = 3600
+            cookie-same-site = "strict"
+            cookie-secure = false
+            cookie-path = "/app"
+            locale-storage = "local-storage"
+        "#,
+        );
+        assert_eq!(
+            custom.cookie_attributes,
+            CookieAttributes {
+                max_age: 3600,
+                same_site: SameSite::Strict,
+                secure: false,
+                path: "/app".to_string(),
+            }
+        );
+        assert_eq!(custom.locale_storage, StorageKind::LocalStorage);
+        custom.check_cookie_attributes().unwrap();
+
+        assert!(matches!(
+            parse("cookie-max-age = 0").check_cookie_attributes(),
+            Err(Error::CookieMaxAgeZero)
+        ));
+        assert!(matches!(
+            parse("cookie-same-site = \"none\"\ncookie-secure = false").check_cookie_attributes(),
+            Err(Error::SameSiteNoneWithoutSecure)
+        ));
+        parse("cookie-same-site = \"none\"")
+            .check_cookie_attributes()
+            .unwrap();
+        assert!(matches!(
+            parse("cookie-path = \"app\"").check_cookie_attributes(),
+            Err(Error::InvalidCookiePath(_))
+        ));
+        assert!(
+            toml::de::from_str::<ConfigFile>(&format!("{}locale-storage = \"session\"", cfg))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn locale_packages() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+            locale-packages = { ui = "../ui/locales", forms = "../forms/locales" }
+        "#;
+        let cfg_file: ConfigFile = toml::de::from_str(cfg).unwrap();
+        let prefixes: Vec<&str> = cfg_file
+            .locale_packages
+            .iter()
+            .map(|package| package.prefix.name.as_str())
+            .collect();
+        assert_eq!(prefixes, ["forms", "ui"]);
+        assert_eq!(cfg_file.locale_packages[1].dir, "../ui/locales");
+        cfg_file.check_locale_packages().unwrap();
+        assert!(cfg_file.namespaces_with_packages().is_empty());
+
+        let cfg_file: ConfigFile =
+            toml::de::from_str(&format!("{}namespaces = [\"home\", \"ui\"]", cfg)).unwrap();
+        assert!(matches!(
+            cfg_file.check_locale_packages(),
+            Err(Error::LocalePackageNamespaceConflict(prefix)) if prefix.name == "ui"
+        ));
+
+        let cfg_file: ConfigFile =
+            toml::de::from_str(&format!("{}namespaces = [\"home\"]", cfg)).unwrap();
+        let namespaces = cfg_file.namespaces_with_packages();
+        let namespaces: Vec<&str> = namespaces
+            .iter()
+            .map(|namespace| namespace.name.as_str())
+            .collect();
+        assert_eq!(namespaces, ["home", "forms", "ui"]);
+    }
+
+    #[test]
+    fn missing_keys() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+        "#;
+        let parse = |options: &str| {
+            toml::de::from_str::<ConfigFile>(&format!("{}{}", cfg, options))
+                .map(|cfg_file| cfg_file.missing_keys)
+        };
+
+        assert_eq!(parse("").unwrap(), MissingKeys::default());
+        assert_eq!(parse("").unwrap().policy(), MissingKeyPolicy::Warn);
+        assert_eq!(
+            parse(r#"missing-keys = "error""#).unwrap(),
+            MissingKeys {
+                debug: MissingKeyPolicy::Error,
+                release: MissingKeyPolicy::Error,
+            }
+        );
+        assert_eq!(
+            parse(r#"missing-keys = { release = "error" }"#).unwrap(),
+            MissingKeys {
+                debug: MissingKeyPolicy::Warn,
+                release: MissingKeyPolicy::Error,
+            }
+        );
+        assert_eq!(
+            parse(r#"missing-keys = { debug = "fallback-with-marker", release = "error" }"#)
+                .unwrap()
+                .debug,
+            MissingKeyPolicy::FallbackWithMarker
+        );
+        assert!(parse(r#"missing-keys = "ignore""#).is_err());
+        assert!(parse(r#"missing-keys = { ci = "error" }"#).is_err());
+    }
+
+    #[test]
+    fn check_key_usage() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+        "#;
+        let parse = |options: &str| {
+            toml::de::from_str::<ConfigFile>(&format!("{}{}", cfg, options))
+                .map(|cfg_file| cfg_file.key_usage_dirs)
+        };
+
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("check-key-usage = false").unwrap().is_empty());
+        assert_eq!(parse("check-key-usage = true").unwrap(), ["src"]);
+        assert_eq!(
+            parse(r#"check-key-usage = ["src", "../app/src"]"#).unwrap(),
+            ["src", "../app/src"]
+        );
+        assert!(parse(r#"check-key-usage = "src""#).is_err());
+    }
+
+    #[test]
+    fn directions() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "ckb"]
+        "#;
+        let cfg_file: ConfigFile = toml::de::from_str(cfg).unwrap();
+        assert!(cfg_file.directions.is_empty());
+
+        let cfg_file: ConfigFile =
+            toml::de::from_str(&format!("{}directions = {{ ckb = \"rtl\" }}", cfg)).unwrap();
+        let ckb = Key::new("ckb").unwrap();
+        assert_eq!(cfg_file.directions.get(&ckb), Some(&TextDirection::Rtl));
+        assert!(cfg_file.check_directions().is_ok());
+
+        let cfg_file: ConfigFile =
+            toml::de::from_str(&format!("{}directions = {{ he = \"rtl\" }}", cfg)).unwrap();
+        assert!(matches!(
+            cfg_file.check_directions(),
+            Err(Error::UnknownLocaleInDirections(locale)) if locale.name == "he"
+        ));
+        assert!(toml::de::from_str::<ConfigFile>(&format!(
+            "{}directions = {{ ckb = \"up\" }}",
+            cfg
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn pseudo_locale() {
+        let cfg = r#"
+            default = "en"
+            locales = ["en", "fr"]
+        "#;
+        let cfg_file: ConfigFile = toml::de::from_str(cfg).unwrap();
+        assert!(cfg_file.pseudo_locale.is_none());
+
+        let cfg_file: ConfigFile =
+            toml::de::from_str(&format!("{}generate-pseudo-locale = true", cfg)).unwrap();
+        assert_eq!(
+            cfg_file.pseudo_locale.map(|locale| locale.name.clone()),
+            Some(PSEUDO_LOCALE.to_string())
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::{fmt::Write, path::Path};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    cfg_file::ConfigFile,
+    error::{Error, Result},
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+};
+
+/// Where the values loaded at runtime are written to, and fetched from in the browser.
+pub struct DynamicAssets {
+    pub dir: String,
+    pub url: String,
+}
+
+/// Write the values loaded at runtime of the locales other than the default one, to `{dynamic-dir}/{locale}.json`,
+/// or `{dynamic-dir}/{locale}/{namespace}.json` with namespaces.
+///
+/// Each file is a flat JSON object mapping the path of the keys (`key.subkey`) to their value,
+/// only for the strings, lists and object lists as the other keys are compiled in for every locale.
+pub fn write_assets(
+    manifest_dir: &Path,
+    cfg_file: &ConfigFile,
+    locales: &LocalesOrNamespaces,
+) -> Result<DynamicAssets> {
+    let dir = manifest_dir.join(&*cfg_file.dynamic_dir);
+    match locales {
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                for locale in namespace.locales.iter().skip(1) {
+                    let path = dir
+                        .join(&locale.top_locale_name.name)
+                        .join(format!("{}.json", namespace.key.name));
+                    write_file(&path, &locale_to_json(locale))?;
+                }
+            }
+        }
+        LocalesOrNamespaces::Locales(locales) => {
+            for locale in locales.iter().skip(1) {
+                let path = dir.join(format!("{}.json", locale.top_locale_name.name));
+                write_file(&path, &locale_to_json(locale))?;
+            }
+        }
+    }
+    Ok(DynamicAssets {
+        dir: dir.to_string_lossy().into_owned(),
+        url: cfg_file.dynamic_url.to_string(),
+    })
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    write_if_changed(path, contents).map_err(|err| Error::DynamicAssetsWrite {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+/// Write the file, creating its directory, unless it already has these contents
+/// so the watchers of the file are not triggered.
+pub fn write_if_changed(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if std::fs::read_to_string(path).is_ok_and(|current| current == contents) {
+        return Ok(());
+    }
+    std::fs::write(path, contents)
+}
+
+fn locale_to_json(locale: &Locale) -> String {
+    let mut values = vec![];
+    collect_values(locale, "", &mut values);
+    values.sort_unstable();
+    let mut json = String::from("{");
+    for (i, (path, value)) in values.into_iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(json, "{}\n  {}: {}", separator, json_string(&path), value);
+    }
+    json.push_str("\n}\n");
+    json
+}
+
+fn collect_values(locale: &Locale, prefix: &str, values: &mut Vec<(String, String)>) {
+    for (key, value) in &locale.keys {
+        let path = format!("{}{}", prefix, key.name);
+        let json = match value {
+            ParsedValue::String(s) => json_string(s),
+            ParsedValue::List(items) => {
+                let items = items.iter().map(|item| json_string(item));
+                format!("[{}]", items.collect::<Vec<_>>().join(", "))
+            }
+            ParsedValue::ObjectList(list) => {
+                let items = list.items.iter().map(|item| {
+                    let fields = list.fields.iter().map(|field| {
+                        let value = item.get(field).unwrap_or_default();
+                        format!("{}: {}", json_string(&field.name), json_string(value))
+                    });
+                    format!("{{ {} }}", fields.collect::<Vec<_>>().join(", "))
+                });
+                format!("[{}]", items.collect::<Vec<_>>().join(", "))
+            }
+            ParsedValue::Subkeys(subkeys) => {
+                collect_values(subkeys, &format!("{}.", path), values);
+                continue;
+            }
+            ParsedValue::ForeignKey(_) | ParsedValue::Bloc(_) => {
+                // foreign keys to strings are reduced to a string when checking the locales, after the assets are written.
+                let mut value = value.clone();
+                value.reduce();
+                match value {
+                    ParsedValue::String(s) => json_string(&s),
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        values.push((path, json));
+    }
+}
+
+/// Write the string as a JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Implement `DynamicKeys` for the type of the keys, its values being created by its `from_dynamic` function.
+pub fn create_dynamic_keys_impl(
+    type_ident: &syn::Ident,
+    namespaces: &[&Key],
+    assets: &DynamicAssets,
+) -> TokenStream {
+    let namespaces = namespaces.iter().map(|namespace| &namespace.name);
+    let DynamicAssets {
+        dir: assets_dir,
+        url: assets_url,
+    } = assets;
+    quote! {
+        impl leptos_i18n::DynamicKeys for #type_ident {
+            type Locale = Locale;
+            const NAMESPACES: &'static [&'static str] = &[#(#namespaces,)*];
+            const ASSETS_DIR: &'static str = #assets_dir;
+            const ASSETS_URL: &'static str = #assets_url;
+            fn from_dynamic(_variant: Locale, _values: &leptos_i18n::__private::DynamicValues) -> Self {
+                Self::from_dynamic(_variant, _values, "")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_json_strings() {
+        assert_eq!(
+            json_string("a \"b\" \\ c\nd\u{1}"),
+            r#""a \"b\" \\ c\nd\u0001""#
+        );
+    }
+}
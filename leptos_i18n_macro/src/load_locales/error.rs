@@ -1,8 +1,11 @@
 use std::{collections::HashSet, fmt::Display, path::PathBuf, rc::Rc};
 
 use super::{
+    cfg_file::NegotiationSource,
+    formatter::Formatter,
     key::{Key, KeyPath},
     plural::PluralType,
+    plural_rules::PluralCategory,
 };
 use quote::quote;
 
@@ -10,7 +13,15 @@ use quote::quote;
 pub type SerdeError = serde_json::Error;
 #[cfg(feature = "yaml_files")]
 pub type SerdeError = serde_yaml::Error;
-#[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
+#[cfg(any(feature = "toml_files", feature = "fluent_files", feature = "po_files"))]
+pub type SerdeError = toml::de::Error;
+#[cfg(not(any(
+    feature = "json_files",
+    feature = "yaml_files",
+    feature = "toml_files",
+    feature = "fluent_files",
+    feature = "po_files"
+)))]
 pub type SerdeError = &'static str; // whatever impl Display
 
 #[derive(Debug)]
@@ -28,8 +39,52 @@ pub enum Error {
         path: PathBuf,
         err: SerdeError,
     },
+    DynamicAssetsWrite {
+        path: PathBuf,
+        err: std::io::Error,
+    },
+    KeyManifestWrite {
+        path: PathBuf,
+        err: std::io::Error,
+    },
     DuplicateLocalesInConfig(HashSet<String>),
     DuplicateNamespacesInConfig(HashSet<String>),
+    UnknownLocaleInInheritance(Rc<Key>),
+    UnknownLocaleInDirections(Rc<Key>),
+    I18nextCompatWithoutJson,
+    LocalePackageNamespaceConflict(Rc<Key>),
+    LocalePackageKeyConflict {
+        package: Rc<Key>,
+        locale: Rc<Key>,
+    },
+    ShardsWithoutNamespaces,
+    UnknownNamespaceInShard {
+        shard: String,
+        namespace: Rc<Key>,
+    },
+    NamespaceInSeveralShards(Rc<Key>),
+    UnknownShard(String),
+    InheritanceCycle(Rc<Key>),
+    DuplicateNegotiationSource(NegotiationSource),
+    NegotiationDefaultNotLast,
+    InvalidCookieName(String),
+    CookieMaxAgeZero,
+    SameSiteNoneWithoutSecure,
+    InvalidCookiePath(String),
+    UndeclaredVariable {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        variable: String,
+    },
+    UnknownReplacementKey {
+        key_path: KeyPath,
+        replacement: String,
+    },
+    UnknownFallbackLocale {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        fallback: Rc<Key>,
+    },
     MissingKeyInLocale {
         locale: Rc<Key>,
         key_path: KeyPath,
@@ -38,6 +93,18 @@ pub enum Error {
         locale: Rc<Key>,
         key_path: KeyPath,
     },
+    ListMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    ObjectListMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    ObjectListFieldsMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
     PluralParse {
         plural: String,
         plural_type: PluralType,
@@ -47,13 +114,43 @@ pub enum Error {
         plural_type: PluralType,
     },
     ImpossibleRange(String),
+    UnknownPluralRules {
+        locale: Rc<Key>,
+        category: PluralCategory,
+    },
+    InvalidPluralCategory {
+        locale: Rc<Key>,
+        category: PluralCategory,
+        categories: Vec<PluralCategory>,
+    },
+    MissingPluralCategories {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        missing: Vec<PluralCategory>,
+    },
     PluralTypeMissmatch {
         locale: Rc<Key>,
         key_path: KeyPath,
         type1: PluralType,
         type2: PluralType,
     },
+    InvalidFormatter {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        formatter: String,
+    },
+    FormatterMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        variable: String,
+    },
     InvalidKey(String),
+    DottedKeyConflict(KeyPath),
+    KeyIdentCollision {
+        key_path: KeyPath,
+        key: Rc<Key>,
+        other: Rc<Key>,
+    },
     EmptyPlural,
     InvalidPluralType(String),
     NestedPlurals,
@@ -61,6 +158,27 @@ pub enum Error {
     MultipleFallbacks,
     MissingFallback(PluralType),
     PluralSubkeys,
+    MissingFeatureDefault,
+    InvalidFeatureValue(String),
+    FeatureValuesWithKeys,
+    InvalidVariableType {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        ty: String,
+    },
+    VariableTypeMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        variable: String,
+    },
+    InvalidSelectVariable(String),
+    InvalidSelectValue(String),
+    MissingSelectOther,
+    SelectMissmatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        variable: String,
+    },
     PluralNumberType {
         found: PluralType,
         expected: PluralType,
@@ -92,6 +210,18 @@ impl Display for Error {
             Error::ConfigFileDeser(err) => {
                 write!(f, "Parsing of cargo manifest (Cargo.toml) failed: {}", err)
             }
+            Error::DynamicAssetsWrite { path, err } => {
+                write!(f,
+                    "Could not write the values loaded at runtime to {:?} : {}",
+                    path, err
+                )
+            },
+            Error::KeyManifestWrite { path, err } => {
+                write!(f,
+                    "Could not write the key manifest to {:?} : {}",
+                    path, err
+                )
+            },
             Error::LocaleFileNotFound { path, err} => {
                 write!(f,
                     "Could not found file {:?} : {}",
@@ -103,7 +233,7 @@ impl Display for Error {
                 path, err
             ),
             Error::MissingKeyInLocale { key_path, locale } => write!(f,
-                "Some keys are different beetween locale files, locale {:?} is missing key: {}",
+                "Locale {:?} is missing key {}, missing keys are errors with the \"missing-keys\" policy of the configuration (Cargo.toml)",
                 locale, key_path
             ),
             Error::PluralParse {
@@ -134,12 +264,93 @@ impl Display for Error {
             Error::ImpossibleRange(range) => write!(f, "the range {:?} is impossible, it end before it starts",
                 range
             ),
+            Error::UnknownPluralRules { locale, category } => write!(f,
+                "the plural category {:?} can't be used in locale {:?}, the plural rules of its language are unknown",
+                category.name(), locale
+            ),
+            Error::InvalidPluralCategory { locale, category, categories } => write!(f,
+                "the language of locale {:?} has no plural category {:?}, its categories are: {}",
+                locale, category.name(), join_categories(categories)
+            ),
+            Error::MissingPluralCategories { locale, key_path, missing } => write!(f,
+                "the plurals at key {} in locale {:?} are missing the plural categories: {}",
+                key_path, locale, join_categories(missing)
+            ),
             Error::DuplicateNamespacesInConfig(duplicates) => write!(f,
                 "Found duplicates namespaces in configuration (Cargo.toml): {:?}", 
                 duplicates
             ),
+            Error::ShardsWithoutNamespaces => write!(f,
+                "\"shards\" can only be used with \"namespaces\" in configuration (Cargo.toml)"
+            ),
+            Error::UnknownNamespaceInShard { shard, namespace } => write!(f,
+                "Namespace {:?} is used in shard {:?} but is not declared in \"namespaces\" in configuration (Cargo.toml)",
+                namespace, shard
+            ),
+            Error::NamespaceInSeveralShards(namespace) => write!(f,
+                "Namespace {:?} is declared in several shards in configuration (Cargo.toml)",
+                namespace
+            ),
+            Error::UnknownShard(shard) => write!(f,
+                "Shard {:?} is not declared in \"shards\" in configuration (Cargo.toml)",
+                shard
+            ),
+            Error::UnknownLocaleInInheritance(locale) => write!(f,
+                "Locale {:?} is used in \"inherits\" but is not declared in \"locales\" in configuration (Cargo.toml)",
+                locale
+            ),
+            Error::UnknownLocaleInDirections(locale) => write!(f,
+                "Locale {:?} is used in \"directions\" but is not declared in \"locales\" in configuration (Cargo.toml)",
+                locale
+            ),
+            Error::LocalePackageNamespaceConflict(package) => write!(f,
+                "Locale package {:?} has the name of a namespace in configuration (Cargo.toml), the keys of a package are in a namespace of its name",
+                package
+            ),
+            Error::LocalePackageKeyConflict { package, locale } => write!(f,
+                "Locale {:?} has a key {:?}, which is also the name of a locale package in configuration (Cargo.toml), the keys of a package are subkeys of its name",
+                locale, package
+            ),
+            Error::I18nextCompatWithoutJson => write!(f,
+                "\"i18next-compat\" is enabled in configuration (Cargo.toml) but the locale files are not JSON files, enable the \"json_files\" feature"
+            ),
+            Error::UndeclaredVariable { locale, key_path, variable } => write!(f, "variable {:?} used at key {} in locale {:?} is not declared in the \"_variables\" section of the default locale", variable, key_path, locale),
+            Error::UnknownReplacementKey { key_path, replacement } => write!(f, "the replacement {:?} of the deprecated key {} does not exist in the default locale", replacement, key_path),
+            Error::UnknownFallbackLocale { locale, key_path, fallback } => write!(f,
+                "Unknown fallback locale {:?} at key {} in locale {:?}",
+                fallback, key_path, locale
+            ),
+            Error::DuplicateNegotiationSource(source) => write!(f,
+                "Source {:?} is listed twice in \"negotiation\" in configuration (Cargo.toml)",
+                source.name()
+            ),
+            Error::NegotiationDefaultNotLast => write!(f,
+                "Source \"default\" must be the last one of \"negotiation\" in configuration (Cargo.toml), the sources after it are never consulted"
+            ),
+            Error::InvalidCookieName(name) => write!(f,
+                "Invalid \"cookie-name\" {:?} in configuration (Cargo.toml), it must not be empty nor contain whitespace, separators or control characters",
+                name
+            ),
+            Error::CookieMaxAgeZero => write!(f,
+                "\"cookie-max-age\" must not be 0 in configuration (Cargo.toml), the cookie would expire as soon as it is set"
+            ),
+            Error::SameSiteNoneWithoutSecure => write!(f,
+                "\"cookie-same-site\" is \"none\" but \"cookie-secure\" is false in configuration (Cargo.toml), browsers reject the cookies with SameSite=None that are not Secure"
+            ),
+            Error::InvalidCookiePath(path) => write!(f,
+                "Invalid \"cookie-path\" {:?} in configuration (Cargo.toml), it must start with '/' and not contain ';' or control characters",
+                path
+            ),
+            Error::InheritanceCycle(locale) => write!(f,
+                "Locale {:?} inherits from itself in configuration (Cargo.toml)",
+                locale
+            ),
             Error::PluralTypeMissmatch { locale, key_path, type1, type2 } => write!(f, "Missmatch plural value type as key {}, locale {:?} has type {} but another locale has type {}", key_path, locale, type1, type2),
-            Error::InvalidKey(key) => write!(f, "invalid key {:?}, it can't be used as a rust identifier, try removing whitespaces and special characters", key),
+            Error::InvalidFormatter { locale, key_path, formatter } => write!(f, "Unknown formatter {:?} at key {} in locale {:?}, the supported formatters are: {}", formatter, key_path, locale, Formatter::SUPPORTED),
+            Error::FormatterMissmatch { locale, key_path, variable } => write!(f, "Missmatch formatting of variable {:?} at key {} in locale {:?}: a variable must be formatted by the same kind of formatter in every locale, and the count of plurals can only be formatted with \"number\" or \"currency\"", variable, key_path, locale),
+            Error::InvalidKey(key) => write!(f, "invalid key {:?}, it can't be used as a rust identifier, it must contain at least one letter or digit", key),
+            Error::DottedKeyConflict(key_path) => write!(f, "key {} is declared both as a value and as subkeys", key_path),
+            Error::KeyIdentCollision { key_path, key, other } => write!(f, "keys {:?} and {:?} at {} are both converted to the rust identifier {:?}, rename one of them", key.name, other.name, key_path, key.ident.to_string()),
             Error::EmptyPlural => write!(f, "empty plurals are not allowed"),
             Error::InvalidPluralType(t) => write!(f, "invalid plural type {:?}", t),
             Error::NestedPlurals => write!(f, "nested plurals are not allowed"),
@@ -147,9 +358,27 @@ impl Display for Error {
             Error::MultipleFallbacks => write!(f, "only one fallback is allowed"),
             Error::MissingFallback(t) => write!(f, "plural type {} require a fallback (or a fullrange \"..\")", t),
             Error::PluralSubkeys => write!(f, "subkeys for plurals are not allowed"),
+            Error::MissingFeatureDefault => write!(f, "values selected by feature require a default value with the \"_\" key"),
+            Error::InvalidFeatureValue(feature) => write!(f, "the value for feature {:?} must be a string or plurals, subkeys, lists and null are not allowed", feature),
+            Error::FeatureValuesWithKeys => write!(f, "values selected by feature can't be mixed with other keys"),
+            Error::InvalidVariableType { locale, key_path, ty } => write!(f, "Invalid type {:?} for a variable at key {} in locale {:?}, the type of a variable must be a rust type: {{{{ count: u32 }}}}", ty, key_path, locale),
+            Error::VariableTypeMissmatch { locale, key_path, variable } => write!(f, "Missmatch type of variable {:?} at key {} in locale {:?}: a typed variable must be declared with the same type everywhere it is used, in every locale, and the count of plurals must have the type of the plurals", variable, key_path, locale),
+            Error::InvalidSelectVariable(name) => write!(f, "invalid select variable {:?}, the \"@@select\" key must be the name of the variable selecting the value", name),
+            Error::InvalidSelectValue(variant) => write!(f, "the value for variant {:?} must be a string or plurals, subkeys, lists, null and values selected by feature are not allowed", variant),
+            Error::MissingSelectOther => write!(f, "select values require a value for the variants not declared with the \"other\" key"),
+            Error::SelectMissmatch { locale, key_path, variable } => write!(f, "Missmatch usage of variable {:?} at key {} in locale {:?}: a variable selecting a value can't be interpolated or be the count of plurals", variable, key_path, locale),
             Error::SubKeyMissmatch { locale, key_path } => {
                 write!(f, "Missmatch value type beetween locale {:?} and default at key {}: one has subkeys and the other has direct value.", locale, key_path)
             },
+            Error::ListMissmatch { locale, key_path } => {
+                write!(f, "Missmatch value type beetween locale {:?} and default at key {}: one is a list and the other is not.", locale, key_path)
+            },
+            Error::ObjectListMissmatch { locale, key_path } => {
+                write!(f, "Missmatch value type beetween locale {:?} and default at key {}: one is a list of objects and the other is not.", locale, key_path)
+            },
+            Error::ObjectListFieldsMissmatch { locale, key_path } => {
+                write!(f, "Missmatch fields in list of objects at key {} in locale {:?}: all items must have the same fields as the items of the default locale.", key_path, locale)
+            },
             Error::PluralNumberType { found, expected } => write!(f, "number type {} can't be used for plural type {}", found, expected),
             Error::ExplicitDefaultInDefault(key_path) => write!(f, "Explicit defaults (null) are not allowed in default locale, at key {}", key_path),
             Error::RecursiveForeignKey { locale, key_path } => write!(f, "Borrow Error while linking foreign key at key {} in locale {:?}, check for recursive foreign key.", key_path, locale),
@@ -159,6 +388,11 @@ impl Display for Error {
     }
 }
 
+fn join_categories(categories: &[PluralCategory]) -> String {
+    let names = categories.iter().map(|category| category.name());
+    names.collect::<Vec<_>>().join(", ")
+}
+
 impl From<Error> for proc_macro::TokenStream {
     fn from(value: Error) -> Self {
         let error = value.to_string();
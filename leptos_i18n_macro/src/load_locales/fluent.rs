@@ -0,0 +1,866 @@
+//! Conversion of Fluent (`.ftl`) files to the values of the other formats.
+//!
+//! - placeables of variables (`{ $name }`) become variables (`{{ name }}`),
+//! - references to messages (`{ other.attribute }`) become foreign keys (`{{ @other.attribute }}`),
+//! - references to terms (`{ -brand }`) and string literals are inlined,
+//! - a select expression on a variable becomes a plural on that variable, renamed `count`,
+//!   or a select value on that variable if one of its variants is not a number or a plural category,
+//! - the attributes of a message become its subkeys, the value of the message being the `value` subkey,
+//! - the comment just above a message becomes its description.
+
+use std::collections::HashMap;
+
+use toml::{map::Map, Value};
+
+/// Subkey of the value of a message with attributes.
+const VALUE_KEY: &str = "value";
+/// Keys of the select values, see `ParsedValue::SELECT_KEY`.
+const SELECT_KEY: &str = "@@select";
+const SELECT_OTHER: &str = "other";
+
+/// Whether the key of a variant is a number or a plural category, the select expression being a plural.
+fn is_plural_variant(key: &str) -> bool {
+    matches!(key, "zero" | "one" | "two" | "few" | "many" | "other") || key.parse::<f64>().is_ok()
+}
+
+/// Parse the Fluent source into a table of values, `namespace` being used to prefix the foreign keys.
+pub fn parse(source: &str, namespace: Option<&str>) -> Result<Value, String> {
+    let raw_entries = split_entries(source)?;
+    let mut entries = Vec::with_capacity(raw_entries.len());
+    for raw in &raw_entries {
+        let entry = Entry::parse(raw).map_err(|err| format!("line {}: {}", raw.line, err))?;
+        entries.push(entry);
+    }
+
+    let context = Context {
+        namespace,
+        terms: entries
+            .iter()
+            .filter(|entry| entry.is_term)
+            .map(|entry| (entry.id, entry))
+            .collect(),
+        with_attributes: entries
+            .iter()
+            .filter(|entry| !entry.is_term && !entry.attributes.is_empty())
+            .map(|entry| entry.id)
+            .collect(),
+    };
+
+    let mut table = Map::new();
+    for entry in entries.iter().filter(|entry| !entry.is_term) {
+        let value = context
+            .message_to_value(entry)
+            .map_err(|err| format!("line {}: in message {:?}: {}", entry.line, entry.id, err))?;
+        if table.insert(entry.id.to_string(), value).is_some() {
+            return Err(format!(
+                "line {}: message {:?} is declared twice",
+                entry.line, entry.id
+            ));
+        }
+        if let Some(comment) = &entry.comment {
+            table.insert(format!("@{}", entry.id), Value::String(comment.clone()));
+        }
+    }
+    Ok(Value::Table(table))
+}
+
+// -----------------------------------------
+// Entries
+// -----------------------------------------
+
+/// The lines of a message or a term, and the comment above it.
+struct RawEntry<'a> {
+    line: usize,
+    header: &'a str,
+    body: Vec<&'a str>,
+    comment: Vec<&'a str>,
+}
+
+/// Split the source in entries, each one starting at a line that is not indented and ending at the next one.
+fn split_entries(source: &str) -> Result<Vec<RawEntry<'_>>, String> {
+    let mut entries: Vec<RawEntry> = vec![];
+    let mut in_entry = false;
+    let mut comment = vec![];
+    for (i, line) in source.lines().enumerate() {
+        if let Some(rest) = line.strip_prefix('#') {
+            in_entry = false;
+            // group and resource comments (`##`, `###`) are not attached to a message.
+            if rest.starts_with('#') {
+                comment.clear();
+            } else {
+                comment.push(rest);
+            }
+        } else if line.trim().is_empty() {
+            match entries.last_mut() {
+                Some(entry) if in_entry => entry.body.push(line),
+                _ => comment.clear(),
+            }
+        } else if line.starts_with(char::is_whitespace) {
+            match entries.last_mut() {
+                Some(entry) if in_entry => entry.body.push(line),
+                _ => return Err(format!("line {}: unexpected indented line", i + 1)),
+            }
+        } else {
+            in_entry = true;
+            entries.push(RawEntry {
+                line: i + 1,
+                header: line,
+                body: vec![],
+                comment: std::mem::take(&mut comment),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+struct Entry<'a> {
+    line: usize,
+    id: &'a str,
+    is_term: bool,
+    value: Option<Vec<Element>>,
+    attributes: Vec<(String, Vec<Element>)>,
+    comment: Option<String>,
+}
+
+impl<'a> Entry<'a> {
+    fn parse(raw: &RawEntry<'a>) -> Result<Self, String> {
+        let (id, first_line) = raw
+            .header
+            .split_once('=')
+            .ok_or_else(|| format!("expected `=` after the identifier in {:?}", raw.header))?;
+        let id = id.trim();
+        let (is_term, name) = match id.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, id),
+        };
+        if !is_identifier(name) {
+            return Err(format!("invalid identifier {:?}", id));
+        }
+
+        let mut value_lines = vec![];
+        let mut attributes: Vec<(&str, &str, Vec<&str>)> = vec![];
+        let mut depth = brace_depth(first_line, 0);
+        for &line in &raw.body {
+            let trimmed = line.trim_start();
+            if depth == 0 && trimmed.starts_with('.') {
+                let (name, first) = trimmed[1..]
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected `=` after the attribute in {:?}", trimmed))?;
+                let name = name.trim();
+                if !is_identifier(name) {
+                    return Err(format!("invalid attribute identifier {:?}", name));
+                }
+                depth = brace_depth(first, 0);
+                attributes.push((name, first, vec![]));
+            } else {
+                depth = brace_depth(line, depth);
+                match attributes.last_mut() {
+                    Some((_, _, lines)) => lines.push(line),
+                    None => value_lines.push(line),
+                }
+            }
+        }
+
+        let value = block_text(first_line, &value_lines);
+        let value = if value.is_empty() {
+            None
+        } else {
+            Some(parse_pattern(&value)?)
+        };
+        let attributes = attributes
+            .into_iter()
+            .map(|(name, first, lines)| {
+                let pattern = parse_pattern(&block_text(first, &lines))
+                    .map_err(|err| format!("in attribute {:?}: {}", name, err))?;
+                Ok((name.to_string(), pattern))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        if value.is_none() && attributes.is_empty() {
+            return Err(format!("{:?} has no value", id));
+        }
+        if is_term && value.is_none() {
+            return Err(format!("term {:?} must have a value", id));
+        }
+        if !is_term && attributes.iter().any(|(name, _)| name == VALUE_KEY) {
+            return Err(format!(
+                "the attribute {:?} is reserved for the value of the message",
+                VALUE_KEY
+            ));
+        }
+
+        let comment = raw
+            .comment
+            .iter()
+            .map(|line| line.trim())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Entry {
+            line: raw.line,
+            id: name,
+            is_term,
+            value,
+            attributes,
+            comment: (!comment.is_empty()).then_some(comment),
+        })
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Depth of the placeables at the end of the line, ignoring the braces in string literals.
+fn brace_depth(line: &str, mut depth: usize) -> usize {
+    let mut in_string = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' if depth > 0 => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    depth
+}
+
+/// Join the first line and the indented lines of a pattern, removing their common indentation.
+fn block_text(first_line: &str, lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let first_line = first_line.trim();
+    let mut text_lines = vec![];
+    if !first_line.is_empty() {
+        text_lines.push(first_line);
+    }
+    text_lines.extend(
+        lines
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or("").trim_end()),
+    );
+    while text_lines.first().is_some_and(|line| line.is_empty()) {
+        text_lines.remove(0);
+    }
+    text_lines.join("\n").trim_end().to_string()
+}
+
+// -----------------------------------------
+// Patterns
+// -----------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    Text(String),
+    Placeable(Expression),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+    Variable(String),
+    Message {
+        id: String,
+        attribute: Option<String>,
+    },
+    Term {
+        id: String,
+        attribute: Option<String>,
+    },
+    Literal(String),
+    Select {
+        selector: String,
+        variants: Vec<Variant>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Variant {
+    key: String,
+    default: bool,
+    pattern: Vec<Element>,
+}
+
+fn parse_pattern(text: &str) -> Result<Vec<Element>, String> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+    };
+    let pattern = parser.pattern(false)?;
+    match parser.peek() {
+        None => Ok(pattern),
+        Some(c) => Err(format!("unexpected {:?}", c)),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected {:?}, found {:?}", expected, c)),
+            None => Err(format!(
+                "expected {:?}, found the end of the pattern",
+                expected
+            )),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Parse text and placeables, in a variant the pattern ends at the line starting the next variant or closing the select.
+    fn pattern(&mut self, in_variant: bool) -> Result<Vec<Element>, String> {
+        let mut elements = vec![];
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    self.pos += 1;
+                    if !text.is_empty() {
+                        elements.push(Element::Text(std::mem::take(&mut text)));
+                    }
+                    elements.push(Element::Placeable(self.placeable()?));
+                }
+                '}' if in_variant => break,
+                '}' => return Err("unbalanced `}`".to_string()),
+                '\n' if in_variant => {
+                    let line_start = self.pos;
+                    self.skip_whitespace();
+                    if matches!(self.peek(), Some('[' | '*' | '}') | None) {
+                        break;
+                    }
+                    self.pos = line_start + 1;
+                    text.push('\n');
+                    while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+                        self.pos += 1;
+                    }
+                }
+                c => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+            }
+        }
+        if in_variant {
+            text.truncate(text.trim_end().len());
+        }
+        if !text.is_empty() {
+            elements.push(Element::Text(text));
+        }
+        Ok(elements)
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            self.pos += 1;
+        }
+        let identifier = self.chars[start..self.pos].iter().collect::<String>();
+        if is_identifier(&identifier) {
+            Ok(identifier)
+        } else {
+            Err(format!("invalid identifier {:?}", identifier))
+        }
+    }
+
+    fn attribute(&mut self) -> Result<Option<String>, String> {
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            self.identifier().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn no_call(&self, name: &str) -> Result<(), String> {
+        if self.peek() == Some('(') {
+            Err(format!(
+                "functions and parameterized terms are not supported, found {:?}",
+                name
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Parse a placeable, after the opening `{`.
+    fn placeable(&mut self) -> Result<Expression, String> {
+        self.skip_whitespace();
+        let expression = match self.peek() {
+            Some('$') => {
+                self.pos += 1;
+                Expression::Variable(self.identifier()?)
+            }
+            Some('-')
+                if !self
+                    .chars
+                    .get(self.pos + 1)
+                    .is_some_and(char::is_ascii_digit) =>
+            {
+                self.pos += 1;
+                let id = self.identifier()?;
+                let attribute = self.attribute()?;
+                self.no_call(&id)?;
+                Expression::Term { id, attribute }
+            }
+            Some('"') => {
+                self.pos += 1;
+                Expression::Literal(self.string_literal()?)
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => Expression::Literal(self.number()),
+            Some(c) if c.is_ascii_alphabetic() => {
+                let id = self.identifier()?;
+                let attribute = self.attribute()?;
+                self.no_call(&id)?;
+                Expression::Message { id, attribute }
+            }
+            Some(c) => return Err(format!("unexpected {:?} in placeable", c)),
+            None => return Err("unclosed placeable".to_string()),
+        };
+        self.skip_whitespace();
+        if self.peek() == Some('-') && self.chars.get(self.pos + 1) == Some(&'>') {
+            self.pos += 2;
+            let Expression::Variable(selector) = expression else {
+                return Err("only variables can be used as selectors".to_string());
+            };
+            let variants = self.variants()?;
+            self.expect('}')?;
+            return Ok(Expression::Select { selector, variants });
+        }
+        self.expect('}')?;
+        Ok(expression)
+    }
+
+    fn variants(&mut self) -> Result<Vec<Variant>, String> {
+        let mut variants = vec![];
+        loop {
+            self.skip_whitespace();
+            let default = match self.peek() {
+                Some('}') => break,
+                Some('*') => {
+                    self.pos += 1;
+                    true
+                }
+                _ => false,
+            };
+            self.expect('[')?;
+            self.skip_whitespace();
+            let key = match self.peek() {
+                Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+                _ => self.identifier()?,
+            };
+            self.skip_whitespace();
+            self.expect(']')?;
+            while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+                self.pos += 1;
+            }
+            let pattern = self.pattern(true)?;
+            variants.push(Variant {
+                key,
+                default,
+                pattern,
+            });
+        }
+        match variants.iter().filter(|variant| variant.default).count() {
+            1 => Ok(variants),
+            0 => Err("the select expression has no default variant (`*[...]`)".to_string()),
+            _ => Err("the select expression has several default variants".to_string()),
+        }
+    }
+
+    fn number(&mut self) -> String {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Parse a string literal, after the opening `"`.
+    fn string_literal(&mut self) -> Result<String, String> {
+        let mut literal = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(literal),
+                Some('\\') => match self.next() {
+                    Some(c @ ('"' | '\\')) => literal.push(c),
+                    Some(u @ ('u' | 'U')) => {
+                        let len = if u == 'u' { 4 } else { 6 };
+                        let hex = self
+                            .chars
+                            .get(self.pos..self.pos + len)
+                            .map(|hex| hex.iter().collect::<String>())
+                            .unwrap_or_default();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("invalid unicode escape {:?}", hex))?;
+                        self.pos += len;
+                        literal.push(c);
+                    }
+                    c => return Err(format!("invalid escape sequence {:?}", c)),
+                },
+                Some('\n') | None => return Err("unclosed string literal".to_string()),
+                Some(c) => literal.push(c),
+            }
+        }
+    }
+}
+
+// -----------------------------------------
+// Conversion
+// -----------------------------------------
+
+struct Context<'a> {
+    namespace: Option<&'a str>,
+    terms: HashMap<&'a str, &'a Entry<'a>>,
+    with_attributes: Vec<&'a str>,
+}
+
+impl Context<'_> {
+    fn message_to_value(&self, entry: &Entry) -> Result<Value, String> {
+        if entry.attributes.is_empty() {
+            let value = entry.value.as_deref().unwrap_or_default();
+            return self.pattern_to_value(value);
+        }
+        let mut table = Map::new();
+        if let Some(value) = &entry.value {
+            table.insert(VALUE_KEY.to_string(), self.pattern_to_value(value)?);
+        }
+        for (name, pattern) in &entry.attributes {
+            let value = self
+                .pattern_to_value(pattern)
+                .map_err(|err| format!("in attribute {:?}: {}", name, err))?;
+            table.insert(name.clone(), value);
+        }
+        Ok(Value::Table(table))
+    }
+
+    /// Convert the pattern to a string, or to a plural or a select value if it contains a select expression.
+    fn pattern_to_value(&self, pattern: &[Element]) -> Result<Value, String> {
+        let mut selects = pattern
+            .iter()
+            .enumerate()
+            .filter_map(|(i, element)| match element {
+                Element::Placeable(Expression::Select { selector, variants }) => {
+                    Some((i, selector, variants))
+                }
+                _ => None,
+            });
+        let Some((i, selector, variants)) = selects.next() else {
+            return self.render(pattern, None, 0).map(Value::String);
+        };
+        if selects.next().is_some() {
+            return Err("only one select expression is supported per pattern".to_string());
+        }
+        if variants
+            .iter()
+            .any(|variant| !variant.default && !is_plural_variant(&variant.key))
+        {
+            return self.select_to_value(pattern, i, selector, variants);
+        }
+        let prefix = self.render(&pattern[..i], Some(selector), 0)?;
+        let suffix = self.render(&pattern[i + 1..], Some(selector), 0)?;
+
+        let mut branches = vec![];
+        let mut default = None;
+        let mut is_float = false;
+        for variant in variants {
+            let text = format!(
+                "{}{}{}",
+                prefix,
+                self.render(&variant.pattern, Some(selector), 0)?,
+                suffix
+            );
+            if variant.default {
+                default = Some(text);
+                continue;
+            }
+            let count = match variant.key.as_str() {
+                "zero" => "0",
+                "one" => "1",
+                "two" => "2",
+                key if key.parse::<f64>().is_ok() => key,
+                key => {
+                    return Err(format!(
+                        "plural category {:?} is not supported, use numbers or make it the default variant",
+                        key
+                    ))
+                }
+            };
+            is_float |= count.contains('.');
+            branches.push(Value::Array(vec![
+                Value::String(text),
+                Value::String(count.to_string()),
+            ]));
+        }
+        if let Some(default) = default {
+            branches.push(Value::Array(vec![
+                Value::String(default),
+                Value::String("_".to_string()),
+            ]));
+        }
+        if is_float {
+            branches.insert(0, Value::String("f64".to_string()));
+        }
+        Ok(Value::Array(branches))
+    }
+
+    /// Convert the pattern with the select expression at index `i` to a select value on the selector,
+    /// the default variant being the `other` value.
+    fn select_to_value(
+        &self,
+        pattern: &[Element],
+        i: usize,
+        selector: &str,
+        variants: &[Variant],
+    ) -> Result<Value, String> {
+        let prefix = self.render(&pattern[..i], None, 0)?;
+        let suffix = self.render(&pattern[i + 1..], None, 0)?;
+        let mut table = Map::new();
+        table.insert(SELECT_KEY.to_string(), Value::String(selector.to_string()));
+        for variant in variants {
+            let text = format!(
+                "{}{}{}",
+                prefix,
+                self.render(&variant.pattern, None, 0)?,
+                suffix
+            );
+            let key = if variant.default {
+                SELECT_OTHER
+            } else {
+                &variant.key
+            };
+            if table.insert(key.to_string(), Value::String(text)).is_some() {
+                return Err(format!("variant {:?} is declared twice", key));
+            }
+        }
+        Ok(Value::Table(table))
+    }
+
+    /// Render the pattern to the syntax of the other formats, the selector being renamed `count`.
+    fn render(
+        &self,
+        pattern: &[Element],
+        selector: Option<&str>,
+        depth: usize,
+    ) -> Result<String, String> {
+        // terms referencing each other in a loop.
+        if depth > 16 {
+            return Err("too many nested terms".to_string());
+        }
+        let mut output = String::new();
+        for element in pattern {
+            match element {
+                Element::Text(text) => output.push_str(text),
+                Element::Placeable(Expression::Literal(literal)) => output.push_str(literal),
+                Element::Placeable(Expression::Variable(name)) => {
+                    let name = if Some(name.as_str()) == selector {
+                        "count"
+                    } else {
+                        name
+                    };
+                    output.push_str(&format!("{{{{ {} }}}}", name));
+                }
+                Element::Placeable(Expression::Message { id, attribute }) => {
+                    let attribute = match attribute {
+                        Some(attribute) => Some(attribute.as_str()),
+                        None if self.with_attributes.contains(&id.as_str()) => Some(VALUE_KEY),
+                        None => None,
+                    };
+                    output.push_str("{{ @");
+                    if let Some(namespace) = self.namespace {
+                        output.push_str(namespace);
+                        output.push_str("::");
+                    }
+                    output.push_str(id);
+                    if let Some(attribute) = attribute {
+                        output.push('.');
+                        output.push_str(attribute);
+                    }
+                    output.push_str(" }}");
+                }
+                Element::Placeable(Expression::Term { id, attribute }) => {
+                    let term = self
+                        .terms
+                        .get(id.as_str())
+                        .ok_or_else(|| format!("unknown term \"-{}\"", id))?;
+                    let pattern = match attribute {
+                        None => term.value.as_deref().unwrap_or_default(),
+                        Some(attribute) => term
+                            .attributes
+                            .iter()
+                            .find_map(|(name, pattern)| (name == attribute).then_some(pattern))
+                            .ok_or_else(|| {
+                                format!("unknown attribute {:?} of term \"-{}\"", attribute, id)
+                            })?,
+                    };
+                    output.push_str(&self.render(pattern, selector, depth + 1)?);
+                }
+                Element::Placeable(Expression::Select { .. }) => {
+                    return Err(
+                        "select expressions can only be used at the top level of a message"
+                            .to_string(),
+                    )
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_ok(source: &str) -> Value {
+        parse(source, None).unwrap()
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn messages_and_variables() {
+        let source = r#"
+# Shown on the home page
+hello = Hello { $user-name }, welcome to { -brand }!
+-brand = Acme { "{" }Corp{ "}" }
+multiline =
+    First line
+      indented
+    Last line
+reference = See { hello }
+"#;
+        let value = parse_ok(source);
+        assert_eq!(
+            value["hello"],
+            string("Hello {{ user-name }}, welcome to Acme {Corp}!")
+        );
+        assert_eq!(value["@hello"], string("Shown on the home page"));
+        assert_eq!(
+            value["multiline"],
+            string("First line\n  indented\nLast line")
+        );
+        assert_eq!(value["reference"], string("See {{ @hello }}"));
+        assert!(value.get("brand").is_none());
+        assert!(value.get("-brand").is_none());
+    }
+
+    #[test]
+    fn select_to_plural() {
+        let source = r#"
+emails = You have { $unread ->
+    [0] no email
+    [one] one email
+   *[other] { $unread } emails
+  } from { $sender }.
+"#;
+        let value = parse_ok(source);
+        assert_eq!(
+            value["emails"],
+            Value::Array(vec![
+                Value::Array(vec![
+                    string("You have no email from {{ sender }}."),
+                    string("0")
+                ]),
+                Value::Array(vec![
+                    string("You have one email from {{ sender }}."),
+                    string("1")
+                ]),
+                Value::Array(vec![
+                    string("You have {{ count }} emails from {{ sender }}."),
+                    string("_")
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn select_to_select_value() {
+        let source = r#"
+invited = { $gender ->
+    [female] She invited { $guest }.
+    [male] He invited { $guest }.
+   *[other] They invited { $guest }.
+  }
+"#;
+        let value = parse_ok(source);
+        assert_eq!(value["invited"]["@@select"], string("gender"));
+        assert_eq!(
+            value["invited"]["female"],
+            string("She invited {{ guest }}.")
+        );
+        assert_eq!(value["invited"]["male"], string("He invited {{ guest }}."));
+        assert_eq!(
+            value["invited"]["other"],
+            string("They invited {{ guest }}.")
+        );
+    }
+
+    #[test]
+    fn attributes_to_subkeys() {
+        let source =
+            "login = Log in\n    .title = Click to log in\ntitle = { login.title } / { login }\n";
+        let value = parse(source, Some("common")).unwrap();
+        assert_eq!(value["login"]["value"], string("Log in"));
+        assert_eq!(value["login"]["title"], string("Click to log in"));
+        assert_eq!(
+            value["title"],
+            string("{{ @common::login.title }} / {{ @common::login.value }}")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("a = { NUMBER($x) }", None)
+            .unwrap_err()
+            .contains("functions"));
+        assert!(parse("a = { $x ->\n [one] one\n }", None)
+            .unwrap_err()
+            .contains("no default variant"));
+        assert!(parse("a = { $x ->\n [few] few\n *[other] other\n }", None)
+            .unwrap_err()
+            .contains("\"few\""));
+        assert_eq!(
+            parse("a = a\na = b", None).unwrap_err(),
+            "line 2: message \"a\" is declared twice"
+        );
+        assert!(parse("a = { -missing }", None)
+            .unwrap_err()
+            .starts_with("line 1: in message \"a\": unknown term"));
+    }
+}
@@ -0,0 +1,228 @@
+use std::{fmt::Display, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::{
+    key::Key,
+    warning::{emit_warning, Warning},
+};
+
+/// Formatter of an interpolated variable: `{{ count, number }}`, `{{ price, currency(EUR) }}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Formatter {
+    Number,
+    Currency(String),
+    Date,
+    Time,
+    DateTime,
+}
+
+/// Kind of value expected by a formatter, the variables formatted by formatters of the same kind share the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatterKind {
+    Number,
+    Date,
+    Time,
+    DateTime,
+}
+
+impl Formatter {
+    pub const SUPPORTED: &'static str =
+        "number, currency(<ISO 4217 code>), date(short), time(short), datetime(short)";
+
+    /// Parse a formatter declaration, `name` or `name(arg)`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (name, arg) = match s.split_once('(') {
+            Some((name, rest)) => (name.trim(), Some(rest.strip_suffix(')')?.trim())),
+            None => (s, None),
+        };
+        match (name, arg) {
+            ("number", None) => Some(Formatter::Number),
+            ("currency", Some(code))
+                if code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase()) =>
+            {
+                Some(Formatter::Currency(code.to_string()))
+            }
+            ("date", None | Some("short")) => Some(Formatter::Date),
+            ("time", None | Some("short")) => Some(Formatter::Time),
+            ("datetime", None | Some("short")) => Some(Formatter::DateTime),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> FormatterKind {
+        match self {
+            Formatter::Number | Formatter::Currency(_) => FormatterKind::Number,
+            Formatter::Date => FormatterKind::Date,
+            Formatter::Time => FormatterKind::Time,
+            Formatter::DateTime => FormatterKind::DateTime,
+        }
+    }
+
+    /// Return the expression formatting the value returned by the function `value` to a `String` for the locale.
+    pub fn format_tokens(&self, value: TokenStream, locale: &Rc<Key>) -> TokenStream {
+        let locale_name = &locale.name;
+        match self {
+            Formatter::Number => {
+                let symbols = NumberSymbols::of_locale(locale);
+                quote!(leptos_i18n::__private::NumberFn::format_number(#value, #locale_name, &#symbols))
+            }
+            Formatter::Currency(code) => {
+                let symbols = NumberSymbols::of_locale(locale);
+                quote!(leptos_i18n::__private::NumberFn::format_currency(#value, #code, #locale_name, &#symbols))
+            }
+            Formatter::Date => {
+                quote!(leptos_i18n::__private::DateFn::format_date(#value, #locale_name))
+            }
+            Formatter::Time => {
+                quote!(leptos_i18n::__private::TimeFn::format_time(#value, #locale_name))
+            }
+            Formatter::DateTime => {
+                quote!(leptos_i18n::__private::DateTimeFn::format_datetime(#value, #locale_name))
+            }
+        }
+    }
+}
+
+impl Display for Formatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Formatter::Number => f.write_str("number"),
+            Formatter::Currency(code) => write!(f, "currency({})", code),
+            Formatter::Date => f.write_str("date"),
+            Formatter::Time => f.write_str("time"),
+            Formatter::DateTime => f.write_str("datetime"),
+        }
+    }
+}
+
+impl FormatterKind {
+    /// Bound of the value of the variables formatted with a formatter of this kind.
+    pub fn get_generic(self) -> TokenStream {
+        match self {
+            FormatterKind::Number => quote!(leptos_i18n::__private::NumberFn),
+            FormatterKind::Date => quote!(leptos_i18n::__private::DateFn),
+            FormatterKind::Time => quote!(leptos_i18n::__private::TimeFn),
+            FormatterKind::DateTime => quote!(leptos_i18n::__private::DateTimeFn),
+        }
+    }
+}
+
+/// Separators of the numbers of a locale, used when the `icu` feature is disabled to format the interpolated numbers
+/// and, with or without it, the decimal separator of the floats written as values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSymbols {
+    pub group: &'static str,
+    pub decimal: &'static str,
+    /// Minimum number of digits before the first group separator, `2` to write `1234` but `12 345`.
+    pub min_grouping: usize,
+}
+
+impl NumberSymbols {
+    const ENGLISH: Self = NumberSymbols {
+        group: ",",
+        decimal: ".",
+        min_grouping: 1,
+    };
+
+    /// Return the separators of the locale, `None` if its language is not known.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let mut subtags = locale.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        let region = subtags.find(|subtag| subtag.len() == 2 || subtag.len() == 3);
+        let region = region.map(str::to_ascii_uppercase);
+        let (group, decimal, min_grouping) = match (language.as_str(), region.as_deref()) {
+            ("de" | "it" | "rm" | "gsw", Some("CH" | "LI")) => ("\u{2019}", ".", 1),
+            ("pt", Some("PT")) => ("\u{a0}", ",", 2),
+            ("fr", _) => ("\u{202f}", ",", 1),
+            ("es", _) => (".", ",", 2),
+            ("pl", _) => ("\u{a0}", ",", 2),
+            (
+                "de" | "it" | "nl" | "pt" | "da" | "id" | "tr" | "el" | "ro" | "hr" | "sl" | "sr"
+                | "vi" | "is" | "ca" | "gl" | "eu",
+                _,
+            ) => (".", ",", 1),
+            (
+                "ru" | "uk" | "be" | "cs" | "sk" | "fi" | "sv" | "nb" | "no" | "nn" | "bg" | "hu"
+                | "lt" | "lv" | "et" | "kk",
+                _,
+            ) => ("\u{a0}", ",", 1),
+            (
+                "en" | "ja" | "zh" | "ko" | "hi" | "th" | "he" | "ms" | "fil" | "ga" | "cy" | "mt"
+                | "sw",
+                _,
+            ) => (",", ".", 1),
+            _ => return None,
+        };
+        Some(NumberSymbols {
+            group,
+            decimal,
+            min_grouping,
+        })
+    }
+
+    /// Return the separators of the locale, the English ones with a warning if its language is not known
+    /// and the numbers are not formatted with ICU4X.
+    pub fn of_locale(locale: &Rc<Key>) -> Self {
+        Self::from_locale(&locale.name).unwrap_or_else(|| {
+            if !cfg!(feature = "icu") {
+                emit_warning(Warning::UnknownNumberFormat {
+                    locale: Rc::clone(locale),
+                });
+            }
+            Self::ENGLISH
+        })
+    }
+}
+
+impl ToTokens for NumberSymbols {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let NumberSymbols {
+            group,
+            decimal,
+            min_grouping,
+        } = self;
+        tokens.extend(quote! {
+            leptos_i18n::__private::NumberSymbols {
+                group: #group,
+                decimal: #decimal,
+                min_grouping: #min_grouping,
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_formatters() {
+        assert_eq!(Formatter::parse("number"), Some(Formatter::Number));
+        assert_eq!(
+            Formatter::parse(" currency( EUR ) "),
+            Some(Formatter::Currency("EUR".to_string()))
+        );
+        assert_eq!(
+            Formatter::parse("datetime(short)"),
+            Some(Formatter::DateTime)
+        );
+        assert_eq!(Formatter::parse("currency"), None);
+        assert_eq!(Formatter::parse("currency(euro)"), None);
+        assert_eq!(Formatter::parse("date(full)"), None);
+        assert_eq!(Formatter::parse("percent"), None);
+    }
+
+    #[test]
+    fn number_symbols_of_locales() {
+        let symbols = |locale| NumberSymbols::from_locale(locale).map(|s| (s.group, s.decimal));
+        assert_eq!(symbols("en-US"), Some((",", ".")));
+        assert_eq!(symbols("de"), Some((".", ",")));
+        assert_eq!(symbols("de-CH"), Some(("\u{2019}", ".")));
+        assert_eq!(symbols("fr_CA"), Some(("\u{202f}", ",")));
+        assert_eq!(symbols("kk"), Some(("\u{a0}", ",")));
+        assert_eq!(symbols("xx"), None);
+    }
+}
@@ -0,0 +1,57 @@
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{cfg_file::ConfigFile, key::Key, locale::FILE_FORMAT};
+
+/// Where the locale files are read again from at runtime in debug builds, with the `hot_reload` feature.
+pub struct HotReload {
+    pub dir: String,
+    /// When the locale files were read, in nanoseconds since the Unix epoch, the files modified after it being read again.
+    pub build_time: u64,
+}
+
+impl HotReload {
+    pub fn new(manifest_dir: &Path, cfg_file: &ConfigFile) -> Option<Self> {
+        if !cfg!(feature = "hot_reload") {
+            return None;
+        }
+        let build_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_nanos() as u64);
+        Some(HotReload {
+            dir: manifest_dir
+                .join(&*cfg_file.locales_dir)
+                .to_string_lossy()
+                .into_owned(),
+            build_time,
+        })
+    }
+}
+
+/// Implement `HotReloadKeys` for the type of the keys in debug builds, its values being created by its `from_dynamic` function.
+pub fn create_hot_reload_keys_impl(
+    type_ident: &syn::Ident,
+    namespaces: &[&Key],
+    hot_reload: &HotReload,
+) -> TokenStream {
+    let namespaces = namespaces.iter().map(|namespace| &namespace.name);
+    let HotReload { dir, build_time } = hot_reload;
+    quote! {
+        #[cfg(debug_assertions)]
+        impl leptos_i18n::__private::HotReloadKeys for #type_ident {
+            type Locale = Locale;
+            const NAMESPACES: &'static [&'static str] = &[#(#namespaces,)*];
+            const LOCALES_DIR: &'static str = #dir;
+            const EXTENSION: &'static str = #FILE_FORMAT;
+            const BUILD_TIME: u64 = #build_time;
+            fn from_dynamic(_variant: Locale, _values: &leptos_i18n::__private::DynamicValues) -> Self {
+                Self::from_dynamic(_variant, _values, "")
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Conversion of the i18next dialect of the JSON files, with `i18next-compat = true`.
+//!
+//! - unescaped interpolations (`{{- name }}`) become variables (`{{ name }}`), inserted as text,
+//! - nesting (`$t(key)`, `$t(namespace:key)`) becomes foreign keys (`{{ @key }}`, `{{ @namespace::key }}`),
+//! - the keys with a plural suffix (`key_one`, `key_other`, ...) become a plural `key` on the plural categories,
+//!   `key_zero` matching a count of `0` in every language as i18next does,
+//! - the legacy `key` and `key_plural` pair becomes a plural `key`, `key` being used for the `one` category,
+//! - nested objects are subkeys, as in the other files.
+//!
+//! The other values, including the formatted variables (`{{ price, number }}`), already have the same syntax.
+
+use serde_json::{Map, Value};
+
+/// The plural suffixes, in the order of the plurals, with the count they match.
+const PLURAL_SUFFIXES: &[(&str, PluralCount)] = &[
+    ("_zero", PluralCount::Exact(0)),
+    ("_one", PluralCount::Category("one")),
+    ("_two", PluralCount::Category("two")),
+    ("_few", PluralCount::Category("few")),
+    ("_many", PluralCount::Category("many")),
+    ("_other", PluralCount::Category("other")),
+];
+/// Suffix of the plural value of the legacy format.
+const LEGACY_PLURAL_SUFFIX: &str = "_plural";
+/// Suffix of the ordinal plurals (`key_ordinal_one`), that are not supported.
+const ORDINAL_SUFFIX: &str = "_ordinal";
+
+#[derive(Clone, Copy)]
+enum PluralCount {
+    Exact(i64),
+    Category(&'static str),
+}
+
+impl From<PluralCount> for Value {
+    fn from(count: PluralCount) -> Self {
+        match count {
+            PluralCount::Exact(count) => Value::from(count),
+            PluralCount::Category(category) => Value::from(category),
+        }
+    }
+}
+
+/// Convert the values of an i18next file to the values of the other JSON files.
+pub fn convert(value: Value) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => convert_map(map, "").map(Value::Object),
+        _ => Err("expected a map of keys".to_string()),
+    }
+}
+
+fn convert_value(value: Value, path: &str) -> Result<Value, String> {
+    match value {
+        Value::String(s) => convert_string(&s)
+            .map(Value::String)
+            .map_err(|err| format!("at key {:?}: {}", path, err)),
+        Value::Object(map) => convert_map(map, path).map(Value::Object),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| convert_value(item, path))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        value => Ok(value),
+    }
+}
+
+fn convert_map(map: Map<String, Value>, path: &str) -> Result<Map<String, Value>, String> {
+    let mut converted = Map::new();
+    for (key, value) in map {
+        let value = convert_value(value, &join_path(path, &key))?;
+        converted.insert(key, value);
+    }
+    group_plurals(&mut converted, path)?;
+    group_legacy_plurals(&mut converted);
+    Ok(converted)
+}
+
+/// Replace the keys with a plural suffix by a plural on their base key, for every base key with an `_other` suffix.
+fn group_plurals(map: &mut Map<String, Value>, path: &str) -> Result<(), String> {
+    let bases = map
+        .keys()
+        .filter_map(|key| key.strip_suffix("_other"))
+        .filter(|base| !base.is_empty() && !base.ends_with(ORDINAL_SUFFIX))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    for base in bases {
+        if map.contains_key(&base) {
+            return Err(format!(
+                "key {:?} is declared both as a value and with plural suffixes",
+                join_path(path, &base)
+            ));
+        }
+        let mut plurals = vec![];
+        for (suffix, count) in PLURAL_SUFFIXES {
+            let Some(value) = map.remove(&format!("{}{}", base, suffix)) else {
+                continue;
+            };
+            if !value.is_string() {
+                return Err(format!(
+                    "the plural value at key {:?} must be a string",
+                    join_path(path, &format!("{}{}", base, suffix))
+                ));
+            }
+            plurals.push(Value::Array(vec![value, Value::from(*count)]));
+        }
+        map.insert(base, Value::Array(plurals));
+    }
+    Ok(())
+}
+
+/// Replace the `key` and `key_plural` string pairs by a plural on `key`.
+fn group_legacy_plurals(map: &mut Map<String, Value>) {
+    let bases = map
+        .keys()
+        .filter_map(|key| key.strip_suffix(LEGACY_PLURAL_SUFFIX))
+        .filter(|base| map.get(*base).is_some_and(Value::is_string))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    for base in bases {
+        let plural = map.remove(&format!("{}{}", base, LEGACY_PLURAL_SUFFIX));
+        let (Some(singular), Some(plural)) = (map.remove(&base), plural) else {
+            continue;
+        };
+        if !plural.is_string() {
+            // not a plural value, put the keys back as they were.
+            map.insert(format!("{}{}", base, LEGACY_PLURAL_SUFFIX), plural);
+            map.insert(base, singular);
+            continue;
+        }
+        let plurals = vec![
+            Value::Array(vec![singular, Value::from("one")]),
+            Value::Array(vec![plural, Value::from("other")]),
+        ];
+        map.insert(base, Value::Array(plurals));
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Convert the interpolations and the nesting of a string value.
+fn convert_string(s: &str) -> Result<String, String> {
+    let s = s.replace("{{-", "{{");
+    let mut converted = String::with_capacity(s.len());
+    let mut rest = s.as_str();
+    while let Some((before, after)) = rest.split_once("$t(") {
+        converted.push_str(before);
+        let (nested, after) = after
+            .split_once(')')
+            .ok_or_else(|| format!("unclosed nesting in {:?}", s))?;
+        if nested.contains(',') {
+            return Err(format!(
+                "the options of the nesting $t({}) are not supported",
+                nested
+            ));
+        }
+        let key = nested.trim();
+        let key = match key.split_once(':') {
+            Some((namespace, key)) => format!("{}::{}", namespace.trim(), key.trim()),
+            None => key.to_string(),
+        };
+        converted.push_str("{{ @");
+        converted.push_str(&key);
+        converted.push_str(" }}");
+        rest = after;
+    }
+    converted.push_str(rest);
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn interpolations_and_nesting() {
+        let value = convert(json!({
+            "greeting": "Hello {{name}}, you owe {{- amount, number }}",
+            "nested": "$t(greeting) and $t(common:buttons.ok)",
+            "list": ["$t(greeting)", "plain"]
+        }))
+        .unwrap();
+        assert_eq!(
+            value["greeting"],
+            json!("Hello {{name}}, you owe {{ amount, number }}")
+        );
+        assert_eq!(
+            value["nested"],
+            json!("{{ @greeting }} and {{ @common::buttons.ok }}")
+        );
+        assert_eq!(value["list"], json!(["{{ @greeting }}", "plain"]));
+        assert!(convert(json!({ "options": "$t(greeting, { \"count\": 1 })" })).is_err());
+    }
+
+    #[test]
+    fn plural_suffixes() {
+        let value = convert(json!({
+            "items_zero": "no items",
+            "items_one": "one item",
+            "items_other": "{{count}} items",
+            "menu": {
+                "files_few": "{{count}} soubory",
+                "files_one": "1 soubor",
+                "files_other": "{{count}} souborů"
+            },
+            "place_ordinal_one": "{{count}}st",
+            "place_ordinal_other": "{{count}}th",
+            "alone_one": "not a plural without other"
+        }))
+        .unwrap();
+        assert_eq!(
+            value["items"],
+            json!([
+                ["no items", 0],
+                ["one item", "one"],
+                ["{{count}} items", "other"]
+            ])
+        );
+        assert_eq!(
+            value["menu"]["files"],
+            json!([
+                ["1 soubor", "one"],
+                ["{{count}} soubory", "few"],
+                ["{{count}} souborů", "other"]
+            ])
+        );
+        assert_eq!(value["place_ordinal_one"], json!("{{count}}st"));
+        assert_eq!(value["alone_one"], json!("not a plural without other"));
+        assert!(value.get("items_one").is_none());
+
+        assert!(convert(json!({ "items": "items", "items_other": "{{count}} items" })).is_err());
+    }
+
+    #[test]
+    fn legacy_plurals() {
+        let value = convert(json!({
+            "item": "one item",
+            "item_plural": "{{count}} items",
+            "orphan_plural": "kept as is"
+        }))
+        .unwrap();
+        assert_eq!(
+            value["item"],
+            json!([["one item", "one"], ["{{count}} items", "other"]])
+        );
+        assert!(value.get("item_plural").is_none());
+        assert_eq!(value["orphan_plural"], json!("kept as is"));
+    }
+}
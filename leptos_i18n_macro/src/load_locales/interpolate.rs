@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 use super::{
     key::Key,
@@ -33,7 +33,7 @@ impl Interpolation {
         locales: &[Locale],
         default_match: &TokenStream,
     ) -> Self {
-        let ident = syn::Ident::new(&format!("{}_builder", key.name), Span::call_site());
+        let ident = format_ident!("{}_builder", key.ident);
 
         let locale_field = Key::new("__locale").unwrap();
 
@@ -42,11 +42,7 @@ impl Interpolation {
             .map(|kind| {
                 #[cfg(feature = "debug_interpolations")]
                 let real_name = kind.get_real_name();
-                let key = kind
-                    .as_key()
-                    .map(|key| key.name.as_str())
-                    .unwrap_or("var_count");
-                let name = format!("__{}", key);
+                let name = format!("__{}", kind.as_ident());
                 let generic = syn::Ident::new(&name, Span::call_site());
                 Field {
                     generic,
@@ -62,7 +58,9 @@ impl Interpolation {
         let builder_impl = Self::builder_impl(&ident, &locale_field, &fields);
         let into_view_impl =
             Self::into_view_impl(key, &ident, &locale_field, &fields, locales, default_match);
-        let new_impl = Self::new_impl(&ident, &locale_field, &fields);
+        let new_impl = Self::new_impl(key, &ident, &locale_field, &fields, locales, default_match);
+        let display_impl =
+            Self::display_impl(key, &ident, &locale_field, &fields, locales, default_match);
         let default_generics = fields
             .iter()
             .map(|_| quote!(builders::EmptyInterpolateValue));
@@ -75,6 +73,8 @@ impl Interpolation {
 
             #into_view_impl
 
+            #display_impl
+
             #builder_impl
         };
 
@@ -85,7 +85,14 @@ impl Interpolation {
         }
     }
 
-    fn new_impl(ident: &syn::Ident, locale_field: &Key, fields: &[Field]) -> TokenStream {
+    fn new_impl(
+        key: &Key,
+        ident: &syn::Ident,
+        locale_field: &Key,
+        fields: &[Field],
+        locales: &[Locale],
+        default_match: &TokenStream,
+    ) -> TokenStream {
         let generics = fields.iter().map(|_| quote!(EmptyInterpolateValue));
 
         let fields = fields.iter().map(|field| {
@@ -93,6 +100,8 @@ impl Interpolation {
             quote!(#field_key: EmptyInterpolateValue)
         });
 
+        let template_match_arms = Self::create_locale_template(key, locales, default_match);
+
         quote! {
             impl #ident<#(#generics,)*> {
                 pub const fn new(#locale_field: Locale) -> Self {
@@ -101,10 +110,31 @@ impl Interpolation {
                         #locale_field
                     }
                 }
+
+                pub const fn get_template(&self) -> Option<&'static [leptos_i18n::__private::TemplatePart]> {
+                    match self.#locale_field {
+                        #(
+                            #template_match_arms,
+                        )*
+                    }
+                }
             }
         }
     }
 
+    fn create_locale_template<'a>(
+        key: &'a Key,
+        locales: &'a [Locale],
+        default_match: &TokenStream,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        Self::create_locale_impl_with(key, locales, default_match, |value| {
+            match value.as_template() {
+                Some(template) => quote!(Some(#template)),
+                None => quote!(None),
+            }
+        })
+    }
+
     fn split_at<T>(slice: &[T], i: usize) -> (&[T], &T, &[T]) {
         let (left, rest) = slice.split_at(i);
         let (mid, right) = rest.split_first().unwrap();
@@ -190,14 +220,14 @@ impl Interpolation {
         }
     }
 
-    fn generate_generics<'a, F: 'a, T: Clone + 'a>(
+    fn generate_generics<'a, F, T: Clone + 'a>(
         left_fields: &'a [Field],
         field_generic: Option<T>,
         right_fields: &'a [Field],
         other_field_map_fn: F,
     ) -> impl Iterator<Item = T> + 'a + Clone
     where
-        F: FnMut(&'a Field) -> T + Copy,
+        F: FnMut(&'a Field) -> T + Copy + 'a,
     {
         left_fields
             .iter()
@@ -280,7 +310,11 @@ impl Interpolation {
         let missing_fields = fields
             .filter_map(|(set, field)| (!set).then_some(field))
             .map(|field| match field.kind {
-                InterpolateKey::Count(_) | InterpolateKey::Variable(_) => field.real_name.into(),
+                InterpolateKey::Count(_)
+                | InterpolateKey::Variable(_)
+                | InterpolateKey::TypedVariable(..)
+                | InterpolateKey::Formatted(..)
+                | InterpolateKey::Select(_) => field.real_name.into(),
                 InterpolateKey::Component(_) => format!("<{}>", field.real_name).into(),
             })
             .collect::<Vec<Cow<_>>>();
@@ -311,9 +345,20 @@ impl Interpolation {
             quote!(#generic)
         };
         let output_field_generic = field.kind.get_generic();
+        // variables keep their type to implement `Display` when they do.
+        let output_field_type = match field.kind {
+            InterpolateKey::Variable(_) => quote!(__T),
+            InterpolateKey::Count(_)
+            | InterpolateKey::TypedVariable(..)
+            | InterpolateKey::Formatted(..)
+            | InterpolateKey::Component(_)
+            | InterpolateKey::Select(_) => {
+                quote!(impl #output_field_generic)
+            }
+        };
         let output_generics = Self::generate_generics(
             left_fields,
-            Some(quote!(impl #output_field_generic)),
+            Some(output_field_type),
             right_fields,
             quoted_gen,
         );
@@ -360,6 +405,19 @@ impl Interpolation {
                     }
                 }
             }
+            InterpolateKey::TypedVariable(key, _)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key) => {
+                quote! {
+                    #[inline]
+                    pub fn #key<__T>(self, #key: __T) -> #ident<#(#output_generics,)*>
+                        where __T: #output_field_generic
+                    {
+                        #destructure
+                        #restructure
+                    }
+                }
+            }
             InterpolateKey::Count(plural_type) => {
                 quote! {
                     #[inline]
@@ -398,7 +456,12 @@ impl Interpolation {
 
             let compile_warning = match field.kind {
                 InterpolateKey::Count(_) => "variable `count` is already set".to_string(),
-                InterpolateKey::Variable(_) => format!("variable `{}` is already set", field.name),
+                InterpolateKey::Variable(_)
+                | InterpolateKey::TypedVariable(..)
+                | InterpolateKey::Formatted(..)
+                | InterpolateKey::Select(_) => {
+                    format!("variable `{}` is already set", field.name)
+                }
                 InterpolateKey::Component(_) => {
                     format!("component `{}` is already set", field.name)
                 }
@@ -471,11 +534,93 @@ impl Interpolation {
         }
     }
 
+    fn display_impl(
+        key: &Key,
+        ident: &syn::Ident,
+        locale_field: &Key,
+        fields: &[Field],
+        locales: &[Locale],
+        default_match: &TokenStream,
+    ) -> TokenStream {
+        let left_generics = fields.iter().map(|field| {
+            let ident = &field.generic;
+            let generic = field.kind.get_generic();
+            match field.kind {
+                InterpolateKey::Variable(_) => quote!(#ident: #generic + core::fmt::Display),
+                InterpolateKey::Count(_)
+                | InterpolateKey::TypedVariable(..)
+                | InterpolateKey::Formatted(..)
+                | InterpolateKey::Select(_) => quote!(#ident: #generic),
+                // components are written as their children, so they don't need to be supplied.
+                InterpolateKey::Component(_) => quote!(#ident),
+            }
+        });
+
+        let right_generics = fields.iter().map(|field| {
+            let ident = &field.generic;
+            quote!(#ident)
+        });
+
+        let left_generics_string = left_generics.clone();
+        let right_generics_string = right_generics.clone();
+
+        let fields_key = fields.iter().map(|f| f.kind);
+
+        let destructure = quote!(let Self { #(#fields_key,)* #locale_field } = self;);
+
+        let count_fn = fields
+            .iter()
+            .any(|field| matches!(field.kind, InterpolateKey::Count(_)));
+
+        let locales_impls =
+            Self::create_locale_impl_with(key, locales, default_match, move |value| {
+                let statements = value.to_display_tokens(count_fn);
+                quote!({ #statements })
+            });
+
+        quote! {
+            #[allow(non_camel_case_types)]
+            impl<#(#left_generics,)*> core::fmt::Display for #ident<#(#right_generics,)*> {
+                #[allow(unused_variables)]
+                fn fmt(&self, __formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #destructure
+                    match *#locale_field {
+                        #(
+                            #locales_impls,
+                        )*
+                    }
+                    Ok(())
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            impl<#(#left_generics_string,)*> #ident<#(#right_generics_string,)*> {
+                /// Render the value to a string, see `t_string!`.
+                #[inline]
+                pub fn build_string(self) -> std::borrow::Cow<'static, str> {
+                    std::borrow::Cow::Owned(std::string::ToString::to_string(&self))
+                }
+            }
+        }
+    }
+
     fn create_locale_impl<'a>(
         key: &'a Key,
         locales: &'a [Locale],
         default_match: &TokenStream,
     ) -> impl Iterator<Item = TokenStream> + 'a {
+        Self::create_locale_impl_with(key, locales, default_match, |value| quote!({ #value }))
+    }
+
+    fn create_locale_impl_with<'a, F>(
+        key: &'a Key,
+        locales: &'a [Locale],
+        default_match: &TokenStream,
+        to_tokens: F,
+    ) -> impl Iterator<Item = TokenStream> + 'a
+    where
+        F: Fn(&ParsedValue) -> TokenStream + 'a,
+    {
         let mut default_match = default_match.clone();
         locales
             .iter()
@@ -492,9 +637,11 @@ impl Interpolation {
                     Some(value) => value,
                 };
 
+                let value = to_tokens(value);
+
                 let ts = match i == 0 {
-                    true => quote!(#default_match => { #value }),
-                    false => quote!(Locale::#locale_key => { #value }),
+                    true => quote!(#default_match => #value),
+                    false => quote!(Locale::#locale_key => #value),
                 };
                 Some(ts)
             })
@@ -34,14 +34,41 @@ impl Eq for Key {}
 impl Key {
     pub fn new(name: &str) -> Option<Self> {
         let name = name.trim();
-        let ident_repr = name.replace('-', "_");
-        let ident = syn::parse_str::<syn::Ident>(&ident_repr).ok()?;
+        let ident = Self::make_ident(name)?;
         Some(Key {
             name: name.to_string(),
             ident,
         })
     }
 
+    /// Create the identifier of a key, escaping what can't be used as a rust identifier:
+    /// the characters other than letters, digits and `_` are replaced by `_`, a `_` is prepended to a leading digit,
+    /// keywords become raw identifiers (`r#type`), and `self`, `Self`, `super` and `crate` get a trailing `_`.
+    ///
+    /// Return `None` if the name does not contain any letter or digit.
+    fn make_ident(name: &str) -> Option<syn::Ident> {
+        let mut ident_repr = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        if !ident_repr.chars().any(char::is_alphanumeric) {
+            return None;
+        }
+        if ident_repr.starts_with(|c: char| c.is_numeric()) {
+            ident_repr.insert(0, '_');
+        }
+        if let Ok(ident) = syn::parse_str::<syn::Ident>(&ident_repr) {
+            return Some(ident);
+        }
+        match ident_repr.as_str() {
+            "self" | "Self" | "super" | "crate" => {
+                ident_repr.push('_');
+                syn::parse_str::<syn::Ident>(&ident_repr).ok()
+            }
+            _ => syn::parse_str::<syn::Ident>(&format!("r#{}", ident_repr)).ok(),
+        }
+    }
+
     pub fn try_new(name: &str) -> Result<Self> {
         Self::new(name).ok_or_else(|| Error::InvalidKey(name.to_string()))
     }
@@ -76,9 +103,8 @@ impl KeyPath {
     }
 }
 
-impl Display for KeyPath {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("\"")?;
+impl KeyPath {
+    fn fmt_path(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(namespace) = &self.namespace {
             write!(f, "{}::", namespace.name)?;
         }
@@ -89,6 +115,27 @@ impl Display for KeyPath {
                 write!(f, ".{}", key.name)?;
             }
         }
+        Ok(())
+    }
+
+    /// Return the path as it would be written in a foreign key, without the surrounding quotes.
+    pub fn to_path_string(&self) -> String {
+        struct Unquoted<'a>(&'a KeyPath);
+
+        impl Display for Unquoted<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt_path(f)
+            }
+        }
+
+        Unquoted(self).to_string()
+    }
+}
+
+impl Display for KeyPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("\"")?;
+        self.fmt_path(f)?;
         f.write_str("\"")
     }
 }
@@ -121,3 +168,26 @@ impl<'de> serde::de::Visitor<'de> for KeyVisitor {
         Key::try_new(v).map_err(E::custom)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ident_of(name: &str) -> Option<String> {
+        Key::new(name).map(|key| key.ident.to_string())
+    }
+
+    #[test]
+    fn escape_invalid_identifiers() {
+        assert_eq!(ident_of("click_count").as_deref(), Some("click_count"));
+        assert_eq!(ident_of("click-count").as_deref(), Some("click_count"));
+        assert_eq!(ident_of("type").as_deref(), Some("r#type"));
+        assert_eq!(ident_of("match").as_deref(), Some("r#match"));
+        assert_eq!(ident_of("self").as_deref(), Some("self_"));
+        assert_eq!(ident_of("404").as_deref(), Some("_404"));
+        assert_eq!(ident_of("clé").as_deref(), Some("clé"));
+        assert_eq!(ident_of("page title").as_deref(), Some("page_title"));
+        assert_eq!(ident_of("?!"), None);
+        assert_eq!(ident_of(""), None);
+    }
+}
@@ -0,0 +1,257 @@
+use std::{collections::HashMap, fmt::Write, path::Path, rc::Rc};
+
+use super::{
+    cfg_file::ConfigFile,
+    dynamic::{json_string, write_if_changed},
+    error::{Error, Result},
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    metadata::KeyMetadata,
+    parsed_value::ParsedValue,
+    plural_rules::PluralRules,
+    snapshot::{interpolation_names, source_text},
+};
+
+/// The keys of the default locale, described for the translation platforms with `load_locales!(export = "path")`.
+pub struct KeyManifest {
+    default: Rc<Key>,
+    locales: Vec<Rc<Key>>,
+    keys: Vec<ManifestKey>,
+}
+
+struct ManifestKey {
+    path: String,
+    kind: &'static str,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl KeyManifest {
+    pub fn new(cfg_file: &ConfigFile, locales: &LocalesOrNamespaces) -> Self {
+        let mut keys = vec![];
+        match locales {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    if let Some(default_locale) = namespace.locales.first() {
+                        let prefix = format!("{}::", namespace.key.name);
+                        collect_keys(default_locale, &prefix, &mut keys);
+                    }
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => {
+                if let Some(default_locale) = locales.first() {
+                    collect_keys(default_locale, "", &mut keys);
+                }
+            }
+        }
+        keys.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        KeyManifest {
+            default: Rc::clone(&cfg_file.default),
+            locales: cfg_file.locales.clone(),
+            keys,
+        }
+    }
+
+    /// Write the manifest as JSON to the path, relative to the directory of the cargo manifest,
+    /// with the keys missing in each locale.
+    pub fn write(
+        &self,
+        manifest_dir: &Path,
+        path: &str,
+        missing_keys: &HashMap<Rc<Key>, Vec<String>>,
+    ) -> Result<()> {
+        let path = manifest_dir.join(path);
+        write_if_changed(&path, &self.to_json(missing_keys))
+            .map_err(|err| Error::KeyManifestWrite { path, err })
+    }
+
+    fn to_json(&self, missing_keys: &HashMap<Rc<Key>, Vec<String>>) -> String {
+        let mut json = String::from("{\n");
+        let _ = writeln!(json, "  \"default\": {},", json_string(&self.default.name));
+        json.push_str("  \"locales\": [");
+        for (i, locale) in self.locales.iter().enumerate() {
+            let categories = PluralRules::for_locale(&locale.name)
+                .map(|rules| {
+                    rules
+                        .categories()
+                        .map(|category| category.to_string())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let missing = missing_keys
+                .get(locale)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            json.push_str(if i == 0 { "\n" } else { ",\n" });
+            let _ = write!(
+                json,
+                "    {{ \"locale\": {}, \"plural_categories\": {}, \"missing_keys\": {} }}",
+                json_string(&locale.name),
+                json_array(&categories),
+                json_array(missing)
+            );
+        }
+        json.push_str("\n  ],\n  \"keys\": [");
+        for (i, key) in self.keys.iter().enumerate() {
+            json.push_str(if i == 0 { "\n" } else { ",\n" });
+            let _ = write!(
+                json,
+                "    {{\n      \"path\": {},\n      \"kind\": {}",
+                json_string(&key.path),
+                json_string(key.kind)
+            );
+            for (name, value) in &key.fields {
+                let _ = write!(json, ",\n      \"{}\": {}", name, value);
+            }
+            json.push_str("\n    }");
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+fn collect_keys(locale: &Locale, prefix: &str, keys: &mut Vec<ManifestKey>) {
+    for (key, value) in &locale.keys {
+        let path = format!("{}{}", prefix, key.name);
+        if let ParsedValue::Subkeys(subkeys) = value {
+            collect_keys(subkeys, &format!("{}.", path), keys);
+            continue;
+        }
+        let mut fields = vec![];
+        let kind = value_fields(value, &mut fields);
+        if let Some(metadata) = locale.metadata.get(key) {
+            metadata_fields(metadata, &mut fields);
+        }
+        keys.push(ManifestKey { path, kind, fields });
+    }
+}
+
+/// Push the fields describing the value and return its kind.
+fn value_fields(value: &ParsedValue, fields: &mut Vec<(&'static str, String)>) -> &'static str {
+    let kind = match value {
+        ParsedValue::Features { default, .. } => return value_fields(default, fields),
+        ParsedValue::List(items) => {
+            fields.push(("items", json_array(items)));
+            return "list";
+        }
+        ParsedValue::ObjectList(list) => {
+            let items = list.items.iter().map(|item| {
+                let item_fields = list.fields.iter().map(|field| {
+                    let value = item.get(field).unwrap_or_default();
+                    format!("{}: {}", json_string(&field.name), json_string(value))
+                });
+                format!("{{ {} }}", item_fields.collect::<Vec<_>>().join(", "))
+            });
+            fields.push((
+                "items",
+                format!("[{}]", items.collect::<Vec<_>>().join(", ")),
+            ));
+            return "object_list";
+        }
+        ParsedValue::Plural(plurals) => {
+            let branches = plurals.branches().into_iter().map(|(count, value)| {
+                format!(
+                    "{{ \"count\": {}, \"value\": {} }}",
+                    json_string(&count),
+                    json_string(&source_text(value))
+                )
+            });
+            fields.push((
+                "plurals",
+                format!("[{}]", branches.collect::<Vec<_>>().join(", ")),
+            ));
+            "plural"
+        }
+        ParsedValue::Select { .. } => "select",
+        value => match value.get_keys() {
+            Some(_) => "interpolation",
+            None => "string",
+        },
+    };
+    if kind != "plural" {
+        fields.push(("source", json_string(&source_text(value))));
+    }
+    if let Some(interpolations) = value.get_keys() {
+        let (variables, components) = interpolation_names(&interpolations);
+        if !variables.is_empty() {
+            fields.push(("variables", json_array(&variables)));
+        }
+        if !components.is_empty() {
+            fields.push(("components", json_array(&components)));
+        }
+    }
+    kind
+}
+
+fn metadata_fields(metadata: &KeyMetadata, fields: &mut Vec<(&'static str, String)>) {
+    for (name, value) in [
+        ("description", &metadata.description),
+        ("context", &metadata.context),
+        ("screenshot", &metadata.screenshot),
+    ] {
+        if let Some(value) = value {
+            fields.push((name, json_string(value)));
+        }
+    }
+    if !metadata.tags.is_empty() {
+        fields.push(("tags", json_array(&metadata.tags)));
+    }
+    if let Some(deprecated) = &metadata.deprecated {
+        let note = deprecated
+            .note
+            .as_deref()
+            .map_or("null".to_string(), json_string);
+        let replacement = deprecated
+            .replacement
+            .as_deref()
+            .map_or("null".to_string(), json_string);
+        fields.push((
+            "deprecated",
+            format!("{{ \"note\": {}, \"replacement\": {} }}", note, replacement),
+        ));
+    }
+}
+
+fn json_array<T: AsRef<str>>(items: &[T]) -> String {
+    let items = items.iter().map(|item| json_string(item.as_ref()));
+    format!("[{}]", items.collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_locales::key::KeyPath;
+
+    #[test]
+    fn value_kinds() {
+        let locale = Rc::new(Key::new("en").unwrap());
+        let value = ParsedValue::new(
+            "Hello {{ name }}, <b>welcome</b>",
+            &KeyPath::new(None),
+            &locale,
+        )
+        .unwrap();
+        let mut fields = vec![];
+        assert_eq!(value_fields(&value, &mut fields), "interpolation");
+        assert_eq!(
+            fields,
+            [
+                (
+                    "source",
+                    r#""Hello {{ name }}, <b>welcome</b>""#.to_string()
+                ),
+                ("variables", r#"["name"]"#.to_string()),
+                ("components", r#"["b"]"#.to_string()),
+            ]
+        );
+
+        let mut fields = vec![];
+        let value = ParsedValue::List(vec!["a".to_string(), "b\"".to_string()]);
+        assert_eq!(value_fields(&value, &mut fields), "list");
+        assert_eq!(fields, [("items", r#"["a", "b\""]"#.to_string())]);
+
+        let mut fields = vec![];
+        let value = ParsedValue::String("plain".to_string());
+        assert_eq!(value_fields(&value, &mut fields), "string");
+        assert_eq!(fields, [("source", r#""plain""#.to_string())]);
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use proc_macro2::{TokenStream, TokenTree};
+
+use super::{
+    key::{Key, KeyPath},
+    locale::{BuildersKeys, BuildersKeysInner, LocaleValue},
+    warning::{emit_warning, Warning},
+};
+use crate::t_macro::parsed_input::Keys;
+
+/// The macros taking a key, with the number of arguments before it.
+const KEY_MACROS: &[(&str, usize)] = &[
+    ("t", 1),
+    ("td", 1),
+    ("t_string", 1),
+    ("td_string", 1),
+    ("t_title", 1),
+    ("t_asset", 1),
+    ("t_meta", 2),
+];
+
+/// The macros scoping a context to a path, with the number of arguments before it.
+const SCOPE_MACROS: &[(&str, usize)] = &[("scope_i18n", 1), ("use_i18n_scoped", 0)];
+
+/// The paths given to the macros of a file.
+#[derive(Default)]
+struct Usages {
+    keys: Vec<Vec<String>>,
+    scopes: Vec<Vec<String>>,
+}
+
+/// Scan the `.rs` files of the directories, relative to the manifest, for the keys used with the `t!` macros,
+/// and emit a warning for each key never used and each key used that does not exist.
+///
+/// A key is used if it is given to a macro or if one of its parent subkeys is.
+/// The keys given to the macros are also looked up relative to the scopes created in the same file,
+/// as the context they are used with is not known.
+/// The keys of the namespaces loaded by another invocation of `load_locales!()`, the ones in `ignored_namespaces`, are not checked.
+pub fn check_key_usage(
+    manifest_dir: &Path,
+    dirs: &[String],
+    keys: &BuildersKeys,
+    ignored_namespaces: &[&Rc<Key>],
+) {
+    let mut defined = defined_keys(keys);
+    let mut files = vec![];
+    for dir in dirs {
+        collect_files(&manifest_dir.join(dir), &mut files);
+    }
+    files.sort();
+
+    for file in files {
+        let Ok(contents) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        let Ok(tokens) = contents.parse::<TokenStream>() else {
+            continue;
+        };
+        let mut usages = Usages::default();
+        find_usages(tokens, &mut usages);
+        let file = file.strip_prefix(manifest_dir).unwrap_or(&file);
+        for usage in &usages.keys {
+            let scoped = usages
+                .scopes
+                .iter()
+                .map(|scope| [&scope[..], &usage[..]].concat());
+            let mut found = false;
+            for path in std::iter::once(usage.clone()).chain(scoped) {
+                let ignored = ignored_namespaces
+                    .iter()
+                    .any(|namespace| path.first() == Some(&namespace.ident.to_string()));
+                found |= ignored || mark_used(&mut defined, &path);
+            }
+            if !found {
+                emit_warning(Warning::UnknownKeyUsage {
+                    key: usage.join("."),
+                    file: file.display().to_string(),
+                });
+            }
+        }
+    }
+
+    let mut unused = defined
+        .into_values()
+        .filter_map(|(key_path, used)| (!used).then_some(key_path))
+        .collect::<Vec<_>>();
+    unused.sort_by_cached_key(KeyPath::to_path_string);
+    for key_path in unused {
+        emit_warning(Warning::UnusedKey { key_path });
+    }
+}
+
+/// Map the identifiers of every key, the namespace being the first one, to its path and whether it is used.
+type DefinedKeys = HashMap<Vec<String>, (KeyPath, bool)>;
+
+fn defined_keys(keys: &BuildersKeys) -> DefinedKeys {
+    let mut defined = DefinedKeys::new();
+    match keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            for namespace in *namespaces {
+                let Some(keys) = keys.get(&namespace.key) else {
+                    continue;
+                };
+                let mut key_path = KeyPath::new(Some(Rc::clone(&namespace.key)));
+                let mut idents = vec![namespace.key.ident.to_string()];
+                add_keys(&mut defined, keys, &mut key_path, &mut idents);
+            }
+        }
+        BuildersKeys::Locales { keys, .. } => {
+            add_keys(&mut defined, keys, &mut KeyPath::new(None), &mut vec![]);
+        }
+    }
+    defined
+}
+
+fn add_keys(
+    defined: &mut DefinedKeys,
+    keys: &BuildersKeysInner,
+    key_path: &mut KeyPath,
+    idents: &mut Vec<String>,
+) {
+    for (key, value) in &keys.0 {
+        key_path.push_key(Rc::clone(key));
+        idents.push(key.ident.to_string());
+        match value {
+            LocaleValue::Subkeys { keys, .. } => add_keys(defined, keys, key_path, idents),
+            _ => {
+                defined.insert(idents.clone(), (key_path.clone(), false));
+            }
+        }
+        idents.pop();
+        key_path.pop_key();
+    }
+}
+
+/// Mark the key and the keys under it as used, returning `false` if there is none.
+fn mark_used(defined: &mut DefinedKeys, usage: &[String]) -> bool {
+    let mut found = false;
+    for (idents, (_, used)) in defined.iter_mut() {
+        if idents.starts_with(usage) {
+            *used = true;
+            found = true;
+        }
+    }
+    found
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Push the path given to every invocation of the `t!` and scope macros in the tokens, the macros nested in others included.
+fn find_usages(tokens: TokenStream, usages: &mut Usages) {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut macro_name: Option<String> = None;
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) => macro_name = Some(ident.to_string()),
+            TokenTree::Punct(punct) if punct.as_char() == '!' => {
+                if let (Some(name), Some(TokenTree::Group(group))) =
+                    (macro_name.take(), tokens.peek())
+                {
+                    if let Some(key) = macro_path(KEY_MACROS, &name, group.stream()) {
+                        usages.keys.push(key);
+                    } else if let Some(scope) = macro_path(SCOPE_MACROS, &name, group.stream()) {
+                        usages.scopes.push(scope);
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                macro_name = None;
+                find_usages(group.stream(), usages);
+            }
+            _ => macro_name = None,
+        }
+    }
+}
+
+/// Return the path given to the macro, if it is one of the given macros.
+fn macro_path(macros: &[(&str, usize)], name: &str, args: TokenStream) -> Option<Vec<String>> {
+    let &(_, skipped) = macros.iter().find(|(macro_name, _)| *macro_name == name)?;
+    let mut commas = 0;
+    let key = args
+        .into_iter()
+        .filter(|token| {
+            if matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',') {
+                commas += 1;
+                false
+            } else {
+                commas == skipped
+            }
+        })
+        .collect::<TokenStream>();
+    let path = match syn::parse2::<Keys>(key).ok()? {
+        Keys::SingleKey(key) => vec![key],
+        Keys::Subkeys(keys) => keys,
+        Keys::Namespace(namespace, keys) => std::iter::once(namespace).chain(keys).collect(),
+    };
+    Some(path.iter().map(ToString::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usages(src: &str) -> (Vec<String>, Vec<String>) {
+        let mut usages = Usages::default();
+        find_usages(src.parse().unwrap(), &mut usages);
+        let join = |paths: Vec<Vec<String>>| paths.into_iter().map(|path| path.join(".")).collect();
+        (join(usages.keys), join(usages.scopes))
+    }
+
+    #[test]
+    fn find_macro_usages() {
+        let src = r#"
+            fn view() -> impl IntoView {
+                let i18n = use_i18n();
+                // t!(i18n, commented)
+                let title = t_string!(i18n, home.title);
+                let settings = scope_i18n!(i18n, admin::settings);
+                let users = use_i18n_scoped!(admin::users);
+                view! {
+                    <p>{t!(i18n, click_count, count = move || 0)}</p>
+                    <p>{leptos_i18n::td!(Locale::en, admin::users.count, count = 1)}</p>
+                    {t_meta!(i18n, name = "description", meta.description)}
+                    {format!("{}", t)}
+                }
+            }
+        "#;
+        let (keys, scopes) = usages(src);
+        assert_eq!(
+            keys,
+            [
+                "home.title",
+                "click_count",
+                "admin.users.count",
+                "meta.description"
+            ]
+        );
+        assert_eq!(scopes, ["admin.settings", "admin.users"]);
+    }
+
+    #[test]
+    fn mark_used_keys() {
+        let key = |name: &str| Rc::new(Key::new(name).unwrap());
+        let mut defined = DefinedKeys::new();
+        for path in [&["home", "title"][..], &["home", "subtitle"], &["unused"]] {
+            let mut key_path = KeyPath::new(None);
+            for name in path {
+                key_path.push_key(key(name));
+            }
+            let idents = path.iter().map(ToString::to_string).collect();
+            defined.insert(idents, (key_path, false));
+        }
+        let usage = |path: &[&str]| path.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert!(mark_used(&mut defined, &usage(&["home"])));
+        assert!(!mark_used(&mut defined, &usage(&["home", "missing"])));
+        let unused = defined
+            .values()
+            .filter(|(_, used)| !used)
+            .map(|(key_path, _)| key_path.to_path_string())
+            .collect::<Vec<_>>();
+        assert_eq!(unused, ["unused"]);
+    }
+}
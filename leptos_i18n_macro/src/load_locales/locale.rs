@@ -1,4 +1,5 @@
 use std::{
+    cell::RefCell,
     collections::{HashMap, HashSet},
     fs::File,
     path::{Path, PathBuf},
@@ -6,19 +7,80 @@ use std::{
 };
 
 use super::{
-    cfg_file::ConfigFile,
+    cfg_file::{ConfigFile, DottedKeys, LocalePackage, MissingKeyPolicy, ValueOptions},
     error::{Error, Result},
     key::{Key, KeyPath},
-    parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+    metadata::{KeyMetadata, VariableDeclaration},
+    parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed, FOREIGN_KEYS, FOREIGN_KEYS_ROOT},
     warning::{emit_warning, Warning},
 };
 
 #[cfg(feature = "yaml_files")]
-const FILE_FORMAT: &str = "yaml";
+pub const FILE_FORMAT: &str = "yaml";
 #[cfg(feature = "json_files")]
-const FILE_FORMAT: &str = "json";
-#[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
-const FILE_FORMAT: &str = "not specified";
+pub const FILE_FORMAT: &str = "json";
+#[cfg(feature = "toml_files")]
+pub const FILE_FORMAT: &str = "toml";
+#[cfg(feature = "fluent_files")]
+pub const FILE_FORMAT: &str = "ftl";
+#[cfg(feature = "po_files")]
+pub const FILE_FORMAT: &str = "po";
+#[cfg(not(any(
+    feature = "json_files",
+    feature = "yaml_files",
+    feature = "toml_files",
+    feature = "fluent_files",
+    feature = "po_files"
+)))]
+pub const FILE_FORMAT: &str = "not specified";
+
+thread_local! {
+    pub static MISSING_KEYS: RefCell<Vec<(Rc<Key>, KeyPath)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Register the foreign keys in the values copied from one locale to another, so they are resolved in the new locale too.
+fn register_copied_foreign_keys(from: &Rc<Key>, to: &Rc<Key>, copied_paths: &[KeyPath]) {
+    FOREIGN_KEYS.with(|foreign_keys| {
+        let mut foreign_keys = foreign_keys.borrow_mut();
+        let to_register = foreign_keys
+            .iter()
+            .filter(|(locale, value_path)| {
+                locale == from
+                    && copied_paths.iter().any(|copied_path| {
+                        copied_path.namespace == value_path.namespace
+                            && value_path.path.starts_with(&copied_path.path)
+                    })
+            })
+            .map(|(_, value_path)| (Rc::clone(to), value_path.clone()))
+            .collect::<Vec<_>>();
+        foreign_keys.extend(to_register);
+    });
+}
+
+fn register_missing_key(locale: Rc<Key>, key_path: KeyPath) {
+    MISSING_KEYS.with(|missing_keys| missing_keys.borrow_mut().push((locale, key_path)));
+}
+
+/// Forget the missing keys left by a previous expansion that returned early with an error.
+pub fn clear_missing_keys() {
+    MISSING_KEYS.with(|missing_keys| missing_keys.borrow_mut().clear());
+}
+
+/// Return the missing keys registered while merging the locales, grouped by locale and sorted.
+pub fn take_missing_keys() -> HashMap<Rc<Key>, Vec<String>> {
+    let missing_keys = MISSING_KEYS.with(|missing_keys| missing_keys.take());
+    let mut grouped: HashMap<Rc<Key>, Vec<String>> = HashMap::new();
+    for (locale, key_path) in missing_keys {
+        grouped
+            .entry(locale)
+            .or_default()
+            .push(key_path.to_path_string());
+    }
+    for key_paths in grouped.values_mut() {
+        key_paths.sort_unstable();
+    }
+    grouped
+}
 
 #[derive(Debug)]
 pub struct Namespace {
@@ -51,9 +113,15 @@ impl Namespace {
         locales_dir_path: &mut PathBuf,
         key: Rc<Key>,
         locale_keys: &[Rc<Key>],
+        value_options: ValueOptions,
+        pseudo_locale: Option<&Rc<Key>>,
     ) -> Result<Self> {
         let mut locales = Vec::with_capacity(locale_keys.len());
         for locale in locale_keys.iter().cloned() {
+            if Some(&locale) == pseudo_locale {
+                locales.push(Locale::empty(Rc::clone(&locale), locale));
+                continue;
+            }
             let file_path: &Path = key.name.as_ref();
             locales_dir_path.push(&locale.name);
             locales_dir_path.push(file_path);
@@ -62,6 +130,7 @@ impl Namespace {
                 locales_dir_path,
                 locale,
                 Some(Rc::clone(&key)),
+                value_options,
             )?);
             locales_dir_path.pop();
             locales_dir_path.pop();
@@ -71,6 +140,96 @@ impl Namespace {
 }
 
 impl LocalesOrNamespaces {
+    /// Fill the keys missing in a locale with the values of their fallback locale, if one is declared for them.
+    pub fn apply_fallbacks(&mut self) -> Result<()> {
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    Locale::apply_fallbacks(&mut namespace.locales, Some(&namespace.key))?;
+                }
+                Ok(())
+            }
+            LocalesOrNamespaces::Locales(locales) => Locale::apply_fallbacks(locales, None),
+        }
+    }
+
+    /// Fill the keys missing in the locales extending another one with the values of their parent.
+    pub fn apply_inheritance(&mut self, inherits: &HashMap<Rc<Key>, Rc<Key>>) {
+        if inherits.is_empty() {
+            return;
+        }
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    Locale::apply_inheritance(
+                        &mut namespace.locales,
+                        inherits,
+                        Some(&namespace.key),
+                    );
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => {
+                Locale::apply_inheritance(locales, inherits, None)
+            }
+        }
+    }
+
+    /// Check that the replacements of the deprecated keys of the default locale exist.
+    pub fn check_deprecations(&self) -> Result<()> {
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    if let Some(default_locale) = namespace.locales.first() {
+                        let mut key_path = KeyPath::new(Some(Rc::clone(&namespace.key)));
+                        default_locale.check_deprecations(self, &mut key_path)?;
+                    }
+                }
+                Ok(())
+            }
+            LocalesOrNamespaces::Locales(locales) => match locales.first() {
+                Some(default_locale) => {
+                    default_locale.check_deprecations(self, &mut KeyPath::new(None))
+                }
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Check that the keys only use the variables declared in the `_variables` section of the default locale, if any.
+    pub fn check_variables(&self) -> Result<()> {
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    Locale::check_variables(&namespace.locales, Some(&namespace.key))?;
+                }
+                Ok(())
+            }
+            LocalesOrNamespaces::Locales(locales) => Locale::check_variables(locales, None),
+        }
+    }
+
+    /// Return the path of every key of the default locale (`key.subkey`, or `namespace::key.subkey`), sorted.
+    pub fn key_paths(&self) -> Vec<String> {
+        let mut paths = vec![];
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    if let Some(default_locale) = namespace.locales.first() {
+                        let prefix = format!("{}::", namespace.key.name);
+                        default_locale.push_key_paths(&prefix, &mut paths);
+                    }
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => {
+                if let Some(default_locale) = locales.first() {
+                    default_locale.push_key_paths("", &mut paths);
+                }
+            }
+        }
+        paths.sort_unstable();
+        paths
+    }
+
     pub fn get_value_at(&self, top_locale: &Rc<Key>, path: &KeyPath) -> Option<&'_ ParsedValue> {
         let locale = match (&path.namespace, self) {
             (None, LocalesOrNamespaces::NameSpaces(_))
@@ -93,25 +252,63 @@ impl LocalesOrNamespaces {
 
     pub fn new(manifest_dir_path: &mut PathBuf, cfg_file: &ConfigFile) -> Result<Self> {
         let locale_keys = &cfg_file.locales;
+        let packages = cfg_file
+            .locale_packages
+            .iter()
+            .map(|package| {
+                let locales = Locale::load_package(manifest_dir_path, cfg_file, package)?;
+                Ok((package, locales))
+            })
+            .collect::<Result<Vec<_>>>()?;
         manifest_dir_path.push(&*cfg_file.locales_dir);
         if let Some(namespace_keys) = &cfg_file.name_spaces {
-            let mut namespaces = Vec::with_capacity(namespace_keys.len());
+            let mut namespaces = Vec::with_capacity(namespace_keys.len() + packages.len());
             for namespace in namespace_keys {
                 namespaces.push(Namespace::new(
                     manifest_dir_path,
                     Rc::clone(namespace),
                     locale_keys,
+                    cfg_file.value_options,
+                    cfg_file.pseudo_locale.as_ref(),
                 )?);
             }
+            // the names of the packages and of the namespaces are checked with the config.
+            namespaces.extend(packages.into_iter().map(|(package, locales)| Namespace {
+                key: Rc::clone(&package.prefix),
+                locales,
+            }));
             Ok(LocalesOrNamespaces::NameSpaces(namespaces))
         } else {
             let mut locales = Vec::with_capacity(locale_keys.len());
             for locale in locale_keys.iter().cloned() {
+                if Some(&locale) == cfg_file.pseudo_locale.as_ref() {
+                    locales.push(Locale::empty(Rc::clone(&locale), locale));
+                    continue;
+                }
                 manifest_dir_path.push(&locale.name);
                 manifest_dir_path.set_extension(FILE_FORMAT);
-                locales.push(Locale::new(manifest_dir_path, locale, None)?);
+                locales.push(Locale::new(
+                    manifest_dir_path,
+                    locale,
+                    None,
+                    cfg_file.value_options,
+                )?);
                 manifest_dir_path.pop();
             }
+            for (package, package_locales) in packages {
+                for (locale, package_locale) in locales.iter_mut().zip(package_locales) {
+                    if locale.keys.contains_key(&package.prefix) {
+                        return Err(Error::LocalePackageKeyConflict {
+                            package: Rc::clone(&package.prefix),
+                            locale: Rc::clone(&locale.name),
+                        });
+                    }
+                    locale.keys.insert(
+                        Rc::clone(&package.prefix),
+                        ParsedValue::Subkeys(package_locale),
+                    );
+                }
+            }
             Ok(LocalesOrNamespaces::Locales(locales))
         }
     }
@@ -122,9 +319,26 @@ pub struct Locale {
     pub top_locale_name: Rc<Key>,
     pub name: Rc<Key>,
     pub keys: HashMap<Rc<Key>, ParsedValue>,
+    pub metadata: HashMap<Rc<Key>, KeyMetadata>,
+    /// Locale to take the values from for the keys missing in this map, declared with `@@fallback`.
+    pub fallback: Option<Rc<Key>>,
+    /// Variables declared in the `_variables` section at the top of the file.
+    pub variables: Option<HashMap<String, VariableDeclaration>>,
 }
 
 impl Locale {
+    fn push_key_paths(&self, prefix: &str, paths: &mut Vec<String>) {
+        for (key, value) in &self.keys {
+            let path = format!("{}{}", prefix, key.name);
+            match value {
+                ParsedValue::Subkeys(subkeys) => {
+                    subkeys.push_key_paths(&format!("{}.", path), paths)
+                }
+                _ => paths.push(path),
+            }
+        }
+    }
+
     pub fn get_value_at(&self, path: &[Rc<Key>]) -> Option<&'_ ParsedValue> {
         match path {
             [] => None,
@@ -147,14 +361,67 @@ impl Locale {
 
     #[cfg(feature = "json_files")]
     fn de_inner(locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
+        if seed.value_options.i18next_compat {
+            let value = serde_json::from_reader(locale_file)?;
+            let value = super::i18next::convert(value).map_err(serde::de::Error::custom)?;
+            return serde::de::DeserializeSeed::deserialize(seed, value);
+        }
         let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
         serde::de::DeserializeSeed::deserialize(seed, &mut deserializer)
     }
 
-    #[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
+    #[cfg(feature = "toml_files")]
+    fn de_inner(mut locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
+        use std::io::Read;
+        // the TOML deserializer only works on a str.
+        let mut contents = String::new();
+        locale_file
+            .read_to_string(&mut contents)
+            .map_err(serde::de::Error::custom)?;
+        let deserializer = toml::Deserializer::new(&contents);
+        serde::de::DeserializeSeed::deserialize(seed, deserializer)
+    }
+
+    #[cfg(feature = "fluent_files")]
+    fn de_inner(mut locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
+        use std::io::Read;
+        let mut contents = String::new();
+        locale_file
+            .read_to_string(&mut contents)
+            .map_err(serde::de::Error::custom)?;
+        let namespace = seed
+            .key_path
+            .namespace
+            .as_ref()
+            .map(|key| key.name.as_str());
+        let value = super::fluent::parse(&contents, namespace).map_err(serde::de::Error::custom)?;
+        serde::de::DeserializeSeed::deserialize(seed, value)
+    }
+
+    #[cfg(feature = "po_files")]
+    fn de_inner(mut locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
+        use std::io::Read;
+        let mut contents = String::new();
+        locale_file
+            .read_to_string(&mut contents)
+            .map_err(serde::de::Error::custom)?;
+        let value = super::po::parse(&contents, &seed.top_locale_name.name)
+            .map_err(serde::de::Error::custom)?;
+        serde::de::DeserializeSeed::deserialize(seed, value)
+    }
+
+    #[cfg(not(any(
+        feature = "json_files",
+        feature = "yaml_files",
+        feature = "toml_files",
+        feature = "fluent_files",
+        feature = "po_files"
+    )))]
     fn de_inner(locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
         let _ = (locale_file, seed);
-        compile_error!("No file format has been provided, supported formats are: json and yaml")
+        compile_error!(
+            "No file format has been provided, supported formats are: json, yaml, toml, ftl and po"
+        )
     }
 
     fn de(locale_file: File, path: &mut PathBuf, seed: LocaleSeed) -> Result<Self> {
@@ -164,7 +431,12 @@ impl Locale {
         })
     }
 
-    pub fn new(path: &mut PathBuf, locale: Rc<Key>, namespace: Option<Rc<Key>>) -> Result<Self> {
+    pub fn new(
+        path: &mut PathBuf,
+        locale: Rc<Key>,
+        namespace: Option<Rc<Key>>,
+        value_options: ValueOptions,
+    ) -> Result<Self> {
         let locale_file = match File::open(&path) {
             Ok(file) => file,
             Err(err) => {
@@ -179,11 +451,343 @@ impl Locale {
             name: Rc::clone(&locale),
             top_locale_name: locale,
             key_path: KeyPath::new(namespace),
+            value_options,
         };
 
         Self::de(locale_file, path, seed)
     }
 
+    /// Read the files of a locale package, one per locale, as a namespace named by its prefix with namespaces,
+    /// else as the subkeys of its prefix.
+    ///
+    /// A package may not translate every locale of the application, the locales without a file have no keys,
+    /// except the default one which must have a file.
+    fn load_package(
+        manifest_dir_path: &Path,
+        cfg_file: &ConfigFile,
+        package: &LocalePackage,
+    ) -> Result<Vec<Self>> {
+        let mut path = manifest_dir_path.join(&package.dir);
+        let prefix = &package.prefix;
+        let (name, key_path) = if cfg_file.name_spaces.is_some() {
+            (None, KeyPath::new(Some(Rc::clone(prefix))))
+        } else {
+            let mut key_path = KeyPath::new(None);
+            key_path.push_key(Rc::clone(prefix));
+            (Some(prefix), key_path)
+        };
+        // the foreign keys of the package are relative to it.
+        FOREIGN_KEYS_ROOT.with(|root| *root.borrow_mut() = Some(key_path.clone()));
+        let locales = Self::load_package_locales(&mut path, cfg_file, name, &key_path);
+        FOREIGN_KEYS_ROOT.with(|root| *root.borrow_mut() = None);
+        locales
+    }
+
+    /// Read the file of each locale of a package from its directory.
+    fn load_package_locales(
+        path: &mut PathBuf,
+        cfg_file: &ConfigFile,
+        name: Option<&Rc<Key>>,
+        key_path: &KeyPath,
+    ) -> Result<Vec<Self>> {
+        let mut locales = Vec::with_capacity(cfg_file.locales.len());
+        for locale in &cfg_file.locales {
+            let name = Rc::clone(name.unwrap_or(locale));
+            path.push(&locale.name);
+            path.set_extension(FILE_FORMAT);
+            if Some(locale) == cfg_file.pseudo_locale.as_ref()
+                || (locale != &cfg_file.default && !path.exists())
+            {
+                locales.push(Locale::empty(Rc::clone(locale), name));
+                path.pop();
+                continue;
+            }
+            let locale_file = File::open(&path).map_err(|err| Error::LocaleFileNotFound {
+                path: path.clone(),
+                err,
+            })?;
+            let seed = LocaleSeed {
+                name,
+                top_locale_name: Rc::clone(locale),
+                key_path: key_path.clone(),
+                value_options: cfg_file.value_options,
+            };
+            locales.push(Self::de(locale_file, path, seed)?);
+            path.pop();
+        }
+        Ok(locales)
+    }
+
+    /// Create a locale without keys, for the locales not read from a file and the subkeys missing in a locale.
+    fn empty(top_locale_name: Rc<Key>, name: Rc<Key>) -> Self {
+        Locale {
+            top_locale_name,
+            name,
+            keys: HashMap::new(),
+            metadata: HashMap::new(),
+            fallback: None,
+            variables: None,
+        }
+    }
+
+    fn apply_inheritance(
+        locales: &mut [Locale],
+        inherits: &HashMap<Rc<Key>, Rc<Key>>,
+        namespace: Option<&Rc<Key>>,
+    ) {
+        let mut done = HashSet::new();
+        for index in 0..locales.len() {
+            Self::inherit_locale(locales, index, inherits, namespace, &mut done);
+        }
+    }
+
+    fn inherit_locale(
+        locales: &mut [Locale],
+        index: usize,
+        inherits: &HashMap<Rc<Key>, Rc<Key>>,
+        namespace: Option<&Rc<Key>>,
+        done: &mut HashSet<Rc<Key>>,
+    ) {
+        let name = Rc::clone(&locales[index].name);
+        if !done.insert(Rc::clone(&name)) {
+            return;
+        }
+        let Some(parent) = inherits.get(&name) else {
+            return;
+        };
+        // presence of the parent and absence of cycles are checked with the config.
+        let Some(parent_index) = locales.iter().position(|locale| &locale.name == parent) else {
+            return;
+        };
+        // the parent must have inherited its own parent first.
+        Self::inherit_locale(locales, parent_index, inherits, namespace, done);
+
+        let parent_keys = locales[parent_index].keys.clone();
+        let mut key_path = KeyPath::new(namespace.cloned());
+        let mut inherited = vec![];
+        locales[index].inherit_keys(parent_keys, &mut key_path, &mut inherited);
+
+        register_copied_foreign_keys(parent, &name, &inherited);
+    }
+
+    fn apply_fallbacks(locales: &mut [Locale], namespace: Option<&Rc<Key>>) -> Result<()> {
+        let Some((default_locale, locales_with_fallback)) = locales.split_first() else {
+            return Ok(());
+        };
+        let mut to_fill = vec![];
+        for (index, locale) in locales_with_fallback.iter().enumerate() {
+            let mut key_path = KeyPath::new(namespace.cloned());
+            let mut paths = vec![];
+            locale.collect_fallbacks(default_locale, None, &mut key_path, &mut paths);
+            to_fill.extend(paths.into_iter().map(|path| (index + 1, path)));
+        }
+
+        for (index, (key_path, fallback)) in to_fill {
+            let Some(fallback_locale) = locales.iter().find(|locale| locale.name == fallback)
+            else {
+                return Err(Error::UnknownFallbackLocale {
+                    locale: Rc::clone(&locales[index].name),
+                    key_path,
+                    fallback,
+                });
+            };
+            let Some(mut value) = fallback_locale.get_value_at(&key_path.path).cloned() else {
+                // missing in the fallback locale too, use the default locale.
+                continue;
+            };
+            let locale = &mut locales[index];
+            value.set_top_locale_name(&locale.top_locale_name);
+            locale.insert_value_at(&key_path.path, value);
+            register_copied_foreign_keys(&fallback, &locale.name, &[key_path]);
+        }
+        Ok(())
+    }
+
+    /// Collect the paths of the keys missing in this locale that have a fallback locale, with that locale.
+    fn collect_fallbacks(
+        &self,
+        default_locale: &Locale,
+        parent_fallback: Option<&Rc<Key>>,
+        key_path: &mut KeyPath,
+        paths: &mut Vec<(KeyPath, Rc<Key>)>,
+    ) {
+        let map_fallback = self.fallback.as_ref().or(parent_fallback);
+        for (key, default_value) in &default_locale.keys {
+            let fallback = self
+                .metadata
+                .get(key)
+                .and_then(|metadata| metadata.fallback.as_ref())
+                .or(map_fallback);
+            key_path.push_key(Rc::clone(key));
+            match (self.keys.get(key), default_value) {
+                (Some(ParsedValue::Subkeys(subkeys)), ParsedValue::Subkeys(default_subkeys)) => {
+                    subkeys.collect_fallbacks(default_subkeys, fallback, key_path, paths);
+                }
+                (None | Some(ParsedValue::Default), _) => {
+                    if let Some(fallback) = fallback {
+                        paths.push((key_path.clone(), Rc::clone(fallback)));
+                    }
+                }
+                (Some(_), _) => {}
+            }
+            key_path.pop_key();
+        }
+    }
+
+    fn check_deprecations(
+        &self,
+        values: &LocalesOrNamespaces,
+        key_path: &mut KeyPath,
+    ) -> Result<()> {
+        for (key, metadata) in &self.metadata {
+            let Some(replacement) = metadata
+                .deprecated
+                .as_ref()
+                .and_then(|deprecation| deprecation.replacement.as_ref())
+            else {
+                continue;
+            };
+            // the replacement is in the same namespace if none is given.
+            let replacement_path = ParsedValue::parse_key_path(replacement).map(|mut path| {
+                if path.namespace.is_none() {
+                    path.namespace = key_path.namespace.clone();
+                }
+                path
+            });
+            let exists = replacement_path
+                .is_some_and(|path| values.get_value_at(&self.top_locale_name, &path).is_some());
+            if !exists {
+                let mut key_path = key_path.clone();
+                key_path.push_key(Rc::clone(key));
+                return Err(Error::UnknownReplacementKey {
+                    key_path,
+                    replacement: replacement.clone(),
+                });
+            }
+        }
+        for (key, value) in &self.keys {
+            if let ParsedValue::Subkeys(subkeys) = value {
+                key_path.push_key(Rc::clone(key));
+                subkeys.check_deprecations(values, key_path)?;
+                key_path.pop_key();
+            }
+        }
+        Ok(())
+    }
+
+    fn check_variables(locales: &[Locale], namespace: Option<&Rc<Key>>) -> Result<()> {
+        let Some((default_locale, others)) = locales.split_first() else {
+            return Ok(());
+        };
+        for locale in others.iter().filter(|locale| locale.variables.is_some()) {
+            emit_warning(Warning::IgnoredVariables {
+                locale: Rc::clone(&locale.name),
+                namespace: namespace.cloned(),
+            });
+        }
+        let Some(variables) = &default_locale.variables else {
+            return Ok(());
+        };
+        let mut used = HashSet::new();
+        for locale in locales {
+            let mut key_path = KeyPath::new(namespace.cloned());
+            locale.check_used_variables(variables, &mut used, &mut key_path)?;
+        }
+        let mut unused = variables
+            .keys()
+            .filter(|name| !used.contains(name.as_str()))
+            .collect::<Vec<_>>();
+        unused.sort();
+        for name in unused {
+            emit_warning(Warning::UnusedVariable {
+                name: name.clone(),
+                namespace: namespace.cloned(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_used_variables<'a>(
+        &self,
+        variables: &'a HashMap<String, VariableDeclaration>,
+        used: &mut HashSet<&'a str>,
+        key_path: &mut KeyPath,
+    ) -> Result<()> {
+        for (key, value) in &self.keys {
+            key_path.push_key(Rc::clone(key));
+            if let ParsedValue::Subkeys(subkeys) = value {
+                subkeys.check_used_variables(variables, used, key_path)?;
+            } else if let Some(keys) = value.get_keys() {
+                let is_plural = keys
+                    .iter()
+                    .any(|key| matches!(key, InterpolateKey::Count(_)));
+                for key in &keys {
+                    let (InterpolateKey::Variable(variable)
+                    | InterpolateKey::TypedVariable(variable, _)
+                    | InterpolateKey::Formatted(variable, _)
+                    | InterpolateKey::Select(variable)) = key
+                    else {
+                        continue;
+                    };
+                    let name = variable.name.strip_prefix("var_").unwrap_or(&variable.name);
+                    // the count of plurals is always available.
+                    if is_plural && name == "count" {
+                        continue;
+                    }
+                    let Some((name, _)) = variables.get_key_value(name) else {
+                        return Err(Error::UndeclaredVariable {
+                            locale: Rc::clone(&self.top_locale_name),
+                            key_path: std::mem::take(key_path),
+                            variable: name.to_string(),
+                        });
+                    };
+                    used.insert(name.as_str());
+                }
+            }
+            key_path.pop_key();
+        }
+        Ok(())
+    }
+
+    fn insert_value_at(&mut self, path: &[Rc<Key>], value: ParsedValue) {
+        match path {
+            [] => {}
+            [key] => {
+                self.keys.insert(Rc::clone(key), value);
+            }
+            [key, path @ ..] => {
+                if let Some(ParsedValue::Subkeys(subkeys)) = self.keys.get_mut(key) {
+                    subkeys.insert_value_at(path, value);
+                }
+            }
+        }
+    }
+
+    /// Take the values of the parent for the keys missing or declared as `null` in this locale, recursing in subkeys.
+    fn inherit_keys(
+        &mut self,
+        parent_keys: HashMap<Rc<Key>, ParsedValue>,
+        key_path: &mut KeyPath,
+        inherited: &mut Vec<KeyPath>,
+    ) {
+        for (key, mut parent_value) in parent_keys {
+            key_path.push_key(Rc::clone(&key));
+            match (self.keys.get_mut(&key), &mut parent_value) {
+                (Some(ParsedValue::Subkeys(subkeys)), ParsedValue::Subkeys(parent_subkeys)) => {
+                    let parent_keys = std::mem::take(&mut parent_subkeys.keys);
+                    subkeys.inherit_keys(parent_keys, key_path, inherited);
+                }
+                (None | Some(ParsedValue::Default), _) => {
+                    parent_value.set_top_locale_name(&self.top_locale_name);
+                    self.keys.insert(key, parent_value);
+                    inherited.push(key_path.clone());
+                }
+                (Some(_), _) => {}
+            }
+            key_path.pop_key();
+        }
+    }
+
     pub fn make_builder_keys(&mut self) -> BuildersKeysInner {
         let mut keys = BuildersKeysInner::default();
         for (key, value) in &mut self.keys {
@@ -200,23 +804,27 @@ impl Locale {
         default_locale: &str,
         top_locale: Rc<Key>,
         key_path: &mut KeyPath,
+        policy: MissingKeyPolicy,
     ) -> Result<()> {
         for (key, keys) in &mut keys.0 {
             key_path.push_key(Rc::clone(key));
             if let Some(value) = self.keys.get_mut(key) {
-                value.merge(keys, default_locale, Rc::clone(&self.name), key_path)?;
+                value.merge(
+                    keys,
+                    default_locale,
+                    Rc::clone(&self.name),
+                    key_path,
+                    policy,
+                )?;
             } else {
-                emit_warning(Warning::MissingKey {
-                    locale: top_locale.clone(),
-                    key_path: key_path.clone(),
-                });
+                self.merge_missing_key(key, keys, default_locale, &top_locale, key_path, policy)?;
             }
             key_path.pop_key();
         }
 
         // reverse key comparaison
         for key in self.keys.keys() {
-            if keys.0.get(key).is_none() {
+            if !keys.0.contains_key(key) {
                 key_path.push_key(Rc::clone(key));
                 emit_warning(Warning::SurplusKey {
                     locale: top_locale.clone(),
@@ -228,20 +836,89 @@ impl Locale {
         Ok(())
     }
 
+    /// Apply the policy for the missing keys to a key of the default locale missing in this one.
+    ///
+    /// With `fallback-with-marker` the values become their key path, without a warning, and the keys of missing subkeys are filled the same way,
+    /// the lists keeping the value of the default locale as they can't hold a marker. The keys are still reported as missing at runtime.
+    fn merge_missing_key(
+        &mut self,
+        key: &Rc<Key>,
+        keys: &mut LocaleValue,
+        default_locale: &str,
+        top_locale: &Rc<Key>,
+        key_path: &mut KeyPath,
+        policy: MissingKeyPolicy,
+    ) -> Result<()> {
+        if policy == MissingKeyPolicy::Error {
+            return Err(Error::MissingKeyInLocale {
+                locale: Rc::clone(top_locale),
+                key_path: std::mem::take(key_path),
+            });
+        }
+        if policy == MissingKeyPolicy::FallbackWithMarker {
+            match keys {
+                LocaleValue::Value(_) => {
+                    register_missing_key(Rc::clone(top_locale), key_path.clone());
+                    let marker = format!("[{}]", key_path.to_path_string());
+                    let mut value = ParsedValue::String(marker);
+                    value.merge(
+                        keys,
+                        default_locale,
+                        Rc::clone(&self.name),
+                        key_path,
+                        policy,
+                    )?;
+                    self.keys.insert(Rc::clone(key), value);
+                    return Ok(());
+                }
+                LocaleValue::Subkeys { .. } => {
+                    let subkeys = Locale::empty(Rc::clone(&self.top_locale_name), Rc::clone(key));
+                    let mut value = ParsedValue::Subkeys(subkeys);
+                    return value.merge(
+                        keys,
+                        default_locale,
+                        Rc::clone(&self.name),
+                        key_path,
+                        policy,
+                    );
+                }
+                LocaleValue::List | LocaleValue::ObjectList(_) => {}
+            }
+        }
+        register_missing_key(Rc::clone(top_locale), key_path.clone());
+        emit_warning(Warning::MissingKey {
+            locale: Rc::clone(top_locale),
+            key_path: key_path.clone(),
+        });
+        Ok(())
+    }
+
+    /// Explicit defaults (`null`) are not allowed in the default locale, including in subkeys.
+    fn check_no_explicit_default(&self, key_path: &mut KeyPath) -> Result<()> {
+        for (key, value) in &self.keys {
+            key_path.push_key(Rc::clone(key));
+            match value {
+                ParsedValue::Default => {
+                    return Err(Error::ExplicitDefaultInDefault(std::mem::take(key_path)))
+                }
+                ParsedValue::Subkeys(subkeys) => subkeys.check_no_explicit_default(key_path)?,
+                _ => {}
+            }
+            key_path.pop_key();
+        }
+        Ok(())
+    }
+
     pub fn check_locales_inner(
         locales: &mut [Locale],
         namespace: Option<Rc<Key>>,
+        policy: MissingKeyPolicy,
     ) -> Result<BuildersKeysInner> {
         let mut locales = locales.iter_mut();
         let default_locale = locales.next().unwrap();
         let mut key_path = KeyPath::new(namespace);
 
-        for (key, value) in &default_locale.keys {
-            if matches!(value, ParsedValue::Default) {
-                key_path.push_key(Rc::clone(key));
-                return Err(Error::ExplicitDefaultInDefault(key_path));
-            }
-        }
+        default_locale.check_no_explicit_default(&mut key_path)?;
 
         let mut default_keys = default_locale.make_builder_keys();
 
@@ -254,13 +931,17 @@ impl Locale {
                 default_locale_name,
                 top_locale,
                 &mut key_path,
+                policy,
             )?;
         }
 
         Ok(default_keys)
     }
 
-    pub fn check_locales(locales: &mut LocalesOrNamespaces) -> Result<BuildersKeys> {
+    pub fn check_locales(
+        locales: &mut LocalesOrNamespaces,
+        policy: MissingKeyPolicy,
+    ) -> Result<BuildersKeys<'_>> {
         match locales {
             LocalesOrNamespaces::NameSpaces(namespaces) => {
                 let mut keys = HashMap::with_capacity(namespaces.len());
@@ -268,13 +949,14 @@ impl Locale {
                     let k = Self::check_locales_inner(
                         &mut namespace.locales,
                         Some(Rc::clone(&namespace.key)),
+                        policy,
                     )?;
                     keys.insert(Rc::clone(&namespace.key), k);
                 }
                 Ok(BuildersKeys::NameSpaces { namespaces, keys })
             }
             LocalesOrNamespaces::Locales(locales) => {
-                let keys = Self::check_locales_inner(locales, None)?;
+                let keys = Self::check_locales_inner(locales, None, policy)?;
                 Ok(BuildersKeys::Locales { locales, keys })
             }
         }
@@ -283,6 +965,8 @@ impl Locale {
 
 pub enum LocaleValue {
     Value(Option<HashSet<InterpolateKey>>),
+    List,
+    ObjectList(Vec<Rc<Key>>),
     Subkeys {
         locales: Vec<Locale>,
         keys: BuildersKeysInner,
@@ -294,30 +978,210 @@ pub struct LocaleSeed {
     pub name: Rc<Key>,
     pub top_locale_name: Rc<Key>,
     pub key_path: KeyPath,
+    pub value_options: ValueOptions,
 }
 
-impl<'de> serde::de::Visitor<'de> for LocaleSeed {
-    type Value = HashMap<Rc<Key>, ParsedValue>;
+impl LocaleSeed {
+    /// Split the key on the dots if they are interpreted as nesting.
+    fn split_key(&self, key: &str) -> Result<Vec<Rc<Key>>> {
+        match self.value_options.dotted_keys {
+            DottedKeys::Nested => key
+                .split('.')
+                .map(|key| Key::try_new(key).map(Rc::new))
+                .collect(),
+            DottedKeys::Literal => Ok(vec![Rc::new(Key::try_new(key)?)]),
+        }
+    }
+
+    fn subkeys_at_mut<'a>(
+        keys: &'a mut HashMap<Rc<Key>, ParsedValue>,
+        path: &[Rc<Key>],
+    ) -> Option<&'a mut Locale> {
+        let (key, path) = path.split_first()?;
+        let Some(ParsedValue::Subkeys(subkeys)) = keys.get_mut(key) else {
+            return None;
+        };
+        if path.is_empty() {
+            Some(subkeys)
+        } else {
+            Self::subkeys_at_mut(&mut subkeys.keys, path)
+        }
+    }
 
-    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    /// Insert the value at the path, creating the subkeys on the way and merging them with the existing ones,
+    /// so `"home.title"` and a `"home"` map can both be declared.
+    fn insert_nested(
+        &self,
+        keys: &mut HashMap<Rc<Key>, ParsedValue>,
+        path: &[Rc<Key>],
+        value: ParsedValue,
+        key_path: &mut KeyPath,
+    ) -> Result<()> {
+        let Some((key, path)) = path.split_first() else {
+            return Ok(());
+        };
+        key_path.push_key(Rc::clone(key));
+        if path.is_empty() {
+            match (keys.get_mut(key), value) {
+                (None, value) => {
+                    keys.insert(Rc::clone(key), value);
+                }
+                (Some(ParsedValue::Subkeys(subkeys)), ParsedValue::Subkeys(new_subkeys)) => {
+                    for (new_key, new_value) in new_subkeys.keys {
+                        self.insert_nested(&mut subkeys.keys, &[new_key], new_value, key_path)?;
+                    }
+                    subkeys.metadata.extend(new_subkeys.metadata);
+                    subkeys.fallback = subkeys.fallback.take().or(new_subkeys.fallback);
+                }
+                (Some(_), _) => return Err(Error::DottedKeyConflict(std::mem::take(key_path))),
+            }
+        } else {
+            let subkeys = keys.entry(Rc::clone(key)).or_insert_with(|| {
+                ParsedValue::Subkeys(Locale {
+                    top_locale_name: Rc::clone(&self.top_locale_name),
+                    name: Rc::clone(key),
+                    keys: HashMap::new(),
+                    metadata: HashMap::new(),
+                    fallback: None,
+                    variables: None,
+                })
+            });
+            let ParsedValue::Subkeys(subkeys) = subkeys else {
+                return Err(Error::DottedKeyConflict(std::mem::take(key_path)));
+            };
+            self.insert_nested(&mut subkeys.keys, path, value, key_path)?;
+        }
+        key_path.pop_key();
+        Ok(())
+    }
+
+    /// Deserialize the map of keys, `first_key` is the key already read from the map if any.
+    pub fn deserialize_map<'de, A>(
+        mut self,
+        mut first_key: Option<String>,
+        mut map: A,
+    ) -> Result<Locale, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
         let mut keys = HashMap::new();
+        let mut metadata = HashMap::<Rc<Key>, KeyMetadata>::new();
+        let mut nested_metadata = Vec::<(Vec<Rc<Key>>, Rc<Key>, KeyMetadata)>::new();
+        let mut fallback = None;
+        let mut variables = None;
 
-        while let Some(locale_key) = map.next_key()? {
-            self.key_path.push_key(Rc::clone(&locale_key));
+        while let Some(locale_key) = match first_key.take() {
+            Some(locale_key) => Some(locale_key),
+            None => map.next_key::<String>()?,
+        } {
+            if locale_key == KeyMetadata::FALLBACK_KEY {
+                fallback = Some(map.next_value()?);
+                continue;
+            }
+            if locale_key == VariableDeclaration::SECTION_KEY && self.key_path.path.is_empty() {
+                variables = Some(map.next_value()?);
+                continue;
+            }
+            if let Some(metadata_key) = locale_key.strip_prefix(KeyMetadata::PREFIX) {
+                let mut path = self
+                    .split_key(metadata_key)
+                    .map_err(serde::de::Error::custom)?;
+                let metadata_key = path.pop().unwrap();
+                if path.is_empty() {
+                    metadata.insert(metadata_key, map.next_value()?);
+                } else {
+                    nested_metadata.push((path, metadata_key, map.next_value()?));
+                }
+                continue;
+            }
+            let path = self
+                .split_key(&locale_key)
+                .map_err(serde::de::Error::custom)?;
+            let locale_key = Rc::clone(&path[0]);
+            if let Some(other) = keys
+                .keys()
+                .find(|other: &&Rc<Key>| other.ident == locale_key.ident && **other != locale_key)
+            {
+                return Err(serde::de::Error::custom(Error::KeyIdentCollision {
+                    key_path: self.key_path.clone(),
+                    key: Rc::clone(&locale_key),
+                    other: Rc::clone(other),
+                }));
+            }
+            for key in &path {
+                self.key_path.push_key(Rc::clone(key));
+            }
             let value = map.next_value_seed(ParsedValueSeed {
                 top_locale_name: &self.top_locale_name,
-                key: &locale_key,
+                key: path.last().unwrap(),
                 key_path: &self.key_path,
                 in_plural: false,
+                value_options: self.value_options,
             })?;
-            self.key_path.pop_key();
-            keys.insert(locale_key, value);
+            for _ in &path {
+                self.key_path.pop_key();
+            }
+            if self.value_options.dotted_keys == DottedKeys::Nested {
+                let mut key_path = self.key_path.clone();
+                self.insert_nested(&mut keys, &path, value, &mut key_path)
+                    .map_err(serde::de::Error::custom)?;
+            } else {
+                keys.insert(locale_key, value);
+            }
+        }
+
+        for (path, metadata_key, key_metadata) in nested_metadata {
+            match Self::subkeys_at_mut(&mut keys, &path) {
+                Some(subkeys)
+                    if subkeys.keys.contains_key(&metadata_key)
+                        || key_metadata.fallback.is_some() =>
+                {
+                    subkeys.metadata.insert(metadata_key, key_metadata);
+                }
+                _ => {
+                    let mut key_path = self.key_path.clone();
+                    for key in path.into_iter().chain([metadata_key]) {
+                        key_path.push_key(key);
+                    }
+                    emit_warning(Warning::OrphanMetadata {
+                        locale: Rc::clone(&self.top_locale_name),
+                        key_path,
+                    });
+                }
+            }
+        }
+
+        for (metadata_key, key_metadata) in &metadata {
+            // metadata for a missing key can still declare where to take its value from.
+            if !keys.contains_key(metadata_key) && key_metadata.fallback.is_none() {
+                let mut key_path = self.key_path.clone();
+                key_path.push_key(Rc::clone(metadata_key));
+                emit_warning(Warning::OrphanMetadata {
+                    locale: Rc::clone(&self.top_locale_name),
+                    key_path,
+                });
+            }
         }
 
-        Ok(keys)
+        Ok(Locale {
+            name: self.name,
+            top_locale_name: self.top_locale_name,
+            keys,
+            metadata,
+            fallback,
+            variables,
+        })
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for LocaleSeed {
+    type Value = Locale;
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.deserialize_map(None, map)
     }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -335,16 +1199,76 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed {
     where
         D: serde::Deserializer<'de>,
     {
-        let keys = deserializer.deserialize_map(self.clone())?;
-        let Self {
-            name,
-            top_locale_name,
-            ..
-        } = self;
-        Ok(Locale {
-            name,
-            keys,
-            top_locale_name,
-        })
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(all(test, feature = "json_files"))]
+mod tests {
+    use super::*;
+    use crate::load_locales::warning::WARNINGS;
+    use serde::de::DeserializeSeed;
+
+    fn locale(name: &str, json: &str) -> Locale {
+        let name = Rc::new(Key::new(name).unwrap());
+        let seed = LocaleSeed {
+            name: Rc::clone(&name),
+            top_locale_name: name,
+            key_path: KeyPath::new(None),
+            value_options: Default::default(),
+        };
+        seed.deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap()
+    }
+
+    fn take_warnings() -> Vec<String> {
+        WARNINGS.with(|warnings| warnings.take().iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn undeclared_variable() {
+        let en = locale(
+            "en",
+            r#"{ "_variables": { "name": "Name of the user" }, "hello": "Hello {{ name }}, you have {{ count }} messages" }"#,
+        );
+
+        let err = Locale::check_variables(&[en], None).unwrap_err();
+        assert!(
+            matches!(&err, Error::UndeclaredVariable { locale, variable, .. } if locale.name == "en" && variable == "count"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn unused_and_ignored_variables() {
+        take_warnings();
+        let en = locale(
+            "en",
+            r#"{ "_variables": { "name": "Name of the user", "unused": null }, "hello": "Hello {{ name }}", "messages": [["{{ count }} messages", "_"]] }"#,
+        );
+        let fr = locale(
+            "fr",
+            r#"{ "_variables": { "nom": "Nom de l'utilisateur" }, "hello": "Bonjour {{ name }}", "messages": [["{{ count }} messages", "_"]] }"#,
+        );
+
+        Locale::check_variables(&[en, fr], None).unwrap();
+        assert_eq!(
+            take_warnings(),
+            [
+                "Variables can only be declared in the default locale, the \"_variables\" section of locale \"fr\" is ignored",
+                "Variable \"unused\" is declared but never used",
+            ]
+        );
+    }
+
+    #[test]
+    fn no_declared_variables() {
+        take_warnings();
+        let en = locale("en", r#"{ "hello": "Hello {{ name }}" }"#);
+        assert!(en.variables.is_none());
+
+        Locale::check_variables(&[en], None).unwrap();
+        assert!(take_warnings().is_empty());
     }
 }
@@ -0,0 +1,479 @@
+use std::rc::Rc;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::key::Key;
+
+/// Metadata for translators declared next to a key with the `@key` convention.
+///
+/// It does not generate any translation, but it is validated and used for the documentation of the generated keys.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyMetadata {
+    pub description: Option<String>,
+    pub context: Option<String>,
+    pub screenshot: Option<String>,
+    pub tags: Vec<String>,
+    /// Locale to take the value from when the key is missing in this locale, instead of the default locale.
+    pub fallback: Option<Rc<Key>>,
+    pub deprecated: Option<Deprecation>,
+}
+
+/// Deprecation of a key, the generated field is marked `#[deprecated]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Deprecation {
+    pub note: Option<String>,
+    /// Path of the key to use instead, with the same syntax as foreign keys.
+    pub replacement: Option<String>,
+}
+
+impl Deprecation {
+    fn to_attr(&self) -> TokenStream {
+        let note = match (&self.note, &self.replacement) {
+            (None, None) => return quote!(#[deprecated]),
+            (Some(note), None) => note.clone(),
+            (None, Some(replacement)) => format!("use `{}` instead", replacement),
+            (Some(note), Some(replacement)) => format!("{}, use `{}` instead", note, replacement),
+        };
+        quote!(#[deprecated(note = #note)])
+    }
+}
+
+impl KeyMetadata {
+    pub const PREFIX: char = '@';
+    /// Key of the fallback locale for all the keys of a map.
+    pub const FALLBACK_KEY: &'static str = "@@fallback";
+
+    /// Return the lines of documentation describing the metadata.
+    fn doc_lines(&self) -> Vec<String> {
+        let mut lines = vec![];
+        let mut push_paragraph = |paragraph: String| {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.extend(paragraph.lines().map(|line| format!(" {}", line)));
+        };
+        if let Some(description) = &self.description {
+            push_paragraph(description.clone());
+        }
+        if let Some(context) = &self.context {
+            push_paragraph(format!("Context: {}", context));
+        }
+        if let Some(screenshot) = &self.screenshot {
+            push_paragraph(format!("Screenshot: <{}>", screenshot));
+        }
+        if !self.tags.is_empty() {
+            push_paragraph(format!("Tags: {}", self.tags.join(", ")));
+        }
+        lines
+    }
+
+    /// Return the attributes for the field of the key: the docs, followed by the given lines describing the value,
+    /// and the deprecation, if any.
+    pub fn to_attrs(metadata: Option<&Self>, value_lines: Vec<String>) -> TokenStream {
+        let mut lines = metadata.map(Self::doc_lines).unwrap_or_default();
+        if !lines.is_empty() && !value_lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(value_lines);
+        let deprecated = metadata
+            .and_then(|metadata| metadata.deprecated.as_ref())
+            .map(Deprecation::to_attr);
+        quote!(#(#[doc = #lines])* #deprecated)
+    }
+}
+
+// -----------------------------------------
+// Deserialization
+// -----------------------------------------
+
+enum Field {
+    Description,
+    Context,
+    Screenshot,
+    Tags,
+    Fallback,
+    Deprecated,
+    Replacement,
+    Unknown,
+}
+
+impl Field {
+    const FIELDS: &'static [&'static str] = &[
+        "description",
+        "context",
+        "screenshot",
+        "tags",
+        "fallback",
+        "deprecated",
+        "replacement",
+    ];
+}
+
+impl<'de> serde::Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> serde::de::Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "an identifier for the fields {:?}",
+            Field::FIELDS
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "description" => Ok(Field::Description),
+            "context" => Ok(Field::Context),
+            "screenshot" => Ok(Field::Screenshot),
+            "tags" => Ok(Field::Tags),
+            "fallback" => Ok(Field::Fallback),
+            "deprecated" => Ok(Field::Deprecated),
+            "replacement" => Ok(Field::Replacement),
+            _ => Ok(Field::Unknown), // skip unknown fields, they can be used by other tools
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for KeyMetadata {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(KeyMetadataVisitor)
+    }
+}
+
+struct KeyMetadataVisitor;
+
+impl<'de> serde::de::Visitor<'de> for KeyMetadataVisitor {
+    type Value = KeyMetadata;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either a description or a map with the fields {:?}",
+            Field::FIELDS
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(KeyMetadata {
+            description: Some(v.to_string()),
+            ..Default::default()
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        fn deser_field<'de, A, T>(
+            option: &mut Option<T>,
+            map: &mut A,
+            field_name: &'static str,
+        ) -> Result<(), A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+            T: serde::de::DeserializeOwned,
+        {
+            if option.replace(map.next_value()?).is_some() {
+                Err(serde::de::Error::duplicate_field(field_name))
+            } else {
+                Ok(())
+            }
+        }
+        let mut description = None;
+        let mut context = None;
+        let mut screenshot = None;
+        let mut tags = None;
+        let mut fallback = None;
+        let mut deprecated: Option<DeprecatedField> = None;
+        let mut replacement = None;
+        while let Some(field) = map.next_key::<Field>()? {
+            match field {
+                Field::Description => deser_field(&mut description, &mut map, "description")?,
+                Field::Context => deser_field(&mut context, &mut map, "context")?,
+                Field::Screenshot => deser_field(&mut screenshot, &mut map, "screenshot")?,
+                Field::Tags => deser_field(&mut tags, &mut map, "tags")?,
+                Field::Fallback => deser_field(&mut fallback, &mut map, "fallback")?,
+                Field::Deprecated => deser_field(&mut deprecated, &mut map, "deprecated")?,
+                Field::Replacement => deser_field(&mut replacement, &mut map, "replacement")?,
+                Field::Unknown => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        // a replacement implies the deprecation, unless it is explicitly set to `false`.
+        let deprecated = match (deprecated, replacement) {
+            (Some(DeprecatedField(None)), _) | (None, None) => None,
+            (Some(DeprecatedField(Some(note))), replacement) => Some(Deprecation {
+                note: (!note.is_empty()).then_some(note),
+                replacement,
+            }),
+            (None, Some(replacement)) => Some(Deprecation {
+                note: None,
+                replacement: Some(replacement),
+            }),
+        };
+        Ok(KeyMetadata {
+            description,
+            context,
+            screenshot,
+            tags: tags.unwrap_or_default(),
+            fallback,
+            deprecated,
+        })
+    }
+}
+
+/// Value of the `deprecated` field, either a boolean or a note, `None` if not deprecated.
+struct DeprecatedField(Option<String>);
+
+impl<'de> serde::Deserialize<'de> for DeprecatedField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DeprecatedFieldVisitor)
+    }
+}
+
+struct DeprecatedFieldVisitor;
+
+impl<'de> serde::de::Visitor<'de> for DeprecatedFieldVisitor {
+    type Value = DeprecatedField;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either a boolean or a deprecation note")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(DeprecatedField(v.then(String::new)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(DeprecatedField(Some(v.to_string())))
+    }
+}
+
+/// A variable declared in the `_variables` section of the default locale, shared by the keys of the file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VariableDeclaration {
+    pub description: Option<String>,
+    /// Type expected for the variable, for translators and tooling.
+    pub r#type: Option<String>,
+    /// Example value for the variable, for translators and tooling.
+    pub default: Option<String>,
+}
+
+impl VariableDeclaration {
+    /// Key of the section declaring the variables at the top of a file.
+    pub const SECTION_KEY: &'static str = "_variables";
+}
+
+enum VariableField {
+    Description,
+    Type,
+    Default,
+    Unknown,
+}
+
+impl VariableField {
+    const FIELDS: &'static [&'static str] = &["description", "type", "default"];
+}
+
+impl<'de> serde::Deserialize<'de> for VariableField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(VariableFieldVisitor)
+    }
+}
+
+struct VariableFieldVisitor;
+
+impl<'de> serde::de::Visitor<'de> for VariableFieldVisitor {
+    type Value = VariableField;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "an identifier for the fields {:?}",
+            VariableField::FIELDS
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "description" => Ok(VariableField::Description),
+            "type" => Ok(VariableField::Type),
+            "default" => Ok(VariableField::Default),
+            _ => Ok(VariableField::Unknown),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VariableDeclaration {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(VariableDeclarationVisitor)
+    }
+}
+
+struct VariableDeclarationVisitor;
+
+impl<'de> serde::de::Visitor<'de> for VariableDeclarationVisitor {
+    type Value = VariableDeclaration;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "either a description or a map with the fields {:?}",
+            VariableField::FIELDS
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(VariableDeclaration {
+            description: Some(v.to_string()),
+            ..Default::default()
+        })
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(VariableDeclaration::default())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut declaration = VariableDeclaration::default();
+        while let Some(field) = map.next_key::<VariableField>()? {
+            let value = match field {
+                VariableField::Description => &mut declaration.description,
+                VariableField::Type => &mut declaration.r#type,
+                VariableField::Default => &mut declaration.default,
+                VariableField::Unknown => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                    continue;
+                }
+            };
+            *value = Some(map.next_value()?);
+        }
+        Ok(declaration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_doc_lines() {
+        let metadata = KeyMetadata {
+            description: Some("Title of the page".to_string()),
+            context: None,
+            screenshot: Some("https://example.com/home.png".to_string()),
+            tags: vec!["home".to_string(), "header".to_string()],
+            fallback: None,
+            deprecated: None,
+        };
+
+        assert_eq!(
+            metadata.doc_lines(),
+            [
+                " Title of the page",
+                "",
+                " Screenshot: <https://example.com/home.png>",
+                "",
+                " Tags: home, header"
+            ]
+        );
+    }
+
+    #[test]
+    fn deprecation_note() {
+        let deprecation = Deprecation {
+            note: Some("the page was removed".to_string()),
+            replacement: Some("home.title".to_string()),
+        };
+        assert_eq!(
+            deprecation.to_attr().to_string(),
+            quote!(#[deprecated(note = "the page was removed, use `home.title` instead")])
+                .to_string()
+        );
+        assert_eq!(
+            Deprecation::default().to_attr().to_string(),
+            quote!(#[deprecated]).to_string()
+        );
+    }
+
+    #[cfg(feature = "json_files")]
+    #[test]
+    fn variable_declaration_forms() {
+        use std::collections::HashMap;
+
+        let variables: HashMap<String, VariableDeclaration> = serde_json::from_str(
+            r#"{
+                "name": "Name of the user",
+                "count": { "description": "Number of messages", "type": "u32", "default": "3", "unknown": 0 },
+                "empty": null
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            variables["name"],
+            VariableDeclaration {
+                description: Some("Name of the user".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            variables["count"],
+            VariableDeclaration {
+                description: Some("Number of messages".to_string()),
+                r#type: Some("u32".to_string()),
+                default: Some("3".to_string()),
+            }
+        );
+        assert_eq!(variables["empty"], VariableDeclaration::default());
+    }
+}
@@ -1,48 +1,137 @@
 use std::{
     collections::{HashMap, HashSet},
     ops::{Deref, Not},
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
 };
 
 pub mod cfg_file;
+pub mod dynamic;
 pub mod error;
+#[cfg(feature = "fluent_files")]
+pub mod fluent;
+pub mod formatter;
+pub mod hot_reload;
+#[cfg(feature = "json_files")]
+pub mod i18next;
 pub mod interpolate;
 pub mod key;
+pub mod key_manifest;
+pub mod key_usage;
 pub mod locale;
+pub mod metadata;
 pub mod parsed_value;
 pub mod plural;
+pub mod plural_rules;
+#[cfg(feature = "po_files")]
+pub mod po;
+pub mod pseudo;
+pub mod select;
+pub mod shard;
+pub mod snapshot;
 pub mod warning;
 
-use cfg_file::ConfigFile;
+use cfg_file::{
+    ConfigFile, CookieAttributes, NegotiationSource, SameSite, StorageKind, TextDirection,
+};
+use dynamic::DynamicAssets;
 use error::{Error, Result};
+use hot_reload::HotReload;
 use interpolate::{create_empty_type, Interpolation};
 use key::Key;
+use key_manifest::KeyManifest;
 use locale::{Locale, LocaleValue};
+use metadata::KeyMetadata;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::load_locales::parsed_value::ParsedValue;
+use crate::load_locales::parsed_value::{InterpolateKey, ParsedValue};
 
 use self::{
     locale::{BuildersKeys, BuildersKeysInner, LocalesOrNamespaces, Namespace},
     warning::generate_warnings,
 };
 
-pub fn load_locales() -> Result<TokenStream> {
+pub fn load_locales(args: shard::LoadLocalesArgs) -> Result<TokenStream> {
+    locale::clear_missing_keys();
+
     let mut cargo_manifest_dir: PathBuf = std::env::var("CARGO_MANIFEST_DIR")
         .map_err(Error::CargoDirEnvNotPresent)?
         .into();
 
-    let cfg_file = ConfigFile::new(&mut cargo_manifest_dir)?;
+    let mut cfg_file = ConfigFile::new(&mut cargo_manifest_dir)?;
+    let all_namespaces = cfg_file.namespaces_with_packages();
+    cfg_file.select_shard(args.shard.as_deref())?;
+    parsed_value::CONSTANTS.with(|constants| *constants.borrow_mut() = cfg_file.constants.clone());
+    let manifest_dir = cargo_manifest_dir.clone();
     let mut locales = LocalesOrNamespaces::new(&mut cargo_manifest_dir, &cfg_file)?;
 
+    locales.apply_inheritance(&cfg_file.inherits);
+    if let Some(pseudo) = &cfg_file.pseudo_locale {
+        locales.pseudo_localize(pseudo);
+    }
+    locales.apply_fallbacks()?;
+    locales.check_deprecations()?;
+
     ParsedValue::resolve_foreign_keys(&locales, &cfg_file.default)?;
+    locales.check_variables()?;
+    let select_enums = select::create_select_enums(&locales);
+
+    let assets = args
+        .dynamic
+        .then(|| dynamic::write_assets(&manifest_dir, &cfg_file, &locales))
+        .transpose()?;
+    let hot_reload = HotReload::new(&manifest_dir, &cfg_file);
+    let runtime = RuntimeValues {
+        dynamic: assets.as_ref(),
+        hot_reload: hot_reload.as_ref(),
+    };
 
-    let keys = Locale::check_locales(&mut locales)?;
+    let key_manifest = args
+        .export
+        .as_ref()
+        .map(|_| KeyManifest::new(&cfg_file, &locales));
+
+    if args.shard.is_some() {
+        let keys = Locale::check_locales(&mut locales, cfg_file.missing_keys.policy())?;
+        check_key_usage(&manifest_dir, &cfg_file, &all_namespaces, &keys);
+        // the missing keys of a shard are not reported at runtime, as `Locale` is generated by the main invocation.
+        let missing_keys = locale::take_missing_keys();
+        if let (Some(key_manifest), Some(path)) = (&key_manifest, &args.export) {
+            key_manifest.write(&manifest_dir, path, &missing_keys)?;
+        }
+        let locale_type = create_locale_type(keys, &cfg_file, true, runtime);
+        let warnings = generate_warnings();
+        return Ok(shard::create_shard_module(
+            args.locale.as_ref(),
+            quote!(#select_enums #locale_type),
+            warnings,
+        ));
+    }
+
+    // the snapshots are only used by tests, they are not compiled in without the `snapshot` feature.
+    let mut snapshots = if cfg!(feature = "snapshot") {
+        snapshot::create_snapshots(&locales)
+    } else {
+        vec![]
+    };
+    if args.dynamic {
+        // the snapshots contain every value, only the default locale is compiled in.
+        snapshots.retain(|(locale, _)| locale == &cfg_file.default);
+    }
 
-    let locale_type = create_locale_type(keys, &cfg_file);
-    let locale_enum = create_locales_enum(&cfg_file);
+    let key_paths = locales.key_paths();
+
+    let keys = Locale::check_locales(&mut locales, cfg_file.missing_keys.policy())?;
+    check_key_usage(&manifest_dir, &cfg_file, &all_namespaces, &keys);
+
+    let missing_keys = locale::take_missing_keys();
+    if let (Some(key_manifest), Some(path)) = (&key_manifest, &args.export) {
+        key_manifest.write(&manifest_dir, path, &missing_keys)?;
+    }
+
+    let locale_type = create_locale_type(keys, &cfg_file, false, runtime);
+    let locale_enum = create_locales_enum(&cfg_file, &missing_keys, &snapshots, &key_paths);
 
     let warnings = generate_warnings();
 
@@ -52,28 +141,123 @@ pub fn load_locales() -> Result<TokenStream> {
 
             #locale_type
 
+            #select_enums
+
             #[inline]
             pub fn use_i18n() -> leptos_i18n::I18nContext<Locale> {
                 leptos_i18n::use_i18n_context()
             }
 
+            #[inline]
+            pub fn try_use_i18n() -> Option<leptos_i18n::I18nContext<Locale>> {
+                leptos_i18n::try_use_i18n_context()
+            }
+
             #[inline]
             pub fn provide_i18n_context() -> leptos_i18n::I18nContext<Locale> {
                 leptos_i18n::provide_i18n_context()
             }
 
-            pub use leptos_i18n::{t, td};
+            #[inline]
+            pub fn provide_i18n_context_with_storage(storage: impl leptos_i18n::LocaleStorage) -> leptos_i18n::I18nContext<Locale> {
+                leptos_i18n::provide_i18n_context_with_storage(storage)
+            }
+
+            #[inline]
+            pub fn provide_i18n_context_with(locale: Locale) -> leptos_i18n::I18nContext<Locale> {
+                leptos_i18n::provide_i18n_context_with(locale)
+            }
+
+            /// Keep the `lang` and `dir` attributes of the `<html>` element in sync with the current locale.
+            ///
+            /// The context already does it when created, this sets them again if another `<Html />` overwrote them.
+            #[leptos::component]
+            pub fn I18nHtmlAttrs() -> impl leptos::IntoView {
+                leptos_i18n::provide_dir(use_i18n())
+            }
+
+            pub use leptos_i18n::{t, td, t_string, td_string, t_title, t_meta, t_asset, scope_i18n, use_i18n_scoped};
 
             #warnings
         }
     })
 }
 
-fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
+/// Check the usage of the keys if enabled, the namespaces loaded by the other invocations of the macro being ignored.
+fn check_key_usage(
+    manifest_dir: &Path,
+    cfg_file: &ConfigFile,
+    all_namespaces: &[Rc<Key>],
+    keys: &BuildersKeys,
+) {
+    if cfg_file.key_usage_dirs.is_empty() {
+        return;
+    }
+    let loaded = cfg_file.namespaces_with_packages();
+    let ignored_namespaces = all_namespaces
+        .iter()
+        .filter(|namespace| !loaded.contains(namespace))
+        .collect::<Vec<_>>();
+    key_usage::check_key_usage(
+        manifest_dir,
+        &cfg_file.key_usage_dirs,
+        keys,
+        &ignored_namespaces,
+    );
+}
+
+fn create_locales_enum(
+    cfg_file: &ConfigFile,
+    missing_keys: &HashMap<Rc<Key>, Vec<String>>,
+    snapshots: &[(Rc<Key>, String)],
+    key_paths: &[String],
+) -> TokenStream {
     let ConfigFile {
-        default, locales, ..
+        default,
+        locales,
+        negotiation,
+        cookie_name,
+        cookie_attributes,
+        locale_storage,
+        directions,
+        ..
     } = cfg_file;
 
+    let locale_storage = match locale_storage {
+        StorageKind::Cookie => quote!(leptos_i18n::StorageKind::Cookie),
+        StorageKind::LocalStorage => quote!(leptos_i18n::StorageKind::LocalStorage),
+        StorageKind::None => quote!(leptos_i18n::StorageKind::None),
+    };
+
+    let cookie_attributes = {
+        let CookieAttributes {
+            max_age,
+            same_site,
+            secure,
+            path,
+        } = cookie_attributes;
+        let same_site = match same_site {
+            SameSite::Strict => quote!(leptos_i18n::SameSite::Strict),
+            SameSite::Lax => quote!(leptos_i18n::SameSite::Lax),
+            SameSite::None => quote!(leptos_i18n::SameSite::None),
+        };
+        quote! {
+            leptos_i18n::CookieAttributes {
+                max_age: #max_age,
+                same_site: #same_site,
+                secure: #secure,
+                path: #path,
+            }
+        }
+    };
+
+    let negotiation = negotiation.iter().map(|source| match source {
+        NegotiationSource::Cookie => quote!(leptos_i18n::LocaleSource::Cookie),
+        NegotiationSource::AcceptLanguage => quote!(leptos_i18n::LocaleSource::AcceptLanguage),
+        NegotiationSource::Url => quote!(leptos_i18n::LocaleSource::Url),
+        NegotiationSource::Default => quote!(leptos_i18n::LocaleSource::Default),
+    });
+
     let as_str_match_arms = locales
         .iter()
         .map(|key| (&key.ident, &key.name))
@@ -86,17 +270,211 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
         .map(|(variant, locale)| quote!(#locale => Some(Locale::#variant)))
         .collect::<Vec<_>>();
 
-    let derives = if cfg!(feature = "serde") {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)])
+    let fallback_chain_match_arms = locales
+        .iter()
+        .map(|locale| {
+            let chain = cfg_file.fallback_chain(locale);
+            quote!(Locale::#locale => &[#(Locale::#chain,)*])
+        })
+        .collect::<Vec<_>>();
+
+    let missing_keys_match_arms = locales
+        .iter()
+        .filter_map(|locale| Some((locale, missing_keys.get(locale)?)))
+        .map(|(locale, keys)| quote!(Locale::#locale => &[#(#keys,)*]))
+        .collect::<Vec<_>>();
+
+    let direction_match_arms = locales
+        .iter()
+        .filter_map(|locale| Some((locale, directions.get(locale)?)))
+        .map(|(locale, direction)| match direction {
+            TextDirection::Ltr => quote!(Locale::#locale => leptos_i18n::Direction::Ltr),
+            TextDirection::Rtl => quote!(Locale::#locale => leptos_i18n::Direction::Rtl),
+        })
+        .collect::<Vec<_>>();
+
+    let (snapshot_fn, snapshot_impl) = if cfg!(feature = "snapshot") {
+        let snapshot_match_arms = snapshots
+            .iter()
+            .map(|(locale, snapshot)| quote!(Locale::#locale => #snapshot));
+        let snapshot_fn = quote! {
+            /// Return every key of this locale rendered to text, one `path = value` line per key sorted by path,
+            /// with the default value of the variables declared in `_variables` or `{{ name }}` in place of the variables.
+            pub fn snapshot(self) -> &'static str {
+                <Self as leptos_i18n::Locale>::snapshot(self)
+            }
+        };
+        let snapshot_impl = quote! {
+            fn snapshot(self) -> &'static str {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#snapshot_match_arms,)*
+                    _ => ""
+                }
+            }
+        };
+        (snapshot_fn, snapshot_impl)
+    } else {
+        (quote!(), quote!())
+    };
+
+    let locales_count = locales.len();
+    let locales_indexes = (0..locales_count).map(proc_macro2::Literal::usize_unsuffixed);
+
+    let serde_impls = if cfg!(feature = "serde") {
+        quote! {
+            impl leptos_i18n::__private::serde::Serialize for Locale {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: leptos_i18n::__private::serde::Serializer,
+                {
+                    leptos_i18n::__private::serialize_locale(*self, serializer)
+                }
+            }
+
+            impl<'de> leptos_i18n::__private::serde::Deserialize<'de> for Locale {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: leptos_i18n::__private::serde::Deserializer<'de>,
+                {
+                    leptos_i18n::__private::deserialize_locale(deserializer)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let icu_impls = if cfg!(feature = "icu") {
+        quote! {
+            impl From<Locale> for leptos_i18n::__private::icu_locale_core::Locale {
+                fn from(locale: Locale) -> Self {
+                    leptos_i18n::__private::to_icu_locale(leptos_i18n::Locale::as_str(locale))
+                }
+            }
+
+            impl From<Locale> for leptos_i18n::__private::icu_locale_core::LanguageIdentifier {
+                fn from(locale: Locale) -> Self {
+                    leptos_i18n::__private::to_icu_language_identifier(leptos_i18n::Locale::as_str(locale))
+                }
+            }
+        }
     } else {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)])
+        quote!()
     };
 
     quote! {
-        #derives
+        /// The locales of the application, ordered and numbered in the order they are declared in the configuration.
+        #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
         #[allow(non_camel_case_types)]
         pub enum Locale {
-            #(#locales,)*
+            #(#locales = #locales_indexes,)*
+        }
+
+        #serde_impls
+
+        #icu_impls
+
+        impl Locale {
+            /// The number of locales.
+            pub const COUNT: usize = #locales_count;
+
+            const ALL: [Locale; #locales_count] = [#(Locale::#locales,)*];
+
+            /// Return an iterator over all the locales, in declaration order.
+            pub fn iter() -> std::array::IntoIter<Locale, #locales_count> {
+                Self::ALL.into_iter()
+            }
+
+            /// Return the position of the locale in the configuration, which is also its discriminant.
+            pub const fn index(self) -> usize {
+                self as usize
+            }
+
+            /// Return the locale at the given position in the configuration, if any.
+            pub const fn from_index(index: usize) -> Option<Self> {
+                if index < Self::COUNT {
+                    Some(Self::ALL[index])
+                } else {
+                    None
+                }
+            }
+
+            /// Return the tag of the locale.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(#as_str_match_arms,)*
+                }
+            }
+
+            /// Return the locale matching the best the requested tags, sorted in preferred order, following the BCP 47 lookup scheme:
+            /// `"fr-CH"` match `"fr"` if there is no `"fr-CH"` locale. Return the default locale if none match.
+            pub fn find_best_match<T: AsRef<str>>(requested: &[T]) -> Self {
+                <Self as leptos_i18n::Locale>::find_best_match(requested)
+            }
+
+            /// Return every locale matching one of the requested tags, sorted in preferred order, without duplicates,
+            /// following the same lookup scheme as `find_best_match`.
+            pub fn find_matches<T: AsRef<str>>(requested: &[T]) -> Vec<Self> {
+                <Self as leptos_i18n::Locale>::find_matches(requested)
+            }
+
+            /// Return this locale followed by the locales its missing keys are taken from, ending with the default locale:
+            /// the locale it extends with `inherits` or else the locale of its tag without the last subtags (`fr-CA` then `fr`).
+            pub fn fallback_chain(self) -> &'static [Self] {
+                <Self as leptos_i18n::Locale>::fallback_chain(self)
+            }
+
+            /// Return the direction of the text of this locale, declared in `directions` in the configuration
+            /// or else derived from its tag.
+            pub fn direction(self) -> leptos_i18n::Direction {
+                <Self as leptos_i18n::Locale>::direction(self)
+            }
+
+            #snapshot_fn
+
+            /// Return the path of every key, sorted: `key.subkey`, or `namespace::key.subkey` with namespaces.
+            pub fn key_paths() -> &'static [&'static str] {
+                <Self as leptos_i18n::Locale>::key_paths()
+            }
+
+            /// Return the value of the key at the given path for this locale, if it is a string or contains only variables,
+            /// the variables being rendered as `{{ name }}`.
+            pub fn get_translation(self, key_path: &str) -> Option<std::borrow::Cow<'static, str>> {
+                <Self as leptos_i18n::Locale>::get_translation(self, key_path)
+            }
+
+            /// Return a builder rendering the value of the key at the given path for this locale with the given variables.
+            pub fn translation_builder<'a>(self, key_path: &str) -> Option<leptos_i18n::TranslationBuilder<'a>> {
+                <Self as leptos_i18n::Locale>::translation_builder(self, key_path)
+            }
+        }
+
+        impl std::fmt::Display for Locale {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(Locale::as_str(*self))
+            }
+        }
+
+        impl std::str::FromStr for Locale {
+            type Err = leptos_i18n::ParseLocaleError;
+
+            /// Parse the locale from its tag, ignoring case and accepting both `_` and `-` as separators.
+            ///
+            /// If no locale has this tag, its subtags are removed from the end until one match: `"fr-CA"` parse to `Locale::fr`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                leptos_i18n::__private::lookup_locale(s)
+                    .ok_or_else(|| leptos_i18n::ParseLocaleError::new(s))
+            }
+        }
+
+        impl std::convert::TryFrom<&str> for Locale {
+            type Error = leptos_i18n::ParseLocaleError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                std::str::FromStr::from_str(s)
+            }
         }
 
         impl Default for Locale {
@@ -108,15 +486,44 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
         impl leptos_i18n::Locale for Locale {
             type Keys = I18nKeys;
 
+            const NEGOTIATION: &'static [leptos_i18n::LocaleSource] = &[#(#negotiation,)*];
+            const COOKIE_NAME: &'static str = #cookie_name;
+            const STORAGE: leptos_i18n::StorageKind = #locale_storage;
+            const COOKIE_ATTRIBUTES: leptos_i18n::CookieAttributes = #cookie_attributes;
+
             fn as_str(self) -> &'static str {
-                match self {
-                    #(#as_str_match_arms,)*
-                }
+                Locale::as_str(self)
+            }
+            fn get_all() -> &'static [Self] {
+                &Locale::ALL
             }
             fn from_str(s: &str) -> Option<Self> {
                 match s.trim() {
                     #(#from_str_match_arms,)*
-                    _ => None
+                    s => leptos_i18n::__private::lookup_locale(s)
+                }
+            }
+            fn fallback_chain(self) -> &'static [Self] {
+                match self {
+                    #(#fallback_chain_match_arms,)*
+                }
+            }
+            fn missing_keys(self) -> &'static [&'static str] {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#missing_keys_match_arms,)*
+                    _ => &[]
+                }
+            }
+            fn key_paths() -> &'static [&'static str] {
+                &[#(#key_paths,)*]
+            }
+            #snapshot_impl
+            fn direction(self) -> leptos_i18n::Direction {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#direction_match_arms,)*
+                    _ => leptos_i18n::Direction::of(Locale::as_str(self))
                 }
             }
         }
@@ -125,6 +532,7 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
 
 struct Subkeys<'a> {
     original_key: &'a syn::Ident,
+    original_name: &'a str,
     key: syn::Ident,
     mod_key: syn::Ident,
     locales: &'a [Locale],
@@ -134,10 +542,12 @@ struct Subkeys<'a> {
 impl<'a> Subkeys<'a> {
     pub fn new(key: &'a Key, locales: &'a [Locale], keys: &'a BuildersKeysInner) -> Self {
         let original_key = &key.ident;
+        let original_name = &key.name;
         let mod_key = format_ident!("sk_{}", key.ident);
         let key = format_ident!("{}_subkeys", key.ident);
         Subkeys {
             original_key,
+            original_name,
             key,
             mod_key,
             locales,
@@ -146,6 +556,53 @@ impl<'a> Subkeys<'a> {
     }
 }
 
+/// The values of the keys read at runtime instead of the compiled ones.
+#[derive(Clone, Copy)]
+struct RuntimeValues<'a> {
+    /// The locales other than the default one loaded at runtime, with `load_locales!(dynamic)`.
+    dynamic: Option<&'a DynamicAssets>,
+    /// The edited locale files read again in debug builds, with the `hot_reload` feature.
+    hot_reload: Option<&'a HotReload>,
+}
+
+/// Return the arms of `from_variant`, the keys of the locales other than the default one
+/// being the ones loaded at runtime if `dynamic`, or else the ones compiled in.
+///
+/// With `hot_reload`, the keys read again from the edited locale files are returned first in debug builds.
+fn from_variant_match_arms(
+    default_locale: &Key,
+    top_locales: &HashSet<&Key>,
+    dynamic: bool,
+    hot_reload: bool,
+) -> Vec<TokenStream> {
+    top_locales
+        .iter()
+        .map(|&locale| {
+            let keys = if dynamic && locale != default_locale {
+                quote! {
+                    leptos_i18n::__private::dynamic_keys::<Self>(Locale::#locale)
+                        .unwrap_or(&Self::#locale)
+                }
+            } else {
+                quote!(&Self::#locale)
+            };
+            if hot_reload {
+                quote! {
+                    Locale::#locale => {
+                        #[cfg(debug_assertions)]
+                        if let Some(_keys) = leptos_i18n::__private::hot_reload_keys::<Self>(Locale::#locale) {
+                            return _keys;
+                        }
+                        #keys
+                    }
+                }
+            } else {
+                quote!(Locale::#locale => #keys)
+            }
+        })
+        .collect()
+}
+
 fn get_default_match(
     default_locale: &Key,
     top_locales: &HashSet<&Key>,
@@ -159,6 +616,108 @@ fn get_default_match(
     quote!(Locale::#default_locale #(| Locale::#missing_keys)*)
 }
 
+fn create_get_string_fn(string_keys: &[&Rc<Key>], subkeys: &[Subkeys]) -> TokenStream {
+    let string_arms = string_keys.iter().map(|key| {
+        let name = &key.name;
+        quote!(#name => Some(self.#key))
+    });
+
+    let subkeys_arms = subkeys.iter().map(|sk| {
+        let name = sk.original_name;
+        let key = sk.original_key;
+        quote!(#name => self.#key.get_string(_rest))
+    });
+
+    quote! {
+        /// Return the value of the string key at the given path (`key.subkey`), if any.
+        pub fn get_string(&self, _key_path: &str) -> Option<&'static str> {
+            if let Some((_key, _rest)) = _key_path.split_once('.') {
+                match _key {
+                    #(#subkeys_arms,)*
+                    _ => None
+                }
+            } else {
+                match _key_path {
+                    #(#string_arms,)*
+                    _ => None
+                }
+            }
+        }
+    }
+}
+
+fn create_get_template_fn(builder_keys: &[&Rc<Key>], subkeys: &[Subkeys]) -> TokenStream {
+    let builder_arms = builder_keys.iter().map(|key| {
+        let name = &key.name;
+        quote!(#name => self.#key.get_template())
+    });
+
+    let subkeys_arms = subkeys.iter().map(|sk| {
+        let name = sk.original_name;
+        let key = sk.original_key;
+        quote!(#name => self.#key.get_template(_rest))
+    });
+
+    quote! {
+        /// Return the template of the interpolated key at the given path (`key.subkey`), if it does not contain plurals.
+        pub fn get_template(&self, _key_path: &str) -> Option<&'static [leptos_i18n::__private::TemplatePart]> {
+            if let Some((_key, _rest)) = _key_path.split_once('.') {
+                match _key {
+                    #(#subkeys_arms,)*
+                    _ => None
+                }
+            } else {
+                match _key_path {
+                    #(#builder_arms,)*
+                    _ => None
+                }
+            }
+        }
+    }
+}
+
+fn create_render_keys_fn(
+    string_keys: &[&Rc<Key>],
+    builder_keys: &[(&Rc<Key>, &HashSet<InterpolateKey>)],
+    subkeys: &[Subkeys],
+) -> TokenStream {
+    let strings = string_keys.iter().map(|key| {
+        let name = &key.name;
+        quote!(_views.push((format!("{}{}", _prefix, #name), leptos::IntoView::into_view(self.#key)));)
+    });
+
+    // the keys with a typed variable without placeholder are not rendered.
+    let builders = builder_keys.iter().filter_map(|(key, interpolations)| {
+        let name = &key.name;
+        let setters = interpolations
+            .iter()
+            .map(|interpolation| {
+                let placeholder = interpolation.get_placeholder()?;
+                Some(quote!(.#interpolation(#placeholder)))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(quote! {
+            _views.push((format!("{}{}", _prefix, #name), leptos::IntoView::into_view(self.#key #(#setters)*.build())));
+        })
+    });
+
+    let subkeys = subkeys.iter().map(|sk| {
+        let name = sk.original_name;
+        let key = sk.original_key;
+        quote!(self.#key.render_keys_into(&format!("{}{}.", _prefix, #name), _views);)
+    });
+
+    quote! {
+        /// Render every string and interpolated key with placeholder values, the path of the keys being prefixed by `_prefix`.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn render_keys_into(&self, _prefix: &str, _views: &mut Vec<(String, leptos::View)>) {
+            #(#strings)*
+            #(#builders)*
+            #(#subkeys)*
+        }
+    }
+}
+
 fn create_locale_type_inner(
     default_locale: &Key,
     type_ident: &syn::Ident,
@@ -166,7 +725,12 @@ fn create_locale_type_inner(
     locales: &[Locale],
     keys: &HashMap<Rc<Key>, LocaleValue>,
     is_namespace: bool,
+    runtime: RuntimeValues,
 ) -> TokenStream {
+    let RuntimeValues {
+        dynamic,
+        hot_reload,
+    } = runtime;
     let default_match = get_default_match(default_locale, top_locales, locales);
 
     let string_keys = keys
@@ -175,11 +739,87 @@ fn create_locale_type_inner(
         .map(|(key, _)| key)
         .collect::<Vec<_>>();
 
+    let key_docs = |name: &str| {
+        let Some(default_locale) = locales.first() else {
+            return KeyMetadata::to_attrs(None, vec![]);
+        };
+        let metadata = default_locale
+            .metadata
+            .iter()
+            .find_map(|(key, metadata)| (key.name == name).then_some(metadata));
+        let interpolations = keys.iter().find_map(|(key, value)| match value {
+            LocaleValue::Value(interpolations) if key.name == name => interpolations.as_ref(),
+            _ => None,
+        });
+        let value_lines = default_locale
+            .keys
+            .iter()
+            .find_map(|(key, value)| (key.name == name).then_some(value))
+            .map(|value| {
+                snapshot::value_doc_lines(&default_locale.top_locale_name, value, interpolations)
+            })
+            .unwrap_or_default();
+        KeyMetadata::to_attrs(metadata, value_lines)
+    };
+
     let string_fields = string_keys
         .iter()
-        .map(|key| quote!(pub #key: &'static str))
+        .map(|key| {
+            let docs = key_docs(&key.name);
+            quote!(#docs pub #key: &'static str)
+        })
         .collect::<Vec<_>>();
 
+    let list_keys = keys
+        .iter()
+        .filter(|(_, value)| matches!(value, LocaleValue::List))
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    let list_fields = list_keys
+        .iter()
+        .map(|key| {
+            let docs = key_docs(&key.name);
+            quote!(#docs pub #key: &'static [&'static str])
+        })
+        .collect::<Vec<_>>();
+
+    let object_list_keys = keys
+        .iter()
+        .filter_map(|(key, value)| match value {
+            LocaleValue::ObjectList(fields) => Some((key, fields)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let object_list_fields = object_list_keys
+        .iter()
+        .map(|(key, _)| {
+            let docs = key_docs(&key.name);
+            quote!(#docs pub #key: &'static [items::#key])
+        })
+        .collect::<Vec<_>>();
+
+    let items_module = object_list_keys.is_empty().not().then(|| {
+        let item_types = object_list_keys.iter().map(|(key, fields)| {
+            quote! {
+                #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+                #[allow(non_camel_case_types, non_snake_case)]
+                pub struct #key {
+                    #(pub #fields: &'static str,)*
+                }
+            }
+        });
+        quote! {
+            #[doc(hidden)]
+            pub mod items {
+                #(
+                    #item_types
+                )*
+            }
+        }
+    });
+
     let subkeys = keys
         .iter()
         .filter_map(|(key, value)| match value {
@@ -197,6 +837,7 @@ fn create_locale_type_inner(
             sk.locales,
             &sk.keys.0,
             true,
+            runtime,
         );
         quote! {
             pub mod #subkey_mod_ident {
@@ -211,7 +852,8 @@ fn create_locale_type_inner(
         let original_key = &sk.original_key;
         let key = &sk.key;
         let mod_ident = &sk.mod_key;
-        quote!(pub #original_key: subkeys::#mod_ident::#key)
+        let docs = key_docs(sk.original_name);
+        quote!(#docs pub #original_key: subkeys::#mod_ident::#key)
     });
 
     let subkeys_field_new = subkeys
@@ -240,7 +882,10 @@ fn create_locale_type_inner(
     let builders = keys
         .iter()
         .filter_map(|(key, value)| match value {
-            LocaleValue::Value(None) | LocaleValue::Subkeys { .. } => None,
+            LocaleValue::Value(None)
+            | LocaleValue::List
+            | LocaleValue::ObjectList(_)
+            | LocaleValue::Subkeys { .. } => None,
             LocaleValue::Value(Some(keys)) => {
                 Some((key, Interpolation::new(key, keys, locales, &default_match)))
             }
@@ -249,7 +894,8 @@ fn create_locale_type_inner(
 
     let builder_fields = builders.iter().map(|(key, inter)| {
         let inter_ident = &inter.default_generic_ident;
-        quote!(pub #key: builders::#inter_ident)
+        let docs = key_docs(&key.name);
+        quote!(#docs pub #key: builders::#inter_ident)
     });
 
     let init_builder_fields: Vec<TokenStream> = builders
@@ -260,22 +906,52 @@ fn create_locale_type_inner(
         })
         .collect();
 
+    let default_locale_key = default_locale;
     let default_locale = locales.first().unwrap();
 
     let new_match_arms = locales.iter().enumerate().map(|(i, locale)| {
-        let filled_string_fields =
-            string_keys
-                .iter()
-                .filter_map(|&key| match locale.keys.get(key) {
-                    Some(ParsedValue::String(str_value)) => Some(quote!(#key: #str_value)),
-                    _ => {
-                        let str_value = default_locale
-                            .keys
-                            .get(key)
-                            .and_then(ParsedValue::is_string)?;
-                        Some(quote!(#key: #str_value))
-                    }
-                });
+        // the values of the other locales are loaded at runtime, only the ones depending on features are compiled in.
+        let is_dynamic = dynamic.is_some() && i != 0;
+        let compiled_keys = (!is_dynamic).then_some(&locale.keys);
+        let filled_string_fields = string_keys.iter().filter_map(|&key| {
+            let str_value = locale
+                .keys
+                .get(key)
+                .filter(|value| !is_dynamic || matches!(value, ParsedValue::Features { .. }))
+                .and_then(ParsedValue::as_str_tokens)
+                .or_else(|| {
+                    default_locale
+                        .keys
+                        .get(key)
+                        .and_then(ParsedValue::as_str_tokens)
+                })?;
+            Some(quote!(#key: #str_value))
+        });
+
+        let filled_list_fields = list_keys.iter().filter_map(|&key| {
+            let items = compiled_keys
+                .and_then(|keys| keys.get(key))
+                .and_then(ParsedValue::is_list)
+                .or_else(|| default_locale.keys.get(key).and_then(ParsedValue::is_list))?;
+            Some(quote!(#key: &[#(#items,)*]))
+        });
+
+        let filled_object_list_fields = object_list_keys.iter().filter_map(|&(key, fields)| {
+            let list = compiled_keys
+                .and_then(|keys| keys.get(key))
+                .and_then(ParsedValue::is_object_list)
+                .or_else(|| {
+                    default_locale
+                        .keys
+                        .get(key)
+                        .and_then(ParsedValue::is_object_list)
+                })?;
+            let items = list.items.iter().map(|item| {
+                let values = fields.iter().map(|field| item.get(field));
+                quote!(items::#key { #(#fields: #values,)* })
+            });
+            Some(quote!(#key: &[#(#items,)*]))
+        });
 
         let ident = &locale.top_locale_name;
         let pattern = (i != 0).then(|| quote!(Locale::#ident));
@@ -283,12 +959,71 @@ fn create_locale_type_inner(
         quote! {
             #pattern => #type_ident {
                 #(#filled_string_fields,)*
+                #(#filled_list_fields,)*
+                #(#filled_object_list_fields,)*
                 #(#init_builder_fields,)*
                 #(#subkeys_field_new,)*
             }
         }
     });
 
+    let from_dynamic_fn = (dynamic.is_some() || hot_reload.is_some()).then(|| {
+        let strings = string_keys.iter().map(|key| {
+            let name = &key.name;
+            quote!(if let Some(_value) = _values.string(_prefix, #name) { _keys.#key = _value; })
+        });
+        let lists = list_keys.iter().map(|key| {
+            let name = &key.name;
+            quote!(if let Some(_value) = _values.list(_prefix, #name) { _keys.#key = _value; })
+        });
+        let object_lists = object_list_keys.iter().map(|(key, fields)| {
+            let name = &key.name;
+            let field_names = fields.iter().map(|field| &field.name);
+            quote! {
+                let _value = _values.object_list(_prefix, #name, |_field| items::#key { #(#fields: _field(#field_names),)* });
+                if let Some(_value) = _value {
+                    _keys.#key = _value;
+                }
+            }
+        });
+        let subkeys = subkeys.iter().map(|sk| {
+            let name = sk.original_name;
+            let original_key = sk.original_key;
+            let key = &sk.key;
+            let mod_ident = &sk.mod_key;
+            quote! {
+                _keys.#original_key = subkeys::#mod_ident::#key::from_dynamic(_variant, _values, &format!("{}{}.", _prefix, #name));
+            }
+        });
+        quote! {
+            /// Create the keys of the locale with the values loaded at runtime, the keys under `_prefix`
+            /// missing in the values keeping the value of the default locale.
+            pub fn from_dynamic(_variant: Locale, _values: &leptos_i18n::__private::DynamicValues, _prefix: &str) -> Self {
+                #[allow(unused_mut)]
+                let mut _keys = Self::new(_variant);
+                #(#strings)*
+                #(#lists)*
+                #(#object_lists)*
+                #(#subkeys)*
+                _keys
+            }
+        }
+    });
+
+    let get_string_fn = create_get_string_fn(&string_keys, &subkeys);
+
+    let builder_keys = builders.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    let get_template_fn = create_get_template_fn(&builder_keys, &subkeys);
+
+    let builder_interpolations = builder_keys
+        .iter()
+        .filter_map(|&key| match keys.get(key) {
+            Some(LocaleValue::Value(Some(interpolations))) => Some((key, interpolations)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let render_keys_fn = create_render_keys_fn(&string_keys, &builder_interpolations, &subkeys);
+
     let builder_impls = builders.iter().map(|(_, inter)| &inter.imp);
 
     let builder_module = builders.is_empty().not().then(move || {
@@ -308,9 +1043,16 @@ fn create_locale_type_inner(
     });
 
     let (from_variant, const_values) = if !is_namespace {
-        let from_variant_match_arms = top_locales
-            .iter()
-            .map(|locale| quote!(Locale::#locale => &Self::#locale));
+        let from_variant_match_arms = from_variant_match_arms(
+            default_locale_key,
+            top_locales,
+            dynamic.is_some(),
+            hot_reload.is_some(),
+        );
+        let dynamic_keys_impl =
+            dynamic.map(|assets| dynamic::create_dynamic_keys_impl(type_ident, &[], assets));
+        let hot_reload_keys_impl = hot_reload
+            .map(|hot_reload| hot_reload::create_hot_reload_keys_impl(type_ident, &[], hot_reload));
 
         let from_variant = quote! {
             impl leptos_i18n::LocaleKeys for #type_ident {
@@ -322,7 +1064,23 @@ fn create_locale_type_inner(
                         )*
                     }
                 }
+                fn get_string(&self, key_path: &str) -> Option<&'static str> {
+                    Self::get_string(self, key_path)
+                }
+                fn get_template(&self, key_path: &str) -> Option<&'static [leptos_i18n::__private::TemplatePart]> {
+                    Self::get_template(self, key_path)
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                fn render_keys(&self) -> Vec<(String, leptos::View)> {
+                    let mut _views = vec![];
+                    self.render_keys_into("", &mut _views);
+                    _views
+                }
             }
+
+            #dynamic_keys_impl
+
+            #hot_reload_keys_impl
         };
 
         let const_values = top_locales
@@ -346,10 +1104,13 @@ fn create_locale_type_inner(
         #[allow(non_camel_case_types, non_snake_case)]
         pub struct #type_ident {
             #(#string_fields,)*
+            #(#list_fields,)*
+            #(#object_list_fields,)*
             #(#builder_fields,)*
             #(#subkeys_fields,)*
         }
 
+        #[allow(deprecated)]
         impl #type_ident {
 
             #const_values
@@ -361,12 +1122,22 @@ fn create_locale_type_inner(
                     )*
                 }
             }
+
+            #from_dynamic_fn
+
+            #get_string_fn
+
+            #get_template_fn
+
+            #render_keys_fn
         }
 
         #from_variant
 
         #builder_module
 
+        #items_module
+
         #subkeys_module
     }
 }
@@ -381,7 +1152,13 @@ fn create_namespaces_types(
     namespaces: &[Namespace],
     top_locales: &HashSet<&Key>,
     keys: &HashMap<Rc<Key>, BuildersKeysInner>,
+    is_shard: bool,
+    runtime: RuntimeValues,
 ) -> TokenStream {
+    let RuntimeValues {
+        dynamic,
+        hot_reload,
+    } = runtime;
     let namespaces_ts = namespaces.iter().map(|namespace| {
         let namespace_ident = &namespace.key.ident;
         let namespace_module_ident = create_namespace_mod_ident(namespace_ident);
@@ -393,6 +1170,7 @@ fn create_namespaces_types(
             &namespace.locales,
             &keys.0,
             true,
+            runtime,
         );
         quote! {
             pub mod #namespace_module_ident {
@@ -415,18 +1193,107 @@ fn create_namespaces_types(
         quote!(#key: namespaces::#namespace_module_ident::#key::new(_variant))
     });
 
-    let locales = &namespaces.iter().next().unwrap().locales;
+    let get_string_match_arms = namespaces.iter().map(|namespace| {
+        let key = &namespace.key;
+        let name = &key.name;
+        quote!(#name => self.#key.get_string(_rest))
+    });
 
-    let const_values = locales.iter().map(|locale| {
-        let locale_ident = &locale.name;
-        quote!(pub const #locale_ident: Self = Self::new(Locale::#locale_ident);)
+    let get_template_match_arms = namespaces.iter().map(|namespace| {
+        let key = &namespace.key;
+        let name = &key.name;
+        quote!(#name => self.#key.get_template(_rest))
     });
 
-    let from_variant_match_arms = locales.iter().map(|locale| {
-        let locale_ident = &locale.name;
-        quote!(Locale::#locale_ident => &Self::#locale_ident)
+    let render_keys_calls = namespaces.iter().map(|namespace| {
+        let key = &namespace.key;
+        let name = &key.name;
+        quote!(self.#key.render_keys_into(&format!("{}{}::", _prefix, #name), _views);)
+    });
+
+    let const_values = top_locales
+        .iter()
+        .map(|locale| quote!(pub const #locale: Self = Self::new(Locale::#locale);));
+
+    let from_variant_match_arms = from_variant_match_arms(
+        default_locale,
+        top_locales,
+        dynamic.is_some(),
+        hot_reload.is_some(),
+    );
+
+    let from_dynamic_fn = (dynamic.is_some() || hot_reload.is_some()).then(|| {
+        let namespaces_fields = namespaces.iter().map(|namespace| {
+            let key = &namespace.key;
+            let name = &key.name;
+            let namespace_module_ident = create_namespace_mod_ident(&key.ident);
+            quote! {
+                #key: namespaces::#namespace_module_ident::#key::from_dynamic(_variant, _values, &format!("{}{}::", _prefix, #name))
+            }
+        });
+        quote! {
+            /// Create the keys of the locale with the values loaded at runtime, the keys missing in the values
+            /// keeping the value of the default locale.
+            pub fn from_dynamic(_variant: Locale, _values: &leptos_i18n::__private::DynamicValues, _prefix: &str) -> Self {
+                Self {
+                    #(#namespaces_fields,)*
+                }
+            }
+        }
     });
 
+    let namespace_keys = namespaces
+        .iter()
+        .map(|namespace| &*namespace.key)
+        .collect::<Vec<_>>();
+
+    let dynamic_keys_impl = dynamic
+        .map(|assets| dynamic::create_dynamic_keys_impl(i18n_keys_ident, &namespace_keys, assets));
+
+    let hot_reload_keys_impl = hot_reload.map(|hot_reload| {
+        hot_reload::create_hot_reload_keys_impl(i18n_keys_ident, &namespace_keys, hot_reload)
+    });
+
+    let keys_impl = if is_shard {
+        quote! {
+            impl leptos_i18n::ShardKeys for #i18n_keys_ident {
+                type Locale = Locale;
+                fn from_variant(_variant: Locale) -> &'static Self {
+                    match _variant {
+                        #(
+                            #from_variant_match_arms,
+                        )*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl leptos_i18n::LocaleKeys for #i18n_keys_ident {
+                type Locale = Locale;
+                fn from_variant(_variant: Locale) -> &'static Self {
+                    match _variant {
+                        #(
+                            #from_variant_match_arms,
+                        )*
+                    }
+                }
+                fn get_string(&self, key_path: &str) -> Option<&'static str> {
+                    Self::get_string(self, key_path)
+                }
+                fn get_template(&self, key_path: &str) -> Option<&'static [leptos_i18n::__private::TemplatePart]> {
+                    Self::get_template(self, key_path)
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                fn render_keys(&self) -> Vec<(String, leptos::View)> {
+                    let mut _views = vec![];
+                    self.render_keys_into("", &mut _views);
+                    _views
+                }
+            }
+        }
+    };
+
     quote! {
         pub mod namespaces {
             use super::Locale;
@@ -456,22 +1323,48 @@ fn create_namespaces_types(
                     )*
                 }
             }
-        }
 
-        impl leptos_i18n::LocaleKeys for #i18n_keys_ident {
-            type Locale = Locale;
-            fn from_variant(_variant: Locale) -> &'static Self {
-                match _variant {
-                    #(
-                        #from_variant_match_arms,
-                    )*
+            #from_dynamic_fn
+
+            /// Return the value of the string key at the given path (`namespace::key.subkey`), if any.
+            pub fn get_string(&self, _key_path: &str) -> Option<&'static str> {
+                let (_namespace, _rest) = _key_path.split_once("::")?;
+                match _namespace {
+                    #(#get_string_match_arms,)*
+                    _ => None
                 }
             }
+
+            /// Return the template of the interpolated key at the given path (`namespace::key.subkey`), if it does not contain plurals.
+            pub fn get_template(&self, _key_path: &str) -> Option<&'static [leptos_i18n::__private::TemplatePart]> {
+                let (_namespace, _rest) = _key_path.split_once("::")?;
+                match _namespace {
+                    #(#get_template_match_arms,)*
+                    _ => None
+                }
+            }
+
+            /// Render every string and interpolated key with placeholder values, the path of the keys being prefixed by `_prefix`.
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn render_keys_into(&self, _prefix: &str, _views: &mut Vec<(String, leptos::View)>) {
+                #(#render_keys_calls)*
+            }
         }
+
+        #keys_impl
+
+        #dynamic_keys_impl
+
+        #hot_reload_keys_impl
     }
 }
 
-fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile) -> TokenStream {
+fn create_locale_type(
+    keys: BuildersKeys,
+    cfg_file: &ConfigFile,
+    is_shard: bool,
+    runtime: RuntimeValues,
+) -> TokenStream {
     let top_locales = cfg_file.locales.iter().map(Deref::deref).collect();
     let default_locale = cfg_file.default.as_ref();
 
@@ -483,6 +1376,8 @@ fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile) -> TokenStream
             namespaces,
             &top_locales,
             &keys,
+            is_shard,
+            runtime,
         ),
         BuildersKeys::Locales { locales, keys } => create_locale_type_inner(
             default_locale,
@@ -491,6 +1386,7 @@ fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile) -> TokenStream
             locales,
             &keys.0,
             false,
+            runtime,
         ),
     }
 }
@@ -1,18 +1,34 @@
-use std::{cell::RefCell, collections::HashSet, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use serde::de::{value::MapAccessDeserializer, DeserializeSeed};
 
 use super::{
+    cfg_file::{MissingKeyPolicy, ValueOptions},
     error::{Error, Result},
+    formatter::{Formatter, FormatterKind},
     key::{Key, KeyPath},
     locale::{Locale, LocaleSeed, LocaleValue, LocalesOrNamespaces},
-    plural::{PluralType, Plurals},
+    plural::{ObjectList, PluralType, Plurals, PluralsOrList},
+    warning::{emit_warning, Warning},
 };
 
 thread_local! {
     pub static FOREIGN_KEYS: RefCell<HashSet<(Rc<Key>, KeyPath)>> = RefCell::new(HashSet::new());
+    pub static CONSTANTS: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    /// Path the foreign keys without a namespace are relative to, the prefix of the locale package being read.
+    pub static FOREIGN_KEYS_ROOT: RefCell<Option<KeyPath>> = const { RefCell::new(None) };
+}
+
+/// Return the value of a build-time constant, either declared in the configuration or an environment variable.
+fn get_constant(name: &str) -> Option<String> {
+    CONSTANTS
+        .with(|constants| constants.borrow().get(name).cloned())
+        .or_else(|| std::env::var(name).ok())
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,29 +45,66 @@ pub enum ParsedValue {
     Plural(Plurals),
     String(String),
     Variable(Rc<Key>),
+    /// Variable with a declared type: `{{ count: u32 }}`, the type being written as tokens.
+    TypedVariable {
+        key: Rc<Key>,
+        ty: String,
+    },
+    /// Variable formatted for the locale of the value: `{{ count, number }}`.
+    Formatted {
+        key: Rc<Key>,
+        formatter: Formatter,
+        locale: Rc<Key>,
+    },
     Component {
         key: Rc<Key>,
         inner: Box<Self>,
     },
     Bloc(Vec<Self>),
     Subkeys(Locale),
+    List(Vec<String>),
+    ObjectList(ObjectList),
+    /// Value selected at build time by the enabled cargo features, in order, with a default.
+    Features {
+        variants: Vec<(String, Self)>,
+        default: Box<Self>,
+    },
+    /// Value selected at render time by the variant of a variable, in order, with a value for the other variants.
+    Select {
+        key: Rc<Key>,
+        variants: Vec<(String, Self)>,
+        other: Box<Self>,
+    },
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum InterpolateKey {
     Count(PluralType),
     Variable(Rc<Key>),
+    TypedVariable(Rc<Key>, String),
+    Formatted(Rc<Key>, FormatterKind),
     Component(Rc<Key>),
+    Select(Rc<Key>),
 }
 
 impl ParsedValue {
+    /// Key of the map declaring values selected by feature.
+    pub const FEATURES_KEY: &'static str = "@@features";
+    /// Key of the default value of the values selected by feature.
+    pub const FEATURES_DEFAULT: &'static str = "_";
+    /// Key of the map declaring values selected by the variant of a variable, its value being the name of the variable.
+    pub const SELECT_KEY: &'static str = "@@select";
+    /// Key of the value selected by the variants not declared.
+    pub const SELECT_OTHER: &'static str = "other";
+
     pub fn resolve_foreign_keys(
         values: &LocalesOrNamespaces,
         default_locale: &Rc<Key>,
     ) -> Result<()> {
+        // the set is taken as the thread is reused for the other `load_locales!` invocations of the crate.
         FOREIGN_KEYS.with(|foreign_keys| {
-            let set = foreign_keys.borrow();
-            for (locale, value_path) in &*set {
+            let set = foreign_keys.take();
+            for (locale, value_path) in &set {
                 let value = values.get_value_at(locale, value_path).unwrap();
                 value.resolve_foreign_key(values, locale, default_locale, value_path)?;
             }
@@ -95,6 +148,12 @@ impl ParsedValue {
                     path, top_locale
                 )))
             }
+            ParsedValue::List(_) | ParsedValue::ObjectList(_) => {
+                return Err(Error::Custom(format!(
+                    "foreign key to lists is not supported, at key {} in locale {:?}",
+                    path, top_locale
+                )))
+            }
             _ => {}
         }
 
@@ -114,7 +173,13 @@ impl ParsedValue {
         path: &KeyPath,
     ) -> Result<()> {
         match self {
-            ParsedValue::Variable(_) | ParsedValue::String(_) | ParsedValue::Default => Ok(()),
+            ParsedValue::Variable(_)
+            | ParsedValue::TypedVariable { .. }
+            | ParsedValue::Formatted { .. }
+            | ParsedValue::String(_)
+            | ParsedValue::Default
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_) => Ok(()),
             ParsedValue::Subkeys(_) => Ok(()), // unreachable ?
             ParsedValue::Plural(inner) => {
                 inner.resolve_foreign_keys(values, top_locale, default_locale, path)
@@ -128,6 +193,20 @@ impl ParsedValue {
                 }
                 Ok(())
             }
+            ParsedValue::Features { variants, default } => {
+                for (_, value) in variants {
+                    value.resolve_foreign_key(values, top_locale, default_locale, path)?;
+                }
+                default.resolve_foreign_key(values, top_locale, default_locale, path)
+            }
+            ParsedValue::Select {
+                variants, other, ..
+            } => {
+                for (_, value) in variants {
+                    value.resolve_foreign_key(values, top_locale, default_locale, path)?;
+                }
+                other.resolve_foreign_key(values, top_locale, default_locale, path)
+            }
             ParsedValue::ForeignKey(foreign_key) => {
                 let Ok(mut foreign_key) = foreign_key.try_borrow_mut() else {
                     return Err(Error::RecursiveForeignKey {
@@ -149,11 +228,23 @@ impl ParsedValue {
 
     pub fn get_keys_inner(&self, keys: &mut Option<HashSet<InterpolateKey>>) {
         match self {
-            ParsedValue::String(_) | ParsedValue::Subkeys(_) | ParsedValue::Default => {}
+            ParsedValue::String(_)
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::Default
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_) => {}
             ParsedValue::Variable(key) => {
                 keys.get_or_insert_with(HashSet::new)
                     .insert(InterpolateKey::Variable(Rc::clone(key)));
             }
+            ParsedValue::TypedVariable { key, ty } => {
+                keys.get_or_insert_with(HashSet::new)
+                    .insert(InterpolateKey::TypedVariable(Rc::clone(key), ty.clone()));
+            }
+            ParsedValue::Formatted { key, formatter, .. } => {
+                keys.get_or_insert_with(HashSet::new)
+                    .insert(InterpolateKey::Formatted(Rc::clone(key), formatter.kind()));
+            }
             ParsedValue::Component { key, inner } => {
                 keys.get_or_insert_with(HashSet::new)
                     .insert(InterpolateKey::Component(Rc::clone(key)));
@@ -164,6 +255,24 @@ impl ParsedValue {
                     value.get_keys_inner(keys)
                 }
             }
+            ParsedValue::Features { variants, default } => {
+                for (_, value) in variants {
+                    value.get_keys_inner(keys)
+                }
+                default.get_keys_inner(keys)
+            }
+            ParsedValue::Select {
+                key,
+                variants,
+                other,
+            } => {
+                keys.get_or_insert_with(HashSet::new)
+                    .insert(InterpolateKey::Select(Rc::clone(key)));
+                for (_, value) in variants {
+                    value.get_keys_inner(keys)
+                }
+                other.get_keys_inner(keys)
+            }
             ParsedValue::Plural(plurals) => {
                 plurals.get_keys_inner(keys);
                 let plural_type = plurals.get_type();
@@ -183,29 +292,201 @@ impl ParsedValue {
         keys
     }
 
-    pub fn is_string(&self) -> Option<&str> {
+    fn as_template_inner(&self, parts: &mut Vec<TokenStream>) -> Option<()> {
+        match self {
+            ParsedValue::String(s) if s.is_empty() => {}
+            ParsedValue::String(s) => {
+                parts.push(quote!(leptos_i18n::__private::TemplatePart::Str(#s)))
+            }
+            // the arguments of the template are already formatted.
+            ParsedValue::Variable(key)
+            | ParsedValue::TypedVariable { key, .. }
+            | ParsedValue::Formatted { key, .. } => {
+                let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                parts.push(quote!(leptos_i18n::__private::TemplatePart::Var(#name)))
+            }
+            ParsedValue::Bloc(values) => {
+                for value in values {
+                    value.as_template_inner(parts)?;
+                }
+            }
+            ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
+                ForeignKey::Set(inner) => inner.as_template_inner(parts)?,
+                ForeignKey::NotSet(_) => unreachable!(),
+            },
+            ParsedValue::Component { key, inner } => {
+                let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+                let mut inner_parts = vec![];
+                inner.as_template_inner(&mut inner_parts)?;
+                parts.push(
+                    quote!(leptos_i18n::__private::TemplatePart::Comp(#name, &[#(#inner_parts,)*])),
+                )
+            }
+            ParsedValue::Default
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::Plural(_)
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_)
+            | ParsedValue::Features { .. }
+            | ParsedValue::Select { .. } => return None,
+        }
+        Some(())
+    }
+
+    /// Return the parts of a value only made of strings, variables and components, used to render it to a `String` at runtime.
+    pub fn as_template(&self) -> Option<TokenStream> {
+        if let ParsedValue::Features { variants, default } = self {
+            let variants = variants
+                .iter()
+                .map(|(feature, value)| {
+                    let template = value.as_template()?;
+                    Some((
+                        feature.as_str(),
+                        quote!(#template as &[leptos_i18n::__private::TemplatePart]),
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let default = default.as_template()?;
+            return Some(select_by_feature(
+                &variants,
+                quote!(#default as &[leptos_i18n::__private::TemplatePart]),
+            ));
+        }
+        let mut parts = vec![];
+        self.as_template_inner(&mut parts)?;
+        Some(quote!(&[#(#parts,)*]))
+    }
+
+    /// Return the statements writing the value to `__formatter`, used to implement `Display` for the interpolations.
+    ///
+    /// `count_fn` is `true` when the value has plurals, `count` is then the function returning the count.
+    /// Components are written as their children, see `leptos_i18n::__private::display_component`.
+    pub fn to_display_tokens(&self, count_fn: bool) -> TokenStream {
+        match self {
+            ParsedValue::Default
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_) => quote!(),
+            ParsedValue::String(s) if s.is_empty() => quote!(),
+            ParsedValue::String(s) => quote!(__formatter.write_str(#s)?;),
+            ParsedValue::Variable(key) if count_fn && key.name == "var_count" => {
+                quote!(core::fmt::Display::fmt(&var_count(), __formatter)?;)
+            }
+            ParsedValue::Variable(key) => quote!(core::fmt::Display::fmt(#key, __formatter)?;),
+            ParsedValue::TypedVariable { key, .. } => {
+                quote!(core::fmt::Display::fmt(&#key(), __formatter)?;)
+            }
+            ParsedValue::Formatted {
+                key,
+                formatter,
+                locale,
+            } => {
+                let formatted = formatter.format_tokens(quote!(#key), locale);
+                quote!(__formatter.write_str(&#formatted)?;)
+            }
+            ParsedValue::Component { key, inner } => {
+                let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+                let inner = inner.to_display_tokens(count_fn);
+                quote! {
+                    leptos_i18n::__private::display_component(__formatter, #name, |__formatter| {
+                        #inner
+                        Ok(())
+                    })?;
+                }
+            }
+            ParsedValue::Plural(plurals) => plurals.to_display_tokens(),
+            ParsedValue::Bloc(values) => values
+                .iter()
+                .map(|value| value.to_display_tokens(count_fn))
+                .collect(),
+            ParsedValue::Features { variants, default } => {
+                let variants = variants
+                    .iter()
+                    .map(|(feature, value)| {
+                        let statements = value.to_display_tokens(count_fn);
+                        (feature.as_str(), quote!(#statements))
+                    })
+                    .collect::<Vec<_>>();
+                let default = default.to_display_tokens(count_fn);
+                select_by_feature(&variants, default)
+            }
+            ParsedValue::Select {
+                key,
+                variants,
+                other,
+            } => {
+                let values = variants
+                    .iter()
+                    .map(|(_, value)| value.to_display_tokens(count_fn));
+                let other = other.to_display_tokens(count_fn);
+                select_by_variant(key, variants, values, other)
+            }
+            ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
+                ForeignKey::Set(inner) => inner.to_display_tokens(count_fn),
+                ForeignKey::NotSet(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// Set the locale owning the subkeys of this value, used when the value is copied to another locale.
+    pub fn set_top_locale_name(&mut self, top_locale_name: &Rc<Key>) {
+        if let ParsedValue::Subkeys(subkeys) = self {
+            subkeys.top_locale_name = Rc::clone(top_locale_name);
+            for value in subkeys.keys.values_mut() {
+                value.set_top_locale_name(top_locale_name);
+            }
+        }
+    }
+
+    pub fn is_list(&self) -> Option<&[String]> {
+        match self {
+            ParsedValue::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn is_object_list(&self) -> Option<&ObjectList> {
+        match self {
+            ParsedValue::ObjectList(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Return the `&'static str` expression of a value only made of strings, if it is one.
+    pub fn as_str_tokens(&self) -> Option<TokenStream> {
         match self {
-            ParsedValue::String(value) => Some(value),
+            ParsedValue::String(value) => Some(quote!(#value)),
+            ParsedValue::Features { variants, default } => {
+                let variants = variants
+                    .iter()
+                    .map(|(feature, value)| Some((feature.as_str(), value.as_str_tokens()?)))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(select_by_feature(&variants, default.as_str_tokens()?))
+            }
             _ => None,
         }
     }
 
-    pub fn new(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Self {
+    pub fn new(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Result<Self> {
         // look for component
-        if let Some(component) = Self::find_component(value, key_path, locale) {
-            return component;
+        if let Some(component) = Self::find_component(value, key_path, locale)? {
+            return Ok(component);
         }
         // else look for variables
-        if let Some(variable) = Self::find_variable(value, key_path, locale) {
-            return variable;
+        if let Some(variable) = Self::find_variable(value, key_path, locale)? {
+            return Ok(variable);
         }
 
         // else it's just a string
-        ParsedValue::String(value.to_string())
+        Ok(ParsedValue::String(value.to_string()))
     }
 
     pub fn make_locale_value(&mut self) -> LocaleValue {
-        if let ParsedValue::Subkeys(_) = self {
+        if let ParsedValue::List(_) = self {
+            LocaleValue::List
+        } else if let ParsedValue::ObjectList(list) = self {
+            LocaleValue::ObjectList(list.fields.clone())
+        } else if let ParsedValue::Subkeys(_) = self {
             let ParsedValue::Subkeys(mut locale) = core::mem::take(self) else {
                 unreachable!();
             };
@@ -228,6 +509,79 @@ impl ParsedValue {
         let Some(keys) = keys else {
             return Ok(());
         };
+
+        // a variable selecting a value can't be interpolated too, nor be the count of plurals.
+        for key in keys.iter() {
+            let InterpolateKey::Select(variable) = key else {
+                continue;
+            };
+            if keys
+                .iter()
+                .any(|other| other != key && other.as_ident() == variable.ident)
+            {
+                return Err(Error::SelectMissmatch {
+                    locale: top_locale,
+                    key_path: std::mem::take(key_path),
+                    variable: variable
+                        .name
+                        .strip_prefix("var_")
+                        .unwrap_or(&variable.name)
+                        .to_string(),
+                });
+            }
+        }
+
+        // a typed variable must have the same type everywhere, the count of plurals having the type of the plurals.
+        for key in keys.iter() {
+            let InterpolateKey::TypedVariable(variable, ty) = key else {
+                continue;
+            };
+            let conflict = keys.iter().any(|other| match other {
+                InterpolateKey::Count(plural_type) => {
+                    variable.name == "var_count" && quote!(#plural_type).to_string() != *ty
+                }
+                other => other != key && other.as_ident() == variable.ident,
+            });
+            if conflict {
+                return Err(Error::VariableTypeMissmatch {
+                    locale: top_locale,
+                    key_path: std::mem::take(key_path),
+                    variable: variable
+                        .name
+                        .strip_prefix("var_")
+                        .unwrap_or(&variable.name)
+                        .to_string(),
+                });
+            }
+        }
+
+        // a variable must be formatted the same way everywhere, the count of plurals being a number.
+        let mut variables = HashSet::new();
+        let has_count = keys
+            .iter()
+            .any(|key| matches!(key, InterpolateKey::Count(_)));
+        for key in keys.iter() {
+            let (variable, kind) = match key {
+                InterpolateKey::Variable(variable) => (variable, None),
+                InterpolateKey::Formatted(variable, kind) => (variable, Some(*kind)),
+                _ => continue,
+            };
+            let is_count = has_count && variable.name == "var_count";
+            if (is_count && !matches!(kind, None | Some(FormatterKind::Number)))
+                || (!is_count && !variables.insert(variable))
+            {
+                return Err(Error::FormatterMissmatch {
+                    locale: top_locale,
+                    key_path: std::mem::take(key_path),
+                    variable: variable
+                        .name
+                        .strip_prefix("var_")
+                        .unwrap_or(&variable.name)
+                        .to_string(),
+                });
+            }
+        }
+
         let mut iter = keys.iter();
         let Some(count_type) = iter.find_map(|key| match key {
             InterpolateKey::Count(plural_type) => Some(*plural_type),
@@ -252,7 +606,9 @@ impl ParsedValue {
 
         // if the set contains InterpolateKey::Count, remove variable keys with name "count"
         // ("var_count" with the rename)
-        keys.retain(|key| !matches!(key, InterpolateKey::Variable(key) if key.name == "var_count"));
+        keys.retain(|key| {
+            !matches!(key, InterpolateKey::Variable(key) | InterpolateKey::TypedVariable(key, _) | InterpolateKey::Formatted(key, _) if key.name == "var_count")
+        });
 
         Ok(())
     }
@@ -263,6 +619,7 @@ impl ParsedValue {
         default_locale: &str,
         top_locale: Rc<Key>,
         key_path: &mut KeyPath,
+        policy: MissingKeyPolicy,
     ) -> Result<()> {
         self.reduce();
         match (&self, keys) {
@@ -273,7 +630,7 @@ impl ParsedValue {
                 let ParsedValue::Subkeys(mut loc) = core::mem::take(self) else {
                     unreachable!();
                 };
-                loc.merge(keys, default_locale, top_locale, key_path)?;
+                loc.merge(keys, default_locale, top_locale, key_path, policy)?;
                 locales.push(loc);
                 Ok(())
             }
@@ -284,9 +641,36 @@ impl ParsedValue {
                 | ParsedValue::Plural(_)
                 | ParsedValue::String(_)
                 | ParsedValue::Variable(_)
-                | ParsedValue::ForeignKey(_),
+                | ParsedValue::TypedVariable { .. }
+                | ParsedValue::Formatted { .. }
+                | ParsedValue::ForeignKey(_)
+                | ParsedValue::Features { .. }
+                | ParsedValue::Select { .. },
                 LocaleValue::Value(keys),
             ) => self.merge_inner(keys, top_locale, key_path),
+            // Both list
+            (ParsedValue::List(_), LocaleValue::List) => Ok(()),
+            // Both list of objects
+            (ParsedValue::ObjectList(list), LocaleValue::ObjectList(fields)) => {
+                if list.has_fields(fields.iter()) {
+                    Ok(())
+                } else {
+                    Err(Error::ObjectListFieldsMissmatch {
+                        locale: top_locale,
+                        key_path: std::mem::take(key_path),
+                    })
+                }
+            }
+            (ParsedValue::ObjectList(_), _) | (_, LocaleValue::ObjectList(_)) => {
+                Err(Error::ObjectListMissmatch {
+                    locale: top_locale,
+                    key_path: std::mem::take(key_path),
+                })
+            }
+            (ParsedValue::List(_), _) | (_, LocaleValue::List) => Err(Error::ListMissmatch {
+                locale: top_locale,
+                key_path: std::mem::take(key_path),
+            }),
             // not compatible
             _ => Err(Error::SubKeyMissmatch {
                 locale: top_locale,
@@ -295,7 +679,7 @@ impl ParsedValue {
         }
     }
 
-    fn parse_key_path(path: &str) -> Option<KeyPath> {
+    pub fn parse_key_path(path: &str) -> Option<KeyPath> {
         let (mut key_path, path) = if let Some((namespace, rest)) = path.split_once("::") {
             let namespace = Key::new(namespace)?;
 
@@ -312,22 +696,35 @@ impl ParsedValue {
         Some(key_path)
     }
 
-    fn find_variable(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Option<Self> {
-        let (before, rest) = value.split_once("{{")?;
-        let (ident, after) = rest.split_once("}}")?;
+    fn find_variable(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Result<Option<Self>> {
+        let Some((before, rest)) = value.split_once("{{") else {
+            return Ok(None);
+        };
+        let Some((ident, after)) = rest.split_once("}}") else {
+            return Ok(None);
+        };
 
         let ident = ident.trim();
 
-        let first_char = ident.chars().next()?;
-
-        let before = Self::new(before, key_path, locale);
-        let after = Self::new(after, key_path, locale);
+        let Some(first_char) = ident.chars().next() else {
+            return Ok(None);
+        };
 
         let this = match first_char {
             // foreign key
             '@' => {
-                let path = ident.strip_prefix('@')?;
-                let foreign_key_path = Self::parse_key_path(path)?;
+                let Some(mut foreign_key_path) = Self::parse_key_path(&ident[1..]) else {
+                    return Ok(None);
+                };
+                FOREIGN_KEYS_ROOT.with(|root| match &*root.borrow() {
+                    Some(root) if foreign_key_path.namespace.is_none() => {
+                        foreign_key_path.namespace = root.namespace.clone();
+                        foreign_key_path
+                            .path
+                            .splice(0..0, root.path.iter().cloned());
+                    }
+                    _ => {}
+                });
                 FOREIGN_KEYS.with(|foreign_keys| {
                     foreign_keys
                         .borrow_mut()
@@ -335,14 +732,68 @@ impl ParsedValue {
                 });
                 ParsedValue::ForeignKey(RefCell::new(ForeignKey::NotSet(foreign_key_path)))
             }
+            // build-time constant
+            '$' => {
+                let name = ident[1..].trim();
+                get_constant(name)
+                    .map(ParsedValue::String)
+                    .unwrap_or_else(|| {
+                        emit_warning(Warning::UnknownConstant {
+                            locale: Rc::clone(locale),
+                            key_path: key_path.clone(),
+                            name: name.to_string(),
+                        });
+                        ParsedValue::String(format!("{{{{{}}}}}", rest.split_once("}}").unwrap().0))
+                    })
+            }
+            // typed variable
+            _ if ident.contains(':') => {
+                let (name, ty) = ident.split_once(':').unwrap();
+                let Some(key) = Key::new(&format!("var_{}", name)) else {
+                    return Ok(None);
+                };
+                let ty =
+                    syn::parse_str::<syn::Type>(ty).map_err(|_| Error::InvalidVariableType {
+                        locale: Rc::clone(locale),
+                        key_path: key_path.clone(),
+                        ty: ty.trim().to_string(),
+                    })?;
+                ParsedValue::TypedVariable {
+                    key: Rc::new(key),
+                    ty: ty.to_token_stream().to_string(),
+                }
+            }
+            // formatted variable
+            _ if ident.contains(',') => {
+                let (name, formatter) = ident.split_once(',').unwrap();
+                let Some(key) = Key::new(&format!("var_{}", name)) else {
+                    return Ok(None);
+                };
+                let formatter =
+                    Formatter::parse(formatter).ok_or_else(|| Error::InvalidFormatter {
+                        locale: Rc::clone(locale),
+                        key_path: key_path.clone(),
+                        formatter: formatter.trim().to_string(),
+                    })?;
+                ParsedValue::Formatted {
+                    key: Rc::new(key),
+                    formatter,
+                    locale: Rc::clone(locale),
+                }
+            }
             // variable key
             _ => {
-                let ident = Key::new(&format!("var_{}", ident))?;
+                let Some(ident) = Key::new(&format!("var_{}", ident)) else {
+                    return Ok(None);
+                };
                 ParsedValue::Variable(Rc::new(ident))
             }
         };
 
-        Some(ParsedValue::Bloc(vec![before, this, after]))
+        let before = Self::new(before, key_path, locale)?;
+        let after = Self::new(after, key_path, locale)?;
+
+        Ok(Some(ParsedValue::Bloc(vec![before, this, after])))
     }
 
     fn find_valid_component(value: &str) -> Option<(Rc<Key>, &str, &str, &str)> {
@@ -359,19 +810,21 @@ impl ParsedValue {
         }
     }
 
-    fn find_component(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Option<Self> {
-        let (key, before, beetween, after) = Self::find_valid_component(value)?;
+    fn find_component(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Result<Option<Self>> {
+        let Some((key, before, beetween, after)) = Self::find_valid_component(value) else {
+            return Ok(None);
+        };
 
-        let before = ParsedValue::new(before, key_path, locale);
-        let beetween = ParsedValue::new(beetween, key_path, locale);
-        let after = ParsedValue::new(after, key_path, locale);
+        let before = ParsedValue::new(before, key_path, locale)?;
+        let beetween = ParsedValue::new(beetween, key_path, locale)?;
+        let after = ParsedValue::new(after, key_path, locale)?;
 
         let this = ParsedValue::Component {
             key,
             inner: beetween.into(),
         };
 
-        Some(ParsedValue::Bloc(vec![before, this, after]))
+        Ok(Some(ParsedValue::Bloc(vec![before, this, after])))
     }
 
     fn find_closing_tag<'a>(value: &'a str, key: &str) -> Option<(Key, &'a str, &'a str)> {
@@ -447,7 +900,13 @@ impl ParsedValue {
 
     pub fn reduce(&mut self) {
         match self {
-            ParsedValue::Variable(_) | ParsedValue::String(_) | ParsedValue::Default => {}
+            ParsedValue::Variable(_)
+            | ParsedValue::TypedVariable { .. }
+            | ParsedValue::Formatted { .. }
+            | ParsedValue::String(_)
+            | ParsedValue::Default
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_) => {}
             ParsedValue::ForeignKey(foreign_key) => {
                 let fk = foreign_key.get_mut();
                 match fk {
@@ -466,6 +925,20 @@ impl ParsedValue {
                 });
             }
             ParsedValue::Component { inner, .. } => inner.reduce(),
+            ParsedValue::Features { variants, default } => {
+                for (_, value) in variants {
+                    value.reduce();
+                }
+                default.reduce();
+            }
+            ParsedValue::Select {
+                variants, other, ..
+            } => {
+                for (_, value) in variants {
+                    value.reduce();
+                }
+                other.reduce();
+            }
             ParsedValue::Subkeys(subkeys) => {
                 for value in subkeys.keys.values_mut() {
                     value.reduce();
@@ -479,22 +952,47 @@ impl ParsedValue {
         }
     }
 
+    /// Return the statements cloning the keys of the value, for a closure rendering it to capture them.
+    fn captured_keys(&self) -> Option<TokenStream> {
+        self.get_keys().map(|keys| {
+            let keys = keys
+                .into_iter()
+                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
+            quote!(#(#keys)*)
+        })
+    }
+
     fn flatten(&self, tokens: &mut Vec<TokenStream>) {
         match self {
-            ParsedValue::Subkeys(_) | ParsedValue::Default => {}
+            ParsedValue::Subkeys(_)
+            | ParsedValue::Default
+            | ParsedValue::List(_)
+            | ParsedValue::ObjectList(_) => {}
             ParsedValue::String(s) if s.is_empty() => {}
             ParsedValue::String(s) => tokens.push(quote!(leptos::IntoView::into_view(#s))),
             ParsedValue::Plural(plurals) => tokens.push(plurals.to_token_stream()),
             ParsedValue::Variable(key) => {
                 tokens.push(quote!(leptos::IntoView::into_view(core::clone::Clone::clone(&#key))))
             }
+            ParsedValue::TypedVariable { key, .. } => {
+                tokens.push(quote!(leptos::IntoView::into_view({
+                    let #key = core::clone::Clone::clone(&#key);
+                    move || #key()
+                })))
+            }
+            ParsedValue::Formatted {
+                key,
+                formatter,
+                locale,
+            } => {
+                let formatted = formatter.format_tokens(quote!(&#key), locale);
+                tokens.push(quote!(leptos::IntoView::into_view({
+                    let #key = core::clone::Clone::clone(&#key);
+                    move || #formatted
+                })))
+            }
             ParsedValue::Component { key, inner } => {
-                let captured_keys = inner.get_keys().map(|keys| {
-                    let keys = keys
-                        .into_iter()
-                        .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
-                    quote!(#(#keys)*)
-                });
+                let captured_keys = inner.captured_keys();
 
                 let f = quote!({
                     #captured_keys
@@ -508,6 +1006,33 @@ impl ParsedValue {
                     value.flatten(tokens)
                 }
             }
+            ParsedValue::Features { variants, default } => {
+                let variants = variants
+                    .iter()
+                    .map(|(feature, value)| (feature.as_str(), value.to_token_stream()))
+                    .collect::<Vec<_>>();
+                tokens.push(select_by_feature(&variants, default.to_token_stream()))
+            }
+            ParsedValue::Select {
+                key,
+                variants,
+                other,
+            } => {
+                // each value is rendered by a closure called again when the variant changes,
+                // so the captured keys are cloned again for it.
+                let captured_keys = self.captured_keys();
+                let values = variants.iter().map(|(_, value)| {
+                    let captured_keys = value.captured_keys();
+                    quote!({ #captured_keys #value })
+                });
+                let other_captured_keys = other.captured_keys();
+                let other = quote!({ #other_captured_keys #other });
+                let select = select_by_variant(&quote!(&#key), variants, values, other);
+                tokens.push(quote!(leptos::IntoView::into_view({
+                    #captured_keys
+                    move || #select
+                })))
+            }
             ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
                 ForeignKey::Set(inner) => inner.flatten(tokens),
                 ForeignKey::NotSet(_) => unreachable!(),
@@ -519,14 +1044,22 @@ impl ParsedValue {
 impl InterpolateKey {
     pub fn as_ident(&self) -> syn::Ident {
         match self {
-            InterpolateKey::Variable(key) | InterpolateKey::Component(key) => key.ident.clone(),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::TypedVariable(key, _)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Component(key)
+            | InterpolateKey::Select(key) => key.ident.clone(),
             InterpolateKey::Count(_) => format_ident!("var_count"),
         }
     }
 
     pub fn as_key(&self) -> Option<&Key> {
         match self {
-            InterpolateKey::Variable(key) | InterpolateKey::Component(key) => Some(key),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::TypedVariable(key, _)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Component(key)
+            | InterpolateKey::Select(key) => Some(key),
             InterpolateKey::Count(_) => None,
         }
     }
@@ -535,7 +1068,10 @@ impl InterpolateKey {
     pub fn get_real_name(&self) -> &str {
         match self {
             InterpolateKey::Count(_) => "count",
-            InterpolateKey::Variable(key) => key.name.strip_prefix("var_").unwrap(),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::TypedVariable(key, _)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key) => key.name.strip_prefix("var_").unwrap(),
             InterpolateKey::Component(key) => key.name.strip_prefix("comp_").unwrap(),
         }
     }
@@ -545,6 +1081,12 @@ impl InterpolateKey {
             InterpolateKey::Variable(_) => {
                 quote!(leptos::IntoView + core::clone::Clone + 'static)
             }
+            InterpolateKey::TypedVariable(_, ty) => {
+                let ty = syn::parse_str::<syn::Type>(ty).unwrap();
+                quote!(Fn() -> #ty + core::clone::Clone + 'static)
+            }
+            InterpolateKey::Formatted(_, kind) => kind.get_generic(),
+            InterpolateKey::Select(_) => quote!(leptos_i18n::__private::SelectFn),
             InterpolateKey::Count(plural_type) => {
                 quote!(Fn() -> #plural_type + core::clone::Clone + 'static)
             }
@@ -562,6 +1104,12 @@ impl InterpolateKey {
             InterpolateKey::Variable(_) => {
                 quote!(())
             }
+            InterpolateKey::Formatted(..) => quote!(|| leptos_i18n::__private::EmptyFormatValue),
+            InterpolateKey::TypedVariable(_, ty) => {
+                let ty = syn::parse_str::<syn::Type>(ty).unwrap();
+                quote!(|| -> #ty { unreachable!() })
+            }
+            InterpolateKey::Select(_) => quote!(|| ""),
             InterpolateKey::Count(plural_type) => match plural_type {
                 PluralType::F32 | PluralType::F64 => quote!(|| 0.0),
                 _ => quote!(|| 0),
@@ -571,6 +1119,67 @@ impl InterpolateKey {
             }
         }
     }
+
+    /// Return the value given to this variable or component when every key is rendered for tests,
+    /// `None` for a typed variable whose type has no obvious placeholder.
+    ///
+    /// Variables are rendered as `{{ name }}`, components as a `<name>` tag, counts as `0`,
+    /// and selects as their `other` value.
+    pub fn get_placeholder(&self) -> Option<TokenStream> {
+        let placeholder = match self {
+            InterpolateKey::Variable(key) => {
+                let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                let placeholder = format!("{{{{ {} }}}}", name);
+                quote!(#placeholder)
+            }
+            InterpolateKey::TypedVariable(_, ty) => {
+                const DEFAULT_TYPES: &[&str] = &[
+                    "u8",
+                    "u16",
+                    "u32",
+                    "u64",
+                    "u128",
+                    "usize",
+                    "i8",
+                    "i16",
+                    "i32",
+                    "i64",
+                    "i128",
+                    "isize",
+                    "f32",
+                    "f64",
+                    "bool",
+                    "char",
+                    "String",
+                    "& str",
+                    "& 'static str",
+                ];
+                let ty = syn::parse_str::<syn::Type>(ty).ok()?;
+                let name = quote!(#ty).to_string();
+                if !DEFAULT_TYPES.contains(&name.as_str()) {
+                    return None;
+                }
+                quote!(|| -> #ty { core::default::Default::default() })
+            }
+            InterpolateKey::Formatted(_, FormatterKind::Number) => quote!(|| 0),
+            InterpolateKey::Formatted(..) => quote!(|| leptos_i18n::__private::EmptyFormatValue),
+            InterpolateKey::Select(_) => quote!(|| ""),
+            InterpolateKey::Count(plural_type) => match plural_type {
+                PluralType::F32 | PluralType::F64 => quote!(|| 0.0),
+                _ => quote!(|| 0),
+            },
+            InterpolateKey::Component(key) => {
+                let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+                quote! {
+                    |_children: leptos::ChildrenFn| {
+                        let _tag = leptos::html::Custom::new(#name);
+                        leptos::IntoView::into_view(leptos::html::custom(_tag).child(_children()))
+                    }
+                }
+            }
+        };
+        Some(placeholder)
+    }
 }
 
 impl ToTokens for InterpolateKey {
@@ -602,6 +1211,7 @@ pub struct ParsedValueSeed<'a> {
     pub in_plural: bool,
     pub key_path: &'a KeyPath,
     pub key: &'a Rc<Key>,
+    pub value_options: ValueOptions,
 }
 
 impl<'de> serde::de::DeserializeSeed<'de> for ParsedValueSeed<'_> {
@@ -622,10 +1232,39 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     where
         E: serde::de::Error,
     {
-        Ok(ParsedValue::new(v, self.key_path, self.top_locale_name))
+        let v = self.value_options.normalize(v);
+        ParsedValue::new(&v, self.key_path, self.top_locale_name).map_err(serde::de::Error::custom)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(ParsedValue::String(v.to_string()))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(ParsedValue::String(v.to_string()))
     }
 
-    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(ParsedValue::String(v.to_string()))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(ParsedValue::String(format_float(v, self.top_locale_name)))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
@@ -633,15 +1272,33 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             return Err(serde::de::Error::custom(Error::PluralSubkeys));
         }
 
-        let map_de = MapAccessDeserializer::new(map);
+        let first_key = map.next_key::<String>()?;
+
+        if first_key.as_deref() == Some(ParsedValue::FEATURES_KEY) {
+            let value = map.next_value_seed(FeaturesSeed(self))?;
+            if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                return Err(serde::de::Error::custom(Error::FeatureValuesWithKeys));
+            }
+            return Ok(value);
+        }
+
+        if first_key.as_deref() == Some(ParsedValue::SELECT_KEY) {
+            let name = map.next_value::<String>()?;
+            let key = Key::new(&format!("var_{}", name.trim()))
+                .filter(|_| !name.trim().is_empty())
+                .ok_or_else(|| serde::de::Error::custom(Error::InvalidSelectVariable(name)))?;
+            return SelectSeed(self).visit_variants(Rc::new(key), map);
+        }
 
         let seed = LocaleSeed {
             name: Rc::clone(self.key),
             top_locale_name: Rc::clone(self.top_locale_name),
             key_path: self.key_path.to_owned(),
+            value_options: self.value_options,
         };
 
-        seed.deserialize(map_de).map(ParsedValue::Subkeys)
+        seed.deserialize_map(first_key, map)
+            .map(ParsedValue::Subkeys)
     }
 
     fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
@@ -660,7 +1317,21 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         if std::mem::replace(&mut self.in_plural, true) {
             return Err(serde::de::Error::custom(Error::NestedPlurals));
         }
-        let plurals = Plurals::from_serde_seq(map, self)?;
+        let plurals = match Plurals::from_serde_seq(map, self)? {
+            PluralsOrList::Plurals(plurals) => plurals,
+            PluralsOrList::List(mut items) => {
+                for item in &mut items {
+                    *item = self.value_options.normalize(item).into_owned();
+                }
+                return Ok(ParsedValue::List(items));
+            }
+            PluralsOrList::ObjectList(mut list) => {
+                for (_, value) in list.items.iter_mut().flat_map(|item| &mut item.0) {
+                    *value = self.value_options.normalize(value).into_owned();
+                }
+                return Ok(ParsedValue::ObjectList(list));
+            }
+        };
 
         let (invalid_fallback, fallback_count, should_have_fallback) =
             plurals.check_deserialization();
@@ -673,6 +1344,12 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             Err(serde::de::Error::custom(Error::MissingFallback(
                 plurals.get_type(),
             )))
+        } else if let missing @ [_, ..] = &*plurals.missing_categories() {
+            Err(serde::de::Error::custom(Error::MissingPluralCategories {
+                locale: Rc::clone(self.top_locale_name),
+                key_path: self.key_path.clone(),
+                missing: missing.to_vec(),
+            }))
         } else {
             Ok(ParsedValue::Plural(plurals))
         }
@@ -681,11 +1358,191 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             formatter,
-            "either a string, a sequence of plurals or a map of subkeys"
+            "either a string, a sequence of plurals, a list of strings or maps, or a map of subkeys"
         )
     }
 }
 
+/// Seed for the map of values selected by feature.
+struct FeaturesSeed<'a>(ParsedValueSeed<'a>);
+
+impl<'de> serde::de::DeserializeSeed<'de> for FeaturesSeed<'_> {
+    type Value = ParsedValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for FeaturesSeed<'_> {
+    type Value = ParsedValue;
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut variants: Vec<(String, ParsedValue)> = vec![];
+        let mut default = None;
+        while let Some(feature) = map.next_key::<String>()? {
+            let value = map.next_value_seed(self.0)?;
+            if matches!(
+                value,
+                ParsedValue::Subkeys(_)
+                    | ParsedValue::List(_)
+                    | ParsedValue::ObjectList(_)
+                    | ParsedValue::Default
+                    | ParsedValue::Features { .. }
+            ) {
+                return Err(serde::de::Error::custom(Error::InvalidFeatureValue(
+                    feature,
+                )));
+            }
+            if feature == ParsedValue::FEATURES_DEFAULT {
+                if default.replace(value).is_some() {
+                    return Err(serde::de::Error::duplicate_field(
+                        ParsedValue::FEATURES_DEFAULT,
+                    ));
+                }
+            } else if variants.iter().any(|(f, _)| *f == feature) {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate feature {:?}",
+                    feature
+                )));
+            } else {
+                variants.push((feature, value));
+            }
+        }
+        let Some(default) = default else {
+            return Err(serde::de::Error::custom(Error::MissingFeatureDefault));
+        };
+        Ok(ParsedValue::Features {
+            variants,
+            default: Box::new(default),
+        })
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a map of feature names and values, with a default value for the \"_\" key"
+        )
+    }
+}
+
+/// Seed for the variants of a select value, following its `@@select` key.
+struct SelectSeed<'a>(ParsedValueSeed<'a>);
+
+impl SelectSeed<'_> {
+    fn visit_variants<'de, A>(self, key: Rc<Key>, mut map: A) -> Result<ParsedValue, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut variants: Vec<(String, ParsedValue)> = vec![];
+        let mut other = None;
+        while let Some(variant) = map.next_key::<String>()? {
+            let variant = variant.trim().to_string();
+            let value = map.next_value_seed(self.0)?;
+            if matches!(
+                value,
+                ParsedValue::Subkeys(_)
+                    | ParsedValue::List(_)
+                    | ParsedValue::ObjectList(_)
+                    | ParsedValue::Default
+                    | ParsedValue::Features { .. }
+            ) {
+                return Err(serde::de::Error::custom(Error::InvalidSelectValue(variant)));
+            }
+            if Key::new(&variant).is_none() {
+                return Err(serde::de::Error::custom(Error::InvalidKey(variant)));
+            }
+            if variant == ParsedValue::SELECT_OTHER {
+                if other.replace(value).is_some() {
+                    return Err(serde::de::Error::duplicate_field(ParsedValue::SELECT_OTHER));
+                }
+            } else if variants.iter().any(|(v, _)| *v == variant) {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate variant {:?}",
+                    variant
+                )));
+            } else {
+                variants.push((variant, value));
+            }
+        }
+        let Some(other) = other else {
+            return Err(serde::de::Error::custom(Error::MissingSelectOther));
+        };
+        Ok(ParsedValue::Select {
+            key,
+            variants,
+            other: Box::new(other),
+        })
+    }
+}
+
+/// Select between expressions with the cargo features enabled in the crate calling the macro.
+/// The first enabled feature wins, `default` is used when none of them are enabled.
+fn select_by_feature(variants: &[(&str, TokenStream)], default: TokenStream) -> TokenStream {
+    variants
+        .iter()
+        .rev()
+        .fold(default, |else_branch, (feature, value)| {
+            quote!(if cfg!(feature = #feature) { #value } else { #else_branch })
+        })
+}
+
+/// Select between the values with the variant of the select variable at runtime,
+/// `other` being used for the variants not declared.
+fn select_by_variant(
+    key: &impl ToTokens,
+    variants: &[(String, ParsedValue)],
+    values: impl Iterator<Item = TokenStream>,
+    other: TokenStream,
+) -> TokenStream {
+    let names = variants.iter().map(|(name, _)| name);
+    let arms = values
+        .enumerate()
+        .map(|(i, value)| quote!(Some(#i) => { #value }));
+    quote! {
+        match leptos_i18n::__private::SelectFn::select(#key, &[#(#names,)*]) {
+            #(#arms,)*
+            _ => { #other }
+        }
+    }
+}
+
+/// Format a float written as a value in a locale file with the decimal separator of the locale.
+#[cfg(not(feature = "icu"))]
+fn format_float(value: f64, locale: &Rc<Key>) -> String {
+    let decimal = super::formatter::NumberSymbols::of_locale(locale).decimal;
+    value.to_string().replace('.', decimal)
+}
+
+/// Format a float written as a value in a locale file with the decimal separator of the locale, without grouping its digits.
+#[cfg(feature = "icu")]
+fn format_float(value: f64, locale: &Rc<Key>) -> String {
+    use fixed_decimal::Decimal;
+    use icu_decimal::{
+        options::{DecimalFormatterOptions, GroupingStrategy},
+        DecimalFormatter,
+    };
+    use icu_locale_core::Locale;
+
+    let digits = value.to_string();
+    let mut options = DecimalFormatterOptions::default();
+    options.grouping_strategy = Some(GroupingStrategy::Never);
+    let formatted = Locale::try_from_str(&locale.name.replace('_', "-"))
+        .ok()
+        .zip(digits.parse::<Decimal>().ok())
+        .and_then(|(tag, decimal)| {
+            let formatter = DecimalFormatter::try_new(tag.into(), options).ok()?;
+            Some(formatter.format(&decimal).to_string())
+        });
+    formatted.unwrap_or(digits)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -694,7 +1551,7 @@ mod tests {
         let key_path = KeyPath::new(None);
         let locale = Rc::new(Key::new("locale_key").unwrap());
 
-        ParsedValue::new(value, &key_path, &locale)
+        ParsedValue::new(value, &key_path, &locale).unwrap()
     }
 
     fn new_key(key: &str) -> Rc<Key> {
@@ -722,6 +1579,124 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_formatted_variable() {
+        let value = new_parsed_value("{{ price, currency(EUR) }} each");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String(String::new()),
+                ParsedValue::Formatted {
+                    key: new_key("var_price"),
+                    formatter: Formatter::Currency("EUR".to_string()),
+                    locale: new_key("locale_key"),
+                },
+                ParsedValue::String(" each".to_string())
+            ])
+        );
+
+        let key_path = KeyPath::new(None);
+        let locale = new_key("locale_key");
+        let err = ParsedValue::new("{{ price, percent }}", &key_path, &locale).unwrap_err();
+        assert!(matches!(err, Error::InvalidFormatter { formatter, .. } if formatter == "percent"));
+    }
+
+    #[test]
+    fn parse_typed_variable() {
+        let value = new_parsed_value("{{ count: u32 }} items");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String(String::new()),
+                ParsedValue::TypedVariable {
+                    key: new_key("var_count"),
+                    ty: "u32".to_string(),
+                },
+                ParsedValue::String(" items".to_string())
+            ])
+        );
+
+        let key_path = KeyPath::new(None);
+        let locale = new_key("locale_key");
+        let err = ParsedValue::new("{{ count: not a type }}", &key_path, &locale).unwrap_err();
+        assert!(matches!(err, Error::InvalidVariableType { ty, .. } if ty == "not a type"));
+    }
+
+    #[test]
+    fn typed_variable_missmatch() {
+        let locale = new_key("locale_key");
+        let mut keys = LocaleValue::Value(None);
+        for (value, is_ok) in [
+            ("{{ count: u32 }} items", true),
+            ("{{ count : u32 }} articles", true),
+            ("{{ count: i64 }} items", false),
+        ] {
+            let result = new_parsed_value(value).merge(
+                &mut keys,
+                "locale_key",
+                Rc::clone(&locale),
+                &mut KeyPath::new(None),
+                MissingKeyPolicy::default(),
+            );
+            assert_eq!(result.is_ok(), is_ok, "{}", value);
+        }
+        let err = new_parsed_value("{{ count }} and {{ count: u32 }}")
+            .merge(
+                &mut LocaleValue::Value(None),
+                "locale_key",
+                locale,
+                &mut KeyPath::new(None),
+                MissingKeyPolicy::default(),
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::VariableTypeMissmatch { variable, .. } if variable == "count")
+        );
+    }
+
+    fn parse_select(source: &str) -> std::result::Result<ParsedValue, String> {
+        use serde::de::DeserializeSeed;
+        let value: toml::Value = toml::from_str(source).unwrap();
+        let locale = new_key("locale_key");
+        let key = new_key("key");
+        let seed = ParsedValueSeed {
+            top_locale_name: &locale,
+            in_plural: false,
+            key_path: &KeyPath::new(None),
+            key: &key,
+            value_options: ValueOptions::default(),
+        };
+        seed.deserialize(value["key"].clone())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn parse_select_value() {
+        let value = parse_select(
+            r#"key = { "@@select" = "gender", female = "She", male = "He", other = "They" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            ParsedValue::Select {
+                key: new_key("var_gender"),
+                variants: vec![
+                    ("female".to_string(), ParsedValue::String("She".to_string())),
+                    ("male".to_string(), ParsedValue::String("He".to_string())),
+                ],
+                other: Box::new(ParsedValue::String("They".to_string())),
+            }
+        );
+
+        let err = parse_select(r#"key = { "@@select" = "gender", female = "She" }"#).unwrap_err();
+        assert!(err.contains("\"other\""));
+        let err =
+            parse_select(r#"key = { "@@select" = "gender", other = { a = "b" } }"#).unwrap_err();
+        assert!(err.contains("variant \"other\""));
+    }
+
     #[test]
     fn parse_comp() {
         let value = new_parsed_value("before <comp>inner</comp> after");
@@ -781,4 +1756,25 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn typed_variable_placeholder() {
+        let placeholder = |ty: &str| {
+            InterpolateKey::TypedVariable(new_key("var_count"), ty.to_string()).get_placeholder()
+        };
+        for ty in ["u32", "f64", "String", "&str", "&'static str"] {
+            assert!(placeholder(ty).is_some(), "{}", ty);
+        }
+        assert!(placeholder("chrono::NaiveDate").is_none());
+        assert!(placeholder("Vec<u32>").is_none());
+    }
+
+    #[test]
+    fn format_float_with_locale_separator() {
+        assert_eq!(format_float(1.5, &new_key("en")), "1.5");
+        assert_eq!(format_float(1.5, &new_key("fr")), "1,5");
+        assert_eq!(format_float(1.5, &new_key("pt-BR")), "1,5");
+        assert_eq!(format_float(-0.25, &new_key("de-DE")), "-0,25");
+        assert_eq!(format_float(1.5, &new_key("de-CH")), "1.5");
+    }
 }
@@ -17,12 +17,14 @@ use crate::load_locales::{
 use super::{
     error::{Error, Result},
     parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+    plural_rules::{PluralCategory, PluralRules},
 };
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
 pub enum PluralType {
     I8,
     I16,
+    #[default]
     I32,
     I64,
     U8,
@@ -33,12 +35,6 @@ pub enum PluralType {
     F64,
 }
 
-impl Default for PluralType {
-    fn default() -> Self {
-        Self::I32
-    }
-}
-
 type DefaultPluralType = i32;
 
 impl core::fmt::Display for PluralType {
@@ -186,6 +182,27 @@ impl Plurals {
         }
     }
 
+    /// Return the values with their count written as in the locale files, in declaration order.
+    pub fn branches(&self) -> Vec<(String, &ParsedValue)> {
+        fn inner<T: PluralNumber>(v: &PluralsInner<T>) -> Vec<(String, &ParsedValue)> {
+            v.iter()
+                .map(|(plural, value)| (plural.to_string(), value))
+                .collect()
+        }
+        match self {
+            Plurals::I8(v) => inner(v),
+            Plurals::I16(v) => inner(v),
+            Plurals::I32(v) => inner(v),
+            Plurals::I64(v) => inner(v),
+            Plurals::U8(v) => inner(v),
+            Plurals::U16(v) => inner(v),
+            Plurals::U32(v) => inner(v),
+            Plurals::U64(v) => inner(v),
+            Plurals::F32(v) => inner(v),
+            Plurals::F64(v) => inner(v),
+        }
+    }
+
     pub const fn get_type(&self) -> PluralType {
         match self {
             Plurals::I8(_) => PluralType::I8,
@@ -201,97 +218,123 @@ impl Plurals {
         }
     }
 
-    fn to_tokens_integers<T: PluralInteger>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
-        let match_arms = plurals
-            .iter()
-            .map(|(plural, value)| quote!(#plural => #value));
-
-        let mut captured_values = None;
-
-        for (_, value) in plurals {
-            value.get_keys_inner(&mut captured_values);
-        }
-
-        let captured_values = captured_values.map(|keys| {
-            let keys = keys
-                .into_iter()
-                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
-            quote!(#(#keys)*)
+    /// Return the statement selecting the value matching `var_count()`, with each value converted by `value_tokens`.
+    fn select_integers<T: PluralInteger>(
+        plurals: &[(Plural<T>, ParsedValue)],
+        value_tokens: impl Fn(&ParsedValue) -> TokenStream,
+    ) -> TokenStream {
+        let match_arms = plurals.iter().map(|(plural, value)| {
+            let value = value_tokens(value);
+            // plural categories can't be patterns, they are matched with a guard.
+            if plural.has_category() {
+                let plural_count = quote!(plural_count);
+                let condition = plural.to_condition(&plural_count);
+                quote!(#plural_count if #condition => #value)
+            } else {
+                quote!(#plural => #value)
+            }
         });
-        let match_statement = quote! {
+
+        quote! {
             match var_count() {
                 #(
                     #match_arms,
                 )*
             }
-        };
-
-        quote! {
-            leptos::IntoView::into_view(
-                {
-                    #captured_values
-                    move || #match_statement
-                },
-
-            )
         }
     }
 
-    fn to_tokens_floats<T: PluralFloats>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
-        fn to_condition<T: PluralFloats>(plural: &Plural<T>) -> Option<TokenStream> {
+    /// Same as `select_integers` for floats, that can't be matched on.
+    fn select_floats<T: PluralFloats>(
+        plurals: &[(Plural<T>, ParsedValue)],
+        value_tokens: impl Fn(&ParsedValue) -> TokenStream,
+    ) -> TokenStream {
+        let mut ifs = plurals.iter().map(|(plural, value)| {
+            let value = value_tokens(value);
             match plural {
-                Plural::Exact(exact) => Some(quote!(plural_count == #exact)),
-                Plural::Range { .. } => {
-                    Some(quote!(core::ops::RangeBounds::contains(&(#plural), &plural_count)))
-                }
-                Plural::Multiple(conditions) => {
-                    let mut conditions = conditions.iter().filter_map(to_condition);
-                    let first = conditions.next();
-                    Some(quote!(#first #(|| #conditions)*))
+                Plural::Fallback => quote!({ #value }),
+                plural => {
+                    let condition = plural.to_condition(&quote!(plural_count));
+                    quote!(if #condition { #value })
                 }
-                Plural::Fallback => None,
             }
-        }
-
-        let mut ifs = plurals
-            .iter()
-            .map(|(plural, value)| match to_condition(plural) {
-                None => quote!({ #value }),
-                Some(condition) => quote!(if #condition { #value }),
-            });
+        });
         let first = ifs.next();
-        let ifs = quote! {
-            #first
-            #(else #ifs)*
-        };
 
+        quote! {
+            {
+                let plural_count = var_count();
+                #first
+                #(else #ifs)*
+            }
+        }
+    }
+
+    fn captured_values<T>(plurals: &[(Plural<T>, ParsedValue)]) -> Option<TokenStream> {
         let mut captured_values = None;
 
         for (_, value) in plurals {
             value.get_keys_inner(&mut captured_values);
         }
 
-        let captured_values = captured_values.map(|keys| {
+        captured_values.map(|keys| {
             let keys = keys
                 .into_iter()
                 .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
             quote!(#(#keys)*)
-        });
+        })
+    }
+
+    fn to_tokens_integers<T: PluralInteger>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
+        let captured_values = Self::captured_values(plurals);
+        let match_statement = Self::select_integers(plurals, |value| quote!(#value));
 
         quote! {
             leptos::IntoView::into_view(
                 {
                     #captured_values
-                    move || {
-                        let plural_count = var_count();
-                        #ifs
-                    }
+                    move || #match_statement
+                },
+
+            )
+        }
+    }
+
+    fn to_tokens_floats<T: PluralFloats>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
+        let captured_values = Self::captured_values(plurals);
+        let ifs = Self::select_floats(plurals, |value| quote!(#value));
+
+        quote! {
+            leptos::IntoView::into_view(
+                {
+                    #captured_values
+                    move || #ifs
                 },
 
             )
         }
     }
 
+    /// Return the statements writing the value matching the count to the formatter, see `ParsedValue::to_display_tokens`.
+    pub fn to_display_tokens(&self) -> TokenStream {
+        fn value_tokens(value: &ParsedValue) -> TokenStream {
+            let statements = value.to_display_tokens(true);
+            quote!({ #statements })
+        }
+        match self {
+            Plurals::I8(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::I16(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::I32(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::I64(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::U8(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::U16(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::U32(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::U64(plurals) => Self::select_integers(plurals, value_tokens),
+            Plurals::F32(plurals) => Self::select_floats(plurals, value_tokens),
+            Plurals::F64(plurals) => Self::select_floats(plurals, value_tokens),
+        }
+    }
+
     fn deserialize_all_pairs<'de, A, T>(
         mut seq: A,
         plurals: &mut PluralsInner<T>,
@@ -330,25 +373,10 @@ impl Plurals {
         }
     }
 
-    pub fn from_type(plural_type: PluralType) -> Self {
-        match plural_type {
-            PluralType::I8 => Self::I8(vec![]),
-            PluralType::I16 => Self::I16(vec![]),
-            PluralType::I32 => Self::I32(vec![]),
-            PluralType::I64 => Self::I64(vec![]),
-            PluralType::U8 => Self::U8(vec![]),
-            PluralType::U16 => Self::U16(vec![]),
-            PluralType::U32 => Self::U32(vec![]),
-            PluralType::U64 => Self::U64(vec![]),
-            PluralType::F32 => Self::F32(vec![]),
-            PluralType::F64 => Self::F64(vec![]),
-        }
-    }
-
     pub fn from_serde_seq<'de, A>(
         mut seq: A,
         parsed_value_seed: ParsedValueSeed,
-    ) -> Result<Self, A::Error>
+    ) -> Result<PluralsOrList, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
     {
@@ -358,12 +386,112 @@ impl Plurals {
             .map_err(serde::de::Error::custom)?;
 
         let mut plurals = match type_or_plural {
-            TypeOrPlural::Type(plural_type) => Self::from_type(plural_type),
+            TypeOrPlural::Type(plural_type, name) => {
+                return Self::typed_from_serde_seq(plural_type, name, seq, parsed_value_seed)
+            }
             TypeOrPlural::Plural(plural) => Plurals::I32(vec![plural]),
+            TypeOrPlural::ListItem(first) => return Self::list_from_serde_seq(vec![first], seq),
+            TypeOrPlural::Object(first) => {
+                return Self::object_list_from_serde_seq(first, seq, parsed_value_seed)
+            }
         };
 
         plurals.deserialize_inner(seq, parsed_value_seed)?;
-        Ok(plurals)
+        Ok(PluralsOrList::Plurals(plurals))
+    }
+
+    /// The sequence starts with the name of a type, it is a plural of this type if the next element is a plural,
+    /// else a list of strings starting with this name.
+    fn typed_from_serde_seq<'de, A>(
+        plural_type: PluralType,
+        name: String,
+        seq: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<PluralsOrList, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        match plural_type {
+            PluralType::I8 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::I8),
+            PluralType::I16 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::I16),
+            PluralType::I32 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::I32),
+            PluralType::I64 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::I64),
+            PluralType::U8 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::U8),
+            PluralType::U16 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::U16),
+            PluralType::U32 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::U32),
+            PluralType::U64 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::U64),
+            PluralType::F32 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::F32),
+            PluralType::F64 => Self::typed_inner(name, seq, parsed_value_seed, Plurals::F64),
+        }
+    }
+
+    fn typed_inner<'de, A, T>(
+        name: String,
+        mut seq: A,
+        parsed_value_seed: ParsedValueSeed,
+        into_plurals: fn(PluralsInner<T>) -> Plurals,
+    ) -> Result<PluralsOrList, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+        T: PluralNumber,
+    {
+        let plural_seed = PluralStructSeed::<T>(parsed_value_seed, PhantomData);
+        match seq.next_element_seed(PluralOrStrSeed(plural_seed))? {
+            None => Ok(PluralsOrList::List(vec![name])),
+            Some(PluralOrStr::Str(second)) => Self::list_from_serde_seq(vec![name, second], seq),
+            Some(PluralOrStr::Plural(first)) => {
+                let mut plurals = vec![first];
+                Self::deserialize_all_pairs(seq, &mut plurals, parsed_value_seed)?;
+                Ok(PluralsOrList::Plurals(into_plurals(plurals)))
+            }
+        }
+    }
+
+    fn list_from_serde_seq<'de, A>(
+        mut items: Vec<String>,
+        mut seq: A,
+    ) -> Result<PluralsOrList, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        while let Some(item) = seq.next_element::<ListItem>()? {
+            match item {
+                ListItem::Str(item) => items.push(item),
+                // a list of strings is expected, but if the other elements are not strings
+                // the first one is more likely a typo in a plural type.
+                ListItem::Other => {
+                    return Err(serde::de::Error::custom(Error::InvalidPluralType(
+                        items.swap_remove(0),
+                    )))
+                }
+            }
+        }
+        Ok(PluralsOrList::List(items))
+    }
+
+    fn object_list_from_serde_seq<'de, A>(
+        first: ObjectItem,
+        mut seq: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<PluralsOrList, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let fields = first.iter().map(|(key, _)| Rc::clone(key)).collect();
+        let mut list = ObjectList {
+            fields,
+            items: vec![first],
+        };
+        while let Some(ObjectItem(item)) = seq.next_element()? {
+            if !list.has_fields(item.iter().map(|(key, _)| key)) {
+                return Err(serde::de::Error::custom(Error::ObjectListFieldsMissmatch {
+                    locale: Rc::clone(parsed_value_seed.top_locale_name),
+                    key_path: parsed_value_seed.key_path.clone(),
+                }));
+            }
+            list.items.push(ObjectItem(item));
+        }
+        Ok(PluralsOrList::ObjectList(list))
     }
 
     fn check_de_inner<T: PluralNumber>(
@@ -395,6 +523,41 @@ impl Plurals {
         )
     }
 
+    fn missing_categories_inner<T: PluralNumber>(
+        plurals: &[(Plural<T>, ParsedValue)],
+    ) -> Vec<PluralCategory> {
+        let mut rules = None;
+        let mut declared = vec![];
+        for (plural, _) in plurals {
+            plural.collect_categories(&mut rules, &mut declared);
+        }
+        let Some(rules) = rules else {
+            return vec![];
+        };
+        let is_float = T::TYPE.should_have_fallback();
+        rules
+            .required_categories(is_float)
+            .filter(|category| !declared.contains(category))
+            .collect()
+    }
+
+    /// Return the plural categories required by the language of the locale that are not declared,
+    /// if the plurals use categories.
+    pub fn missing_categories(&self) -> Vec<PluralCategory> {
+        match self {
+            Plurals::I8(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::I16(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::I32(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::I64(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::U8(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::U16(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::U32(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::U64(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::F32(plurals) => Self::missing_categories_inner(plurals),
+            Plurals::F64(plurals) => Self::missing_categories_inner(plurals),
+        }
+    }
+
     pub fn check_deserialization(&self) -> (bool, usize, bool) {
         match self {
             Plurals::I8(plurals) => Self::check_de_inner(plurals),
@@ -431,12 +594,17 @@ impl ToTokens for Plurals {
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum Plural<T> {
     Exact(T),
-    Range { start: Option<T>, end: Bound<T> },
+    Range {
+        start: Option<T>,
+        end: Bound<T>,
+    },
     Multiple(Vec<Self>),
+    /// A CLDR plural category of the language of the locale, `other` being parsed as the fallback.
+    Category(PluralCategory, &'static PluralRules),
     Fallback,
 }
 
-pub trait PluralNumber: FromStr + ToTokens + PartialOrd + Copy {
+pub trait PluralNumber: FromStr + ToTokens + PartialOrd + Copy + core::fmt::Display {
     const TYPE: PluralType;
 
     fn range_end_bound(self) -> Option<Bound<Self>>;
@@ -461,6 +629,82 @@ impl<T: PluralNumber> Plural<T> {
         Plural::Multiple(plurals)
     }
 
+    /// Parse a count that can also be a plural category of the language of the locale.
+    pub fn parse(s: &str, locale: &Rc<Key>) -> Result<Self> {
+        let s = s.trim();
+        if s.contains('|') {
+            return s
+                .split('|')
+                .map(|s| Self::parse(s, locale))
+                .collect::<Result<_>>()
+                .map(Self::Multiple)
+                .map(Self::flatten);
+        }
+        let Some(category) = PluralCategory::from_name(s) else {
+            return Self::new(s);
+        };
+        let rules =
+            PluralRules::for_locale(&locale.name).ok_or_else(|| Error::UnknownPluralRules {
+                locale: Rc::clone(locale),
+                category,
+            })?;
+        match category {
+            PluralCategory::Other => Ok(Self::Fallback),
+            category if rules.categories().any(|c| c == category) => {
+                Ok(Self::Category(category, rules))
+            }
+            category => Err(Error::InvalidPluralCategory {
+                locale: Rc::clone(locale),
+                category,
+                categories: rules.categories().collect(),
+            }),
+        }
+    }
+
+    fn has_category(&self) -> bool {
+        match self {
+            Plural::Category(..) => true,
+            Plural::Multiple(plurals) => plurals.iter().any(Self::has_category),
+            _ => false,
+        }
+    }
+
+    fn collect_categories(
+        &self,
+        rules: &mut Option<&'static PluralRules>,
+        categories: &mut Vec<PluralCategory>,
+    ) {
+        match self {
+            Plural::Category(category, category_rules) => {
+                *rules = Some(category_rules);
+                categories.push(*category);
+            }
+            Plural::Multiple(plurals) => {
+                for plural in plurals {
+                    plural.collect_categories(rules, categories);
+                }
+            }
+            Plural::Fallback => categories.push(PluralCategory::Other),
+            Plural::Exact(_) | Plural::Range { .. } => {}
+        }
+    }
+
+    /// Return the expression checking if `count` matches this plural.
+    fn to_condition(&self, count: &TokenStream) -> TokenStream {
+        match self {
+            Plural::Exact(exact) => quote!(#count == #exact),
+            Plural::Range { .. } => {
+                quote!(core::ops::RangeBounds::contains(&(#self), &#count))
+            }
+            Plural::Multiple(conditions) => {
+                let conditions = conditions.iter().map(|plural| plural.to_condition(count));
+                quote!(#(#conditions)||*)
+            }
+            Plural::Category(category, rules) => rules.condition(*category, count),
+            Plural::Fallback => quote!(true),
+        }
+    }
+
     pub fn new(s: &str) -> Result<Self> {
         let parse = |s: &str| {
             s.parse::<T>().map_err(|_| Error::PluralParse {
@@ -543,6 +787,7 @@ impl<T: PluralNumber> ToTokens for Plural<T> {
                 quote!(#start..#end)
             }
             Plural::Fallback => quote!(_),
+            Plural::Category(..) => unreachable!("plural categories are matched with a guard"),
             Plural::Multiple(matchs) => {
                 let mut matchs = matchs.iter().map(Self::to_token_stream);
                 if let Some(first) = matchs.next() {
@@ -559,10 +804,41 @@ impl<T: PluralNumber> ToTokens for Plural<T> {
     }
 }
 
+impl<T: PluralNumber> core::fmt::Display for Plural<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Plural::Exact(num) => write!(f, "{}", num),
+            Plural::Range { start, end } => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                match end {
+                    Bound::Included(end) => write!(f, "..={}", end),
+                    Bound::Excluded(end) => write!(f, "..{}", end),
+                    Bound::Unbounded => f.write_str(".."),
+                }
+            }
+            Plural::Multiple(plurals) => {
+                let mut plurals = plurals.iter();
+                if let Some(first) = plurals.next() {
+                    write!(f, "{}", first)?;
+                }
+                for plural in plurals {
+                    write!(f, " | {}", plural)?;
+                }
+                Ok(())
+            }
+            Plural::Category(category, _) => write!(f, "{}", category),
+            Plural::Fallback => f.write_str("_"),
+        }
+    }
+}
+
+/// Seed for the count of a plural, the locale giving the plural categories.
 #[derive(Debug, Clone, Copy)]
-struct PluralSeed<T>(PhantomData<T>);
+struct PluralSeed<'a, T>(&'a Rc<Key>, PhantomData<T>);
 
-impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<T> {
+impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<'_, T> {
     type Value = Plural<T>;
     fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
     where
@@ -572,7 +848,7 @@ impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<T> {
     }
 }
 
-impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<T> {
+impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<'_, T> {
     type Value = Plural<T>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -646,7 +922,7 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<T> {
     where
         E: serde::de::Error,
     {
-        Plural::new(s).map_err(serde::de::Error::custom)
+        Plural::parse(s, self.0).map_err(serde::de::Error::custom)
     }
 }
 
@@ -673,7 +949,33 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralStructSeed<'_, T> {
         )
     }
 
-    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.deserialize_map(None, map)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let Some(value) = seq.next_element_seed(self.0)? else {
+            return Err(serde::de::Error::invalid_length(0, &"at least 1 element"));
+        };
+        let plural = PluralSeed(self.0.top_locale_name, PhantomData).visit_seq(seq)?;
+
+        Ok((plural, value))
+    }
+}
+
+impl<'de, T: PluralNumber> PluralStructSeed<'_, T> {
+    /// Deserialize a plural from a map, `first_field` is the key already consumed from the map, if any.
+    fn deserialize_map<A>(
+        self,
+        first_field: Option<PluralField>,
+        mut map: A,
+    ) -> std::result::Result<(Plural<T>, ParsedValue), A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
@@ -701,13 +1003,19 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralStructSeed<'_, T> {
         }
         let mut plural = None;
         let mut value = None;
-        while let Some(field) = map.next_key()? {
+        let mut next_field = match first_field {
+            Some(field) => Some(field),
+            None => map.next_key()?,
+        };
+        while let Some(field) = next_field {
             match field {
                 PluralField::Plural => {
-                    deser_field(&mut plural, &mut map, PluralSeed(PhantomData), "count")?
+                    let seed = PluralSeed(self.0.top_locale_name, PhantomData);
+                    deser_field(&mut plural, &mut map, seed, "count")?
                 }
                 PluralField::Value => deser_field(&mut value, &mut map, self.0, "count")?,
             }
+            next_field = map.next_key()?;
         }
 
         let plural = plural.unwrap_or(Plural::Fallback); // if no count, fallback
@@ -715,18 +1023,6 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralStructSeed<'_, T> {
 
         Ok((plural, value))
     }
-
-    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
-    where
-        A: serde::de::SeqAccess<'de>,
-    {
-        let Some(value) = seq.next_element_seed(self.0)? else {
-            return Err(serde::de::Error::invalid_length(0, &"at least 1 element"));
-        };
-        let plural = PluralSeed(PhantomData).visit_seq(seq)?;
-
-        Ok((plural, value))
-    }
 }
 
 enum PluralField {
@@ -772,9 +1068,176 @@ impl<'de> serde::de::Visitor<'de> for PluralFieldVisitor {
     }
 }
 
+pub enum PluralsOrList {
+    Plurals(Plurals),
+    List(Vec<String>),
+    ObjectList(ObjectList),
+}
+
+/// A list of maps of strings, all the items having the same fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectList {
+    pub fields: Vec<Rc<Key>>,
+    pub items: Vec<ObjectItem>,
+}
+
+impl ObjectList {
+    /// Check if the given fields are the same as the fields of this list, regardless of the order.
+    pub fn has_fields<'a>(&self, fields: impl IntoIterator<Item = &'a Rc<Key>>) -> bool {
+        let mut count = 0;
+        for field in fields {
+            if !self.fields.contains(field) {
+                return false;
+            }
+            count += 1;
+        }
+        count == self.fields.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectItem(pub Vec<(Rc<Key>, String)>);
+
+impl ObjectItem {
+    pub fn iter(&self) -> impl Iterator<Item = &(Rc<Key>, String)> {
+        self.0.iter()
+    }
+
+    pub fn get(&self, field: &Key) -> Option<&str> {
+        self.iter()
+            .find(|(key, _)| **key == *field)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn from_map<'de, A>(
+        mut fields: Vec<(Rc<Key>, String)>,
+        mut map: A,
+    ) -> std::result::Result<Self, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        while let Some((key, value)) = map.next_entry::<Key, String>()? {
+            if fields.iter().any(|(k, _)| *k.as_ref() == key) {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate field `{}`",
+                    key.name
+                )));
+            }
+            fields.push((Rc::new(key), value));
+        }
+        Ok(ObjectItem(fields))
+    }
+
+    /// Build the first item of a list of objects from the buffered fields of a map, all values must be strings.
+    fn from_fields<E>(
+        buffered: Vec<(String, Option<PluralField>, BufferedField)>,
+    ) -> std::result::Result<Self, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut fields: Vec<(Rc<Key>, String)> = Vec::with_capacity(buffered.len());
+        for (key, _, field) in buffered {
+            let key = Key::try_new(&key).map_err(serde::de::Error::custom)?;
+            let BufferedField::Str(value) = field else {
+                return Err(serde::de::Error::custom(format!(
+                    "invalid type for field `{}`, expected a string",
+                    key.name
+                )));
+            };
+            if fields.iter().any(|(k, _)| *k.as_ref() == key) {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate field `{}`",
+                    key.name
+                )));
+            }
+            fields.push((Rc::new(key), value));
+        }
+        Ok(ObjectItem(fields))
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for ObjectItem {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ObjectItemVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ObjectItemVisitor {
+            type Value = ObjectItem;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a map of string fields")
+            }
+
+            fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                ObjectItem::from_map(vec![], map)
+            }
+        }
+
+        deserializer.deserialize_map(ObjectItemVisitor)
+    }
+}
+
 enum TypeOrPlural {
-    Type(PluralType),
+    /// The type and the string it was parsed from.
+    Type(PluralType, String),
     Plural((Plural<DefaultPluralType>, ParsedValue)),
+    ListItem(String),
+    Object(ObjectItem),
+}
+
+enum ListItem {
+    Str(String),
+    Other,
+}
+
+impl<'de> serde::de::Deserialize<'de> for ListItem {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ListItemVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ListItemVisitor {
+            type Value = ListItem;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(ListItem::Str(v.to_string()))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(ListItem::Other)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                while map
+                    .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+                    .is_some()
+                {}
+                Ok(ListItem::Other)
+            }
+        }
+
+        deserializer.deserialize_any(ListItemVisitor)
+    }
 }
 
 struct TypeOrPluralSeed<'a>(pub ParsedValueSeed<'a>);
@@ -796,7 +1259,7 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             formatter,
-            "either a string describing a numerical type or a plural"
+            "either a string describing a numerical type, a plural or a string"
         )
     }
 
@@ -804,29 +1267,47 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
     where
         E: serde::de::Error,
     {
-        match v.trim() {
-            "i8" => Ok(TypeOrPlural::Type(PluralType::I8)),
-            "i16" => Ok(TypeOrPlural::Type(PluralType::I16)),
-            "i32" => Ok(TypeOrPlural::Type(PluralType::I32)),
-            "i64" => Ok(TypeOrPlural::Type(PluralType::I64)),
-            "u8" => Ok(TypeOrPlural::Type(PluralType::U8)),
-            "u16" => Ok(TypeOrPlural::Type(PluralType::U16)),
-            "u32" => Ok(TypeOrPlural::Type(PluralType::U32)),
-            "u64" => Ok(TypeOrPlural::Type(PluralType::U64)),
-            "f32" => Ok(TypeOrPlural::Type(PluralType::F32)),
-            "f64" => Ok(TypeOrPlural::Type(PluralType::F64)),
-            _ => Err(serde::de::Error::custom(Error::InvalidPluralType(
-                v.to_string(),
-            ))),
-        }
+        let plural_type = match v.trim() {
+            "i8" => PluralType::I8,
+            "i16" => PluralType::I16,
+            "i32" => PluralType::I32,
+            "i64" => PluralType::I64,
+            "u8" => PluralType::U8,
+            "u16" => PluralType::U16,
+            "u32" => PluralType::U32,
+            "u64" => PluralType::U64,
+            "f32" => PluralType::F32,
+            "f64" => PluralType::F64,
+            _ => return Ok(TypeOrPlural::ListItem(v.to_string())),
+        };
+        Ok(TypeOrPlural::Type(plural_type, v.to_string()))
     }
 
-    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
-        let plural_seed = PluralStructSeed::<DefaultPluralType>(self.0, PhantomData);
-        plural_seed.visit_map(map).map(TypeOrPlural::Plural)
+        // a map with only "count" and "value" fields is a plural, else it's the first item of a list of objects.
+        // the decision needs every key, so the fields are buffered before being parsed.
+        let mut fields = vec![];
+        while let Some(key) = map.next_key::<String>()? {
+            let plural_field = match key.trim() {
+                "count" => Some(PluralField::Plural),
+                "value" => Some(PluralField::Value),
+                _ => None,
+            };
+            let value = map.next_value_seed(BufferedFieldSeed(plural_field.as_ref(), self.0))?;
+            fields.push((key, plural_field, value));
+        }
+
+        if fields
+            .iter()
+            .all(|(_, plural_field, _)| plural_field.is_some())
+        {
+            self.plural_from_fields(fields).map(TypeOrPlural::Plural)
+        } else {
+            ObjectItem::from_fields(fields).map(TypeOrPlural::Object)
+        }
     }
 
     fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
@@ -838,6 +1319,232 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
     }
 }
 
+impl TypeOrPluralSeed<'_> {
+    /// Build a plural from the buffered fields of a map, all the fields are either "count" or "value".
+    fn plural_from_fields<E>(
+        self,
+        fields: Vec<(String, Option<PluralField>, BufferedField)>,
+    ) -> std::result::Result<(Plural<DefaultPluralType>, ParsedValue), E>
+    where
+        E: serde::de::Error,
+    {
+        let mut plural = None;
+        let mut value = None;
+        for (_, plural_field, field) in fields {
+            match plural_field {
+                Some(PluralField::Plural) => {
+                    let count = match field {
+                        BufferedField::Str(s) => serde::de::Visitor::visit_str(
+                            PluralSeed(self.0.top_locale_name, PhantomData),
+                            &s,
+                        )?,
+                        BufferedField::Count(count) => count,
+                        BufferedField::Value(_) => unreachable!(),
+                    };
+                    if plural.replace(count).is_some() {
+                        return Err(serde::de::Error::duplicate_field("count"));
+                    }
+                }
+                Some(PluralField::Value) => {
+                    let parsed = match field {
+                        BufferedField::Str(s) => serde::de::Visitor::visit_str(self.0, &s)?,
+                        BufferedField::Value(parsed) => parsed,
+                        BufferedField::Count(_) => unreachable!(),
+                    };
+                    if value.replace(parsed).is_some() {
+                        return Err(serde::de::Error::duplicate_field("value"));
+                    }
+                }
+                None => unreachable!(),
+            }
+        }
+
+        let plural = plural.unwrap_or(Plural::Fallback); // if no count, fallback
+        let value = value.ok_or_else(|| serde::de::Error::missing_field("value"))?;
+
+        Ok((plural, value))
+    }
+}
+
+/// A field of the first map of a sequence, kept until every key is known.
+enum BufferedField {
+    Str(String),
+    /// A non string "count", only valid in a plural.
+    Count(Plural<DefaultPluralType>),
+    /// A non string "value", only valid in a plural.
+    Value(ParsedValue),
+}
+
+/// Seed for a field of the first map of a sequence, strings are kept as is as they can be either the field of an object or part of a plural.
+struct BufferedFieldSeed<'a, 'b>(Option<&'b PluralField>, ParsedValueSeed<'a>);
+
+impl<'de> serde::de::DeserializeSeed<'de> for BufferedFieldSeed<'_, '_> {
+    type Value = BufferedField;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'a> BufferedFieldSeed<'a, '_> {
+    fn plural_seed(&self) -> PluralSeed<'a, DefaultPluralType> {
+        PluralSeed(self.1.top_locale_name, PhantomData)
+    }
+
+    fn other<E>(self, unexp: serde::de::Unexpected) -> std::result::Result<BufferedField, E>
+    where
+        E: serde::de::Error,
+    {
+        Err(serde::de::Error::invalid_type(unexp, &self))
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for BufferedFieldSeed<'_, '_> {
+    type Value = BufferedField;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.0 {
+            Some(PluralField::Plural) => self.plural_seed().expecting(formatter),
+            Some(PluralField::Value) => write!(formatter, "a value"),
+            None => write!(formatter, "a string"),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(BufferedField::Str(v.to_string()))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.0 {
+            Some(PluralField::Value) => self.1.visit_bool(v).map(BufferedField::Value),
+            _ => self.other(serde::de::Unexpected::Bool(v)),
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.0 {
+            Some(PluralField::Plural) => self.plural_seed().visit_i64(v).map(BufferedField::Count),
+            Some(PluralField::Value) => self.1.visit_i64(v).map(BufferedField::Value),
+            None => self.other(serde::de::Unexpected::Signed(v)),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.0 {
+            Some(PluralField::Plural) => self.plural_seed().visit_u64(v).map(BufferedField::Count),
+            Some(PluralField::Value) => self.1.visit_u64(v).map(BufferedField::Value),
+            None => self.other(serde::de::Unexpected::Unsigned(v)),
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.0 {
+            Some(PluralField::Plural) => self.plural_seed().visit_f64(v).map(BufferedField::Count),
+            Some(PluralField::Value) => self.1.visit_f64(v).map(BufferedField::Value),
+            None => self.other(serde::de::Unexpected::Float(v)),
+        }
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.0 {
+            Some(PluralField::Value) => self.1.visit_unit().map(BufferedField::Value),
+            _ => self.other(serde::de::Unexpected::Unit),
+        }
+    }
+
+    fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        match self.0 {
+            Some(PluralField::Plural) => {
+                self.plural_seed().visit_seq(seq).map(BufferedField::Count)
+            }
+            Some(PluralField::Value) => self.1.visit_seq(seq).map(BufferedField::Value),
+            None => self.other(serde::de::Unexpected::Seq),
+        }
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        match self.0 {
+            Some(PluralField::Value) => self.1.visit_map(map).map(BufferedField::Value),
+            _ => self.other(serde::de::Unexpected::Map),
+        }
+    }
+}
+
+enum PluralOrStr<T> {
+    Plural((Plural<T>, ParsedValue)),
+    Str(String),
+}
+
+/// Deserialize the element following the name of a type, a plural of this type or the second string of a list.
+struct PluralOrStrSeed<'a, T>(PluralStructSeed<'a, T>);
+
+impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralOrStrSeed<'_, T> {
+    type Value = PluralOrStr<T>;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralOrStrSeed<'_, T> {
+    type Value = PluralOrStr<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either a plural or a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralOrStr::Str(v.to_string()))
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.0.visit_map(map).map(PluralOrStr::Plural)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        self.0.visit_seq(seq).map(PluralOrStr::Plural)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -941,6 +1648,83 @@ mod tests {
 
         assert_eq!(plural, Plural::Fallback);
     }
+
+    #[cfg(feature = "json_files")]
+    fn parse_value(json: &str) -> std::result::Result<ParsedValue, String> {
+        use serde::de::DeserializeSeed;
+
+        let locale = Rc::new(Key::new("en").unwrap());
+        let key = Rc::new(Key::new("key").unwrap());
+        let key_path = KeyPath::new(None);
+        let seed = ParsedValueSeed {
+            top_locale_name: &locale,
+            in_plural: false,
+            key_path: &key_path,
+            key: &key,
+            value_options: Default::default(),
+        };
+        seed.deserialize(&mut serde_json::Deserializer::from_str(json))
+            .map_err(|err| err.to_string())
+    }
+
+    #[cfg(feature = "json_files")]
+    #[test]
+    fn test_list_starting_with_type_name() {
+        assert_eq!(
+            parse_value(r#"["u8", "u16", "u32"]"#),
+            Ok(ParsedValue::List(vec![
+                "u8".to_string(),
+                "u16".to_string(),
+                "u32".to_string()
+            ]))
+        );
+        assert_eq!(
+            parse_value(r#"["f64"]"#),
+            Ok(ParsedValue::List(vec!["f64".to_string()]))
+        );
+        let plurals = parse_value(r#"["u8", ["zero", 0], ["many", "_"]]"#);
+        assert!(
+            matches!(plurals, Ok(ParsedValue::Plural(Plurals::U8(ref plurals))) if plurals.len() == 2),
+            "{:?}",
+            plurals
+        );
+    }
+
+    #[cfg(feature = "json_files")]
+    #[test]
+    fn test_invalid_plural_type() {
+        let err = parse_value(r#"["i33", ["zero", 0], ["many", "_"]]"#).unwrap_err();
+        assert!(err.contains("invalid plural type \"i33\""), "{}", err);
+    }
+
+    #[cfg(feature = "json_files")]
+    #[test]
+    fn test_plural_starting_with_value() {
+        let plurals =
+            parse_value(r#"[{ "value": "zero", "count": 0 }, { "value": "other", "count": "_" }]"#);
+        assert!(
+            matches!(plurals, Ok(ParsedValue::Plural(Plurals::I32(ref plurals))) if plurals.len() == 2),
+            "{:?}",
+            plurals
+        );
+    }
+
+    #[cfg(feature = "json_files")]
+    #[test]
+    fn test_object_list_with_value_field() {
+        let Ok(ParsedValue::ObjectList(list)) = parse_value(
+            r#"[{ "value": "fr", "label": "Français" }, { "label": "English", "value": "en" }]"#,
+        ) else {
+            panic!("expected a list of objects");
+        };
+        let value = Key::new("value").unwrap();
+        let values: Vec<_> = list.items.iter().map(|item| item.get(&value)).collect();
+        assert_eq!(values, [Some("fr"), Some("en")]);
+
+        let err =
+            parse_value(r#"[{ "value": "fr", "label": "Français", "count": 1 }]"#).unwrap_err();
+        assert!(err.contains("invalid type for field `count`"), "{}", err);
+    }
 }
 
 mod plural_number_impl {
@@ -0,0 +1,472 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+/// A CLDR plural category, `other` being the fallback `_` of the plurals.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "zero" => Some(Self::Zero),
+            "one" => Some(Self::One),
+            "two" => Some(Self::Two),
+            "few" => Some(Self::Few),
+            "many" => Some(Self::Many),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl core::fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The cardinal plural rules of a group of languages, from the CLDR.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct PluralRules {
+    pub languages: &'static [&'static str],
+    /// The condition of every category but `other`, in the CLDR syntax.
+    pub rules: &'static [(PluralCategory, &'static str)],
+    /// The categories only matching numbers with visible fraction digits, not required for integer plurals.
+    pub decimal_only: &'static [PluralCategory],
+}
+
+use PluralCategory::*;
+
+const MILLIONS: &str = "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5";
+
+#[rustfmt::skip]
+static RULES: &[PluralRules] = &[
+    PluralRules {
+        languages: &["bm", "bo", "dz", "hnj", "id", "ig", "ii", "ja", "jbo", "jv", "jw", "kde", "kea", "km", "ko", "lkt", "lo", "ms", "my", "nqo", "osa", "sah", "ses", "sg", "su", "th", "to", "tpi", "vi", "wo", "yo", "yue", "zh"],
+        rules: &[],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ast", "de", "en", "et", "fi", "fy", "gl", "ia", "io", "lij", "nl", "sc", "sv", "sw", "ur", "yi"],
+        rules: &[(One, "i = 1 and v = 0")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["af", "an", "asa", "az", "bal", "bem", "bez", "bg", "brx", "ce", "cgg", "chr", "ckb", "dv", "ee", "el", "eo", "eu", "fo", "fur", "gsw", "ha", "haw", "hu", "jgo", "jmc", "ka", "kaj", "kcg", "kk", "kkj", "kl", "ks", "ksb", "ku", "ky", "lb", "lg", "mas", "mgo", "ml", "mn", "mr", "nah", "nb", "nd", "ne", "nn", "nnh", "no", "nr", "ny", "nyn", "om", "or", "os", "pap", "ps", "rm", "rof", "rwk", "saq", "sd", "sdh", "seh", "sn", "so", "sq", "ss", "ssy", "st", "syr", "ta", "te", "teo", "tig", "tk", "tn", "tr", "ts", "ug", "uz", "ve", "vo", "vun", "wae", "xh", "xog"],
+        rules: &[(One, "n = 1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["am", "as", "bn", "doi", "fa", "gu", "hi", "kn", "pcm", "zu"],
+        rules: &[(One, "i = 0 or n = 1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ff", "hy", "kab"],
+        rules: &[(One, "i = 0,1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ak", "bho", "guw", "ln", "mg", "nso", "pa", "ti", "wa"],
+        rules: &[(One, "n = 0..1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["si"],
+        rules: &[(One, "n = 0,1 or i = 0 and f = 1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["da"],
+        rules: &[(One, "n = 1 or t != 0 and i = 0,1")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["is"],
+        rules: &[(One, "t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["mk"],
+        rules: &[(One, "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["fil", "tl"],
+        rules: &[(One, "v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["fr"],
+        rules: &[(One, "i = 0,1"), (Many, MILLIONS)],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["es"],
+        rules: &[(One, "n = 1"), (Many, MILLIONS)],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ca", "it", "pt-pt", "vec"],
+        rules: &[(One, "i = 1 and v = 0"), (Many, MILLIONS)],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["pt"],
+        rules: &[(One, "i = 0..1"), (Many, MILLIONS)],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["lv", "prg"],
+        rules: &[
+            (Zero, "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19"),
+            (One, "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ro", "mo"],
+        rules: &[(One, "i = 1 and v = 0"), (Few, "v != 0 or n = 0 or n != 1 and n % 100 = 1..19")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["bs", "hr", "sh", "sr"],
+        rules: &[
+            (One, "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ru", "uk"],
+        rules: &[
+            (One, "v = 0 and i % 10 = 1 and i % 100 != 11"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (Many, "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["be"],
+        rules: &[
+            (One, "n % 10 = 1 and n % 100 != 11"),
+            (Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+            (Many, "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["pl"],
+        rules: &[
+            (One, "i = 1 and v = 0"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (Many, "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["cs", "sk"],
+        rules: &[(One, "i = 1 and v = 0"), (Few, "i = 2..4 and v = 0"), (Many, "v != 0")],
+        decimal_only: &[Many],
+    },
+    PluralRules {
+        languages: &["lt"],
+        rules: &[
+            (One, "n % 10 = 1 and n % 100 != 11..19"),
+            (Few, "n % 10 = 2..9 and n % 100 != 11..19"),
+            (Many, "f != 0"),
+        ],
+        decimal_only: &[Many],
+    },
+    PluralRules {
+        languages: &["sl"],
+        rules: &[
+            (One, "v = 0 and i % 100 = 1"),
+            (Two, "v = 0 and i % 100 = 2"),
+            (Few, "v = 0 and i % 100 = 3..4 or v != 0"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["dsb", "hsb"],
+        rules: &[
+            (One, "v = 0 and i % 100 = 1 or f % 100 = 1"),
+            (Two, "v = 0 and i % 100 = 2 or f % 100 = 2"),
+            (Few, "v = 0 and i % 100 = 3..4 or f % 100 = 3..4"),
+        ],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["he"],
+        rules: &[(One, "i = 1 and v = 0 or i = 0 and v != 0"), (Two, "i = 2 and v = 0")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["gd"],
+        rules: &[(One, "n = 1,11"), (Two, "n = 2,12"), (Few, "n = 3..10,13..19")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ga"],
+        rules: &[(One, "n = 1"), (Two, "n = 2"), (Few, "n = 3..6"), (Many, "n = 7..10")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["mt"],
+        rules: &[(One, "n = 1"), (Two, "n = 2"), (Few, "n = 0 or n % 100 = 3..10"), (Many, "n % 100 = 11..19")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["ar", "ars"],
+        rules: &[(Zero, "n = 0"), (One, "n = 1"), (Two, "n = 2"), (Few, "n % 100 = 3..10"), (Many, "n % 100 = 11..99")],
+        decimal_only: &[],
+    },
+    PluralRules {
+        languages: &["cy"],
+        rules: &[(Zero, "n = 0"), (One, "n = 1"), (Two, "n = 2"), (Few, "n = 3"), (Many, "n = 6")],
+        decimal_only: &[],
+    },
+];
+
+impl PluralRules {
+    /// Return the rules of the language of the locale, the regional subtags being removed until a language match (`pt-BR` use the rules of `pt`).
+    pub fn for_locale(locale: &str) -> Option<&'static Self> {
+        let mut tag = locale.to_ascii_lowercase().replace('_', "-");
+        loop {
+            if let Some(rules) = RULES.iter().find(|rules| rules.languages.contains(&&*tag)) {
+                return Some(rules);
+            }
+            let (parent, _) = tag.rsplit_once('-')?;
+            tag = parent.to_string();
+        }
+    }
+
+    /// Return the categories of the language, `other` last.
+    pub fn categories(&self) -> impl Iterator<Item = PluralCategory> + '_ {
+        self.rules
+            .iter()
+            .map(|(category, _)| *category)
+            .chain(Some(Other))
+    }
+
+    /// Return the categories that must be declared in a plural of the given number kind.
+    pub fn required_categories(&self, is_float: bool) -> impl Iterator<Item = PluralCategory> + '_ {
+        self.categories()
+            .filter(move |category| is_float || !self.decimal_only.contains(category))
+    }
+
+    /// Return the category of the integer, evaluating the rules at compile time.
+    #[cfg(any(feature = "po_files", test))]
+    pub fn category_of_integer(&self, n: u64) -> PluralCategory {
+        self.rules
+            .iter()
+            .find(|(_, rule)| eval_rule(rule, n))
+            .map_or(Other, |(category, _)| *category)
+    }
+
+    /// Return the expression checking if the number in `count` is in the category.
+    pub fn condition(&self, category: PluralCategory, count: &TokenStream) -> TokenStream {
+        let condition = match category {
+            Other if self.rules.is_empty() => quote!(true),
+            Other => {
+                let others = join(
+                    self.rules.iter().map(|(_, rule)| compile_rule(rule)),
+                    quote!(||),
+                );
+                quote!(!#others)
+            }
+            category => self
+                .rules
+                .iter()
+                .find(|(c, _)| *c == category)
+                .map_or_else(|| quote!(false), |(_, rule)| compile_rule(rule)),
+        };
+        quote! {
+            {
+                let __operands = leptos_i18n::__private::PluralOperands::from(#count);
+                #condition
+            }
+        }
+    }
+}
+
+/// Compile a rule of the CLDR syntax (`i % 10 = 2..4 and v = 0 or ...`) to an expression on `__operands`.
+///
+/// The rules are static, so any syntax error is a bug in the table.
+fn compile_rule(rule: &str) -> TokenStream {
+    let or_conditions = rule.split(" or ").map(|and_condition| {
+        join(
+            and_condition.split(" and ").map(compile_relation),
+            quote!(&&),
+        )
+    });
+    join(or_conditions, quote!(||))
+}
+
+/// Join the expressions with the operator, in parentheses.
+fn join(exprs: impl Iterator<Item = TokenStream>, op: TokenStream) -> TokenStream {
+    let joined = exprs
+        .reduce(|joined, expr| quote!(#joined #op #expr))
+        .unwrap_or_default();
+    quote!((#joined))
+}
+
+fn compile_relation(relation: &str) -> TokenStream {
+    let (expr, negate, ranges) = if let Some((expr, ranges)) = relation.split_once("!=") {
+        (expr, true, ranges)
+    } else {
+        let (expr, ranges) = relation
+            .split_once('=')
+            .unwrap_or_else(|| panic!("invalid plural rule relation {:?}", relation));
+        (expr, false, ranges)
+    };
+
+    let (operand, modulo) = match expr.split_once('%') {
+        Some((operand, modulo)) => (operand.trim(), Some(parse_number(modulo))),
+        None => (expr.trim(), None),
+    };
+    let operand = format_ident!("{}", operand);
+    let value = match modulo {
+        Some(modulo) => quote!((__operands.#operand % #modulo)),
+        None => quote!(__operands.#operand),
+    };
+
+    let matches = ranges.split(',').map(|range| match range.split_once("..") {
+        Some((start, end)) => {
+            let (start, end) = (parse_number(start), parse_number(end));
+            quote!((#value.fract() == 0.0 && #value >= #start && #value <= #end))
+        }
+        None => {
+            let exact = parse_number(range);
+            quote!((#value == #exact))
+        }
+    });
+    let matches = join(matches, quote!(||));
+    if negate {
+        quote!(!#matches)
+    } else {
+        matches
+    }
+}
+
+/// Evaluate a rule of the CLDR syntax for an integer, whose operands other than `n` and `i` are `0`.
+#[cfg(any(feature = "po_files", test))]
+fn eval_rule(rule: &str, n: u64) -> bool {
+    rule.split(" or ").any(|and_condition| {
+        and_condition
+            .split(" and ")
+            .all(|relation| eval_relation(relation, n))
+    })
+}
+
+#[cfg(any(feature = "po_files", test))]
+fn eval_relation(relation: &str, n: u64) -> bool {
+    let (expr, negate, ranges) = if let Some((expr, ranges)) = relation.split_once("!=") {
+        (expr, true, ranges)
+    } else {
+        let (expr, ranges) = relation
+            .split_once('=')
+            .unwrap_or_else(|| panic!("invalid plural rule relation {:?}", relation));
+        (expr, false, ranges)
+    };
+
+    let (operand, modulo) = match expr.split_once('%') {
+        Some((operand, modulo)) => (operand.trim(), Some(parse_integer(modulo))),
+        None => (expr.trim(), None),
+    };
+    let operand = match operand {
+        "n" | "i" => n,
+        _ => 0,
+    };
+    let value = modulo.map_or(operand, |modulo| operand % modulo);
+
+    let matches = ranges.split(',').any(|range| match range.split_once("..") {
+        Some((start, end)) => (parse_integer(start)..=parse_integer(end)).contains(&value),
+        None => value == parse_integer(range),
+    });
+    matches != negate
+}
+
+#[cfg(any(feature = "po_files", test))]
+fn parse_integer(s: &str) -> u64 {
+    s.trim()
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid number {:?} in plural rule", s))
+}
+
+fn parse_number(s: &str) -> Literal {
+    let n: f64 = s
+        .trim()
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid number {:?} in plural rule", s));
+    Literal::f64_suffixed(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_for_locale() {
+        let en = PluralRules::for_locale("en-GB").unwrap();
+        assert!(en.languages.contains(&"en"));
+        let pt_pt = PluralRules::for_locale("pt_PT").unwrap();
+        assert!(pt_pt.languages.contains(&"pt-pt"));
+        let pt_br = PluralRules::for_locale("pt-BR").unwrap();
+        assert!(pt_br.languages.contains(&"pt"));
+        assert!(PluralRules::for_locale("xx").is_none());
+
+        let ru = PluralRules::for_locale("ru").unwrap();
+        let categories = ru.categories().collect::<Vec<_>>();
+        assert_eq!(categories, [One, Few, Many, Other]);
+        let cs = PluralRules::for_locale("cs").unwrap();
+        let required = cs.required_categories(false).collect::<Vec<_>>();
+        assert_eq!(required, [One, Few, Other]);
+    }
+
+    #[test]
+    fn category_of_integer() {
+        let ru = PluralRules::for_locale("ru").unwrap();
+        let categories = [1, 2, 5, 11, 21, 22, 112]
+            .map(|n| ru.category_of_integer(n))
+            .to_vec();
+        assert_eq!(categories, [One, Few, Many, Many, One, Few, Many]);
+        let fr = PluralRules::for_locale("fr").unwrap();
+        assert_eq!(fr.category_of_integer(0), One);
+        assert_eq!(fr.category_of_integer(2), Other);
+        assert_eq!(fr.category_of_integer(1_000_000), Many);
+    }
+
+    #[test]
+    fn every_rule_compiles() {
+        // panics on syntax errors in the table.
+        for rules in RULES {
+            for category in rules.categories() {
+                rules.condition(category, &quote!(count));
+            }
+            for (_, rule) in rules.rules {
+                for relation in rule.split(" or ").flat_map(|rule| rule.split(" and ")) {
+                    eval_relation(relation, 0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,822 @@
+//! Conversion of gettext (`.po`) catalogs to the values of the other formats.
+//!
+//! - the `msgid` of a message is its key, and its `msgctxt` the path of the subkeys containing it (`home.menu`),
+//! - the `msgstr` is the value, the untranslated and fuzzy messages being skipped so they are missing keys,
+//! - the forms of a message with a `msgid_plural` become a plural on the CLDR categories of the locale,
+//!   matched to the forms by evaluating the expression of the `Plural-Forms` header on integers,
+//! - the placeholders of the `c-format`, `python-format` and `python-brace-format` messages become variables,
+//!   the `%d` of the plurals being the count,
+//! - the extracted comments (`#.`) become the description of the message.
+
+use toml::{map::Map, Value};
+
+use super::plural_rules::{PluralCategory, PluralRules};
+
+/// The counts the forms are evaluated for to match them to the plural categories.
+const PLURAL_SAMPLES: std::ops::RangeInclusive<u64> = 0..=1000;
+/// Counts above the samples, for the categories of large numbers (`many` in French).
+const LARGE_PLURAL_SAMPLES: &[u64] = &[1_000_000, 2_000_000];
+
+/// Parse the gettext catalog into a table of values, `locale` giving the plural categories the forms are matched to.
+pub fn parse(source: &str, locale: &str) -> Result<Value, String> {
+    let entries = parse_entries(source)?;
+    let mut plural_forms = None;
+    let mut table = Map::new();
+    for entry in entries {
+        if entry.obsolete {
+            continue;
+        }
+        let Some(id) = &entry.id else {
+            return Err(format!("line {}: message without msgid", entry.line));
+        };
+        if id.is_empty() {
+            plural_forms = PluralForms::from_header(entry.msgstr(0))
+                .map_err(|err| format!("line {}: in the header: {}", entry.line, err))?;
+            continue;
+        }
+        let value = entry
+            .to_value(plural_forms.as_ref(), locale)
+            .map_err(|err| format!("line {}: in message {:?}: {}", entry.line, id, err))?;
+        let Some(value) = value else {
+            continue;
+        };
+        insert(&mut table, &entry, id, value)
+            .map_err(|err| format!("line {}: {}", entry.line, err))?;
+    }
+    Ok(Value::Table(table))
+}
+
+/// Insert the value of the message in the subkeys of its context, with its description.
+fn insert(
+    table: &mut Map<String, Value>,
+    entry: &Entry,
+    id: &str,
+    value: Value,
+) -> Result<(), String> {
+    let mut table = table;
+    for key in entry.context.iter().flat_map(|context| context.split('.')) {
+        let subkeys = table
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Table(Map::new()));
+        let Value::Table(subkeys) = subkeys else {
+            return Err(format!(
+                "the context {:?} is also a message",
+                entry.context.as_deref().unwrap_or_default()
+            ));
+        };
+        table = subkeys;
+    }
+    if table.insert(id.to_string(), value).is_some() {
+        return Err(format!("message {:?} is declared twice", id));
+    }
+    if !entry.comments.is_empty() {
+        table.insert(format!("@{}", id), Value::String(entry.comments.join("\n")));
+    }
+    Ok(())
+}
+
+// -----------------------------------------
+// Entries
+// -----------------------------------------
+
+#[derive(Default)]
+struct Entry {
+    line: usize,
+    context: Option<String>,
+    id: Option<String>,
+    id_plural: Option<String>,
+    /// The translations by index, only the index `0` without plurals.
+    msgstr: Vec<(usize, String)>,
+    comments: Vec<String>,
+    flags: Vec<String>,
+    obsolete: bool,
+}
+
+/// The field the continuation lines of a string are appended to.
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+fn parse_entries(source: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = vec![];
+    let mut entry = Entry::default();
+    let mut field = None;
+    for (i, line) in source.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            // the comments not followed by a message are dropped.
+            if !entry.msgstr.is_empty() || entry.id.is_none() {
+                finish_entry(&mut entries, &mut entry);
+            }
+            field = None;
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix('#') {
+            // a comment after the strings starts the next message.
+            if !entry.msgstr.is_empty() {
+                finish_entry(&mut entries, &mut entry);
+                field = None;
+            }
+            if rest.starts_with('~') {
+                entry.obsolete = true;
+            } else if let Some(comment) = rest.strip_prefix('.') {
+                entry.comments.push(comment.trim().to_string());
+            } else if let Some(flags) = rest.strip_prefix(',') {
+                entry
+                    .flags
+                    .extend(flags.split(',').map(|flag| flag.trim().to_string()));
+            }
+            continue;
+        }
+        if line.starts_with('"') {
+            let s = unquote(line).map_err(|err| format!("line {}: {}", line_number, err))?;
+            let target = match field {
+                Some(Field::Context) => entry.context.as_mut(),
+                Some(Field::Id) => entry.id.as_mut(),
+                Some(Field::IdPlural) => entry.id_plural.as_mut(),
+                Some(Field::Str(index)) => entry
+                    .msgstr
+                    .iter_mut()
+                    .find(|(i, _)| *i == index)
+                    .map(|(_, s)| s),
+                None => None,
+            };
+            let Some(target) = target else {
+                return Err(format!("line {}: unexpected string", line_number));
+            };
+            target.push_str(&s);
+            continue;
+        }
+
+        let (keyword, rest) = line
+            .split_once(|c: char| c.is_whitespace())
+            .ok_or_else(|| format!("line {}: expected a keyword and a string", line_number))?;
+        let s = unquote(rest.trim()).map_err(|err| format!("line {}: {}", line_number, err))?;
+        // `msgctxt` and `msgid` start the next message after the strings of the current one.
+        if matches!(keyword, "msgctxt" | "msgid") && !entry.msgstr.is_empty() {
+            finish_entry(&mut entries, &mut entry);
+        }
+        if entry.line == 0 {
+            entry.line = line_number;
+        }
+        let new_field = match keyword {
+            "msgctxt" => {
+                entry.context = Some(s);
+                Field::Context
+            }
+            "msgid" => {
+                entry.id = Some(s);
+                Field::Id
+            }
+            "msgid_plural" => {
+                entry.id_plural = Some(s);
+                Field::IdPlural
+            }
+            "msgstr" => {
+                entry.msgstr.push((0, s));
+                Field::Str(0)
+            }
+            keyword => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .and_then(|index| index.parse().ok())
+                    .ok_or_else(|| {
+                        format!("line {}: unknown keyword {:?}", line_number, keyword)
+                    })?;
+                entry.msgstr.push((index, s));
+                Field::Str(index)
+            }
+        };
+        field = Some(new_field);
+    }
+    finish_entry(&mut entries, &mut entry);
+    Ok(entries)
+}
+
+/// Push the entry if it has strings, and start the next one.
+fn finish_entry(entries: &mut Vec<Entry>, entry: &mut Entry) {
+    let entry = std::mem::take(entry);
+    if entry.id.is_some() || entry.context.is_some() {
+        entries.push(entry);
+    }
+}
+
+/// Remove the quotes of a string and interpret its escape sequences.
+fn unquote(s: &str) -> Result<String, String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(|| format!("expected a quoted string, found {:?}", s))?;
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('t') => unquoted.push('\t'),
+            Some('r') => unquoted.push('\r'),
+            Some('"') => unquoted.push('"'),
+            Some('\\') => unquoted.push('\\'),
+            Some(c) => return Err(format!("unknown escape sequence \\{} in {:?}", c, s)),
+            None => return Err(format!("unterminated escape sequence in {:?}", s)),
+        }
+    }
+    Ok(unquoted)
+}
+
+impl Entry {
+    fn msgstr(&self, index: usize) -> &str {
+        self.msgstr
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map_or("", |(_, s)| s)
+    }
+
+    fn format(&self) -> Option<Format> {
+        self.flags.iter().find_map(|flag| match flag.as_str() {
+            "c-format" => Some(Format::C),
+            "python-format" => Some(Format::Python),
+            "python-brace-format" => Some(Format::PythonBrace),
+            _ => None,
+        })
+    }
+
+    /// Return the value of the message, `None` if it is not translated or fuzzy.
+    fn to_value(
+        &self,
+        plural_forms: Option<&PluralForms>,
+        locale: &str,
+    ) -> Result<Option<Value>, String> {
+        if self.flags.iter().any(|flag| flag == "fuzzy") {
+            return Ok(None);
+        }
+        let format = self.format();
+        if self.id_plural.is_none() {
+            let msgstr = self.msgstr(0);
+            if msgstr.is_empty() {
+                return Ok(None);
+            }
+            return convert_placeholders(msgstr, format, false).map(|s| Some(Value::String(s)));
+        }
+
+        let germanic;
+        let plural_forms = match plural_forms {
+            Some(plural_forms) => plural_forms,
+            None => {
+                germanic = PluralForms::germanic();
+                &germanic
+            }
+        };
+        let forms = (0..plural_forms.nplurals)
+            .map(|index| self.msgstr(index))
+            .collect::<Vec<_>>();
+        if forms.iter().any(|form| form.is_empty()) {
+            return Ok(None);
+        }
+        let rules = PluralRules::for_locale(locale)
+            .ok_or_else(|| format!("no plural rules are known for the locale {:?}", locale))?;
+        let mut plurals = vec![];
+        for category in rules.required_categories(false) {
+            let index = plural_forms.form_of_category(rules, category);
+            let form = forms.get(index).ok_or_else(|| {
+                format!(
+                    "the plural expression returned the form {} but there is {} forms",
+                    index, plural_forms.nplurals
+                )
+            })?;
+            let form = convert_placeholders(form, format, true)?;
+            plurals.push(Value::Array(vec![
+                Value::String(form),
+                Value::String(category.name().to_string()),
+            ]));
+        }
+        Ok(Some(Value::Array(plurals)))
+    }
+}
+
+// -----------------------------------------
+// Placeholders
+// -----------------------------------------
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    C,
+    Python,
+    PythonBrace,
+}
+
+/// Convert the placeholders of the format to variables: `%s` and `%2$s` become `{{ arg1 }}` and `{{ arg2 }}`,
+/// `%(name)s` and `{name}` become `{{ name }}`, and the integers of the plurals (`%d`) become `{{ count }}`.
+fn convert_placeholders(
+    s: &str,
+    format: Option<Format>,
+    is_plural: bool,
+) -> Result<String, String> {
+    let Some(format) = format else {
+        return Ok(s.to_string());
+    };
+    let mut converted = String::with_capacity(s.len());
+    let mut position = 0;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (format, c) {
+            (Format::PythonBrace, '{') if chars.peek() == Some(&'{') => {
+                chars.next();
+                converted.push('{');
+            }
+            (Format::PythonBrace, '}') if chars.peek() == Some(&'}') => {
+                chars.next();
+                converted.push('}');
+            }
+            (Format::PythonBrace, '{') => {
+                let name = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let name = name.split([':', '!']).next().unwrap_or_default().trim();
+                position += 1;
+                push_variable(&mut converted, name, position);
+            }
+            (Format::C | Format::Python, '%') => {
+                if chars.peek() == Some(&'%') {
+                    chars.next();
+                    converted.push('%');
+                    continue;
+                }
+                let mut name = String::new();
+                if format == Format::Python && chars.peek() == Some(&'(') {
+                    chars.next();
+                    name = chars.by_ref().take_while(|c| *c != ')').collect();
+                }
+                let mut spec = String::new();
+                let conversion = loop {
+                    match chars.next() {
+                        Some(c) if c.is_ascii_alphabetic() && !"hlLqjztI".contains(c) => break c,
+                        Some(c) => spec.push(c),
+                        None => return Err(format!("unterminated placeholder in {:?}", s)),
+                    }
+                };
+                position += 1;
+                if let Some((index, _)) = spec.split_once('$') {
+                    name = format!("arg{}", index);
+                }
+                if name.is_empty() && is_plural && matches!(conversion, 'd' | 'i' | 'u') {
+                    name = "count".to_string();
+                }
+                push_variable(&mut converted, &name, position);
+            }
+            (_, c) => converted.push(c),
+        }
+    }
+    Ok(converted)
+}
+
+fn push_variable(s: &mut String, name: &str, position: usize) {
+    if name.is_empty() {
+        s.push_str(&format!("{{{{ arg{} }}}}", position));
+    } else if name.chars().all(|c| c.is_ascii_digit()) {
+        s.push_str(&format!("{{{{ arg{} }}}}", name));
+    } else {
+        s.push_str(&format!("{{{{ {} }}}}", name));
+    }
+}
+
+// -----------------------------------------
+// Plural forms
+// -----------------------------------------
+
+/// The `Plural-Forms` of the header: `nplurals=2; plural=(n != 1);`.
+struct PluralForms {
+    nplurals: usize,
+    plural: Expr,
+}
+
+impl PluralForms {
+    /// The plural forms of the catalogs without `Plural-Forms`, as gettext does.
+    fn germanic() -> Self {
+        PluralForms {
+            nplurals: 2,
+            plural: Expr::Binary(BinaryOp::Ne, Box::new(Expr::N), Box::new(Expr::Number(1))),
+        }
+    }
+
+    fn from_header(header: &str) -> Result<Option<Self>, String> {
+        let Some(plural_forms) = header
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Plural-Forms:"))
+        else {
+            return Ok(None);
+        };
+        let mut nplurals = None;
+        let mut plural = None;
+        for part in plural_forms.split(';') {
+            let Some((name, value)) = part.split_once('=') else {
+                continue;
+            };
+            match name.trim() {
+                "nplurals" => {
+                    nplurals = Some(
+                        value
+                            .trim()
+                            .parse::<usize>()
+                            .map_err(|_| format!("invalid nplurals {:?}", value.trim()))?,
+                    )
+                }
+                "plural" => plural = Some(Expr::parse(value)?),
+                _ => {}
+            }
+        }
+        match (nplurals, plural) {
+            (Some(nplurals), Some(plural)) if nplurals > 0 => {
+                Ok(Some(PluralForms { nplurals, plural }))
+            }
+            _ => Err(format!("invalid Plural-Forms {:?}", plural_forms.trim())),
+        }
+    }
+
+    /// Return the form used for most of the integers of the category,
+    /// or the last form for the categories without integers (`other` in Russian).
+    fn form_of_category(&self, rules: &PluralRules, category: PluralCategory) -> usize {
+        let mut counts = vec![0usize; self.nplurals + 1];
+        let samples = PLURAL_SAMPLES.chain(LARGE_PLURAL_SAMPLES.iter().copied());
+        for n in samples.filter(|n| rules.category_of_integer(*n) == category) {
+            let form = usize::try_from(self.plural.eval(n)).unwrap_or(usize::MAX);
+            counts[form.min(self.nplurals)] += 1;
+        }
+        counts
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, count)| **count > 0)
+            .max_by_key(|(_, count)| **count)
+            .map_or(self.nplurals - 1, |(form, _)| form)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// An expression of the C subset used by `Plural-Forms`.
+#[derive(Debug, PartialEq)]
+enum Expr {
+    N,
+    Number(i64),
+    Not(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Ternary(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn parse(s: &str) -> Result<Self, String> {
+        let tokens = tokenize(s)?;
+        let mut parser = ExprParser { tokens, pos: 0 };
+        let expr = parser.ternary()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(format!(
+                "unexpected {:?} in plural expression {:?}",
+                parser.tokens[parser.pos],
+                s.trim()
+            ));
+        }
+        Ok(expr)
+    }
+
+    fn eval(&self, n: u64) -> i64 {
+        let n = n as i64;
+        self.eval_inner(n)
+    }
+
+    fn eval_inner(&self, n: i64) -> i64 {
+        match self {
+            Expr::N => n,
+            Expr::Number(value) => *value,
+            Expr::Not(expr) => (expr.eval_inner(n) == 0) as i64,
+            Expr::Ternary(condition, then, otherwise) => {
+                if condition.eval_inner(n) != 0 {
+                    then.eval_inner(n)
+                } else {
+                    otherwise.eval_inner(n)
+                }
+            }
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (left.eval_inner(n), right.eval_inner(n));
+                match op {
+                    BinaryOp::Or => (left != 0 || right != 0) as i64,
+                    BinaryOp::And => (left != 0 && right != 0) as i64,
+                    BinaryOp::Eq => (left == right) as i64,
+                    BinaryOp::Ne => (left != right) as i64,
+                    BinaryOp::Lt => (left < right) as i64,
+                    BinaryOp::Le => (left <= right) as i64,
+                    BinaryOp::Gt => (left > right) as i64,
+                    BinaryOp::Ge => (left >= right) as i64,
+                    BinaryOp::Add => left.wrapping_add(right),
+                    BinaryOp::Sub => left.wrapping_sub(right),
+                    BinaryOp::Mul => left.wrapping_mul(right),
+                    BinaryOp::Div => left.checked_div(right).unwrap_or(0),
+                    BinaryOp::Rem => left.checked_rem(right).unwrap_or(0),
+                }
+            }
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<String>, String> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '0'..='9' => {
+                let mut number = c.to_string();
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    number.push(digit);
+                }
+                tokens.push(number);
+            }
+            'n' | '?' | ':' | '(' | ')' | '+' | '-' | '*' | '/' | '%' => tokens.push(c.to_string()),
+            '=' | '!' | '<' | '>' | '&' | '|' => {
+                let mut op = c.to_string();
+                if let Some(next) = chars.next_if(|next| matches!(next, '=' | '&' | '|')) {
+                    op.push(next);
+                }
+                tokens.push(op);
+            }
+            c => {
+                return Err(format!(
+                    "unexpected {:?} in plural expression {:?}",
+                    c,
+                    s.trim()
+                ))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser of the expression.
+struct ExprParser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn ternary(&mut self) -> Result<Expr, String> {
+        let condition = self.binary(0)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let then = self.ternary()?;
+        if !self.eat(":") {
+            return Err("expected `:` in plural expression".to_string());
+        }
+        let otherwise = self.ternary()?;
+        Ok(Expr::Ternary(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// The binary operators by precedence, from the lowest.
+    const LEVELS: &'static [&'static [(&'static str, BinaryOp)]] = &[
+        &[("||", BinaryOp::Or)],
+        &[("&&", BinaryOp::And)],
+        &[("==", BinaryOp::Eq), ("!=", BinaryOp::Ne)],
+        &[
+            ("<=", BinaryOp::Le),
+            (">=", BinaryOp::Ge),
+            ("<", BinaryOp::Lt),
+            (">", BinaryOp::Gt),
+        ],
+        &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+        &[
+            ("*", BinaryOp::Mul),
+            ("/", BinaryOp::Div),
+            ("%", BinaryOp::Rem),
+        ],
+    ];
+
+    fn binary(&mut self, level: usize) -> Result<Expr, String> {
+        let Some(operators) = Self::LEVELS.get(level) else {
+            return self.unary();
+        };
+        let mut left = self.binary(level + 1)?;
+        while let Some(&(_, op)) = operators
+            .iter()
+            .find(|(token, _)| self.peek() == Some(*token))
+        {
+            self.pos += 1;
+            let right = self.binary(level + 1)?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        if self.eat("(") {
+            let expr = self.ternary()?;
+            if !self.eat(")") {
+                return Err("expected `)` in plural expression".to_string());
+            }
+            return Ok(expr);
+        }
+        match self.peek() {
+            Some("n") => {
+                self.pos += 1;
+                Ok(Expr::N)
+            }
+            Some(number) if number.starts_with(|c: char| c.is_ascii_digit()) => {
+                let number = number
+                    .parse()
+                    .map_err(|_| format!("invalid number {:?} in plural expression", number))?;
+                self.pos += 1;
+                Ok(Expr::Number(number))
+            }
+            token => Err(format!("unexpected {:?} in plural expression", token)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    fn plural(forms: &[(&str, &str)]) -> Value {
+        Value::Array(
+            forms
+                .iter()
+                .map(|(form, category)| Value::Array(vec![string(form), string(category)]))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn messages_and_contexts() {
+        let source = r#"
+# translator comment
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+#. Shown on the home page
+#: src/home.rs:12
+msgid "welcome"
+msgstr "Bienvenue "
+"sur le site"
+
+msgctxt "home.menu"
+msgid "open"
+msgstr "Ouvrir \"tout\""
+
+msgid "untranslated"
+msgstr ""
+
+#, fuzzy
+msgid "fuzzy"
+msgstr "Pas sûr"
+
+#~ msgid "obsolete"
+#~ msgstr "Obsolète"
+"#;
+        let value = parse(source, "fr").unwrap();
+        assert_eq!(value["welcome"], string("Bienvenue sur le site"));
+        assert_eq!(value["@welcome"], string("Shown on the home page"));
+        assert_eq!(value["home"]["menu"]["open"], string("Ouvrir \"tout\""));
+        assert!(value.get("untranslated").is_none());
+        assert!(value.get("fuzzy").is_none());
+        assert!(value.get("obsolete").is_none());
+        assert!(value.get("").is_none());
+    }
+
+    #[test]
+    fn placeholders() {
+        let source = r#"
+#, c-format
+msgid "greeting"
+msgstr "Bonjour %s, vous avez %2$d ans à 100%%"
+
+#, python-format
+msgid "named"
+msgstr "Bonjour %(name)s"
+
+#, python-brace-format
+msgid "brace"
+msgstr "Bonjour {name} {{literal}}"
+
+msgid "plain"
+msgstr "Keep %s and {{ var }}"
+"#;
+        let value = parse(source, "fr").unwrap();
+        assert_eq!(
+            value["greeting"],
+            string("Bonjour {{ arg1 }}, vous avez {{ arg2 }} ans à 100%")
+        );
+        assert_eq!(value["named"], string("Bonjour {{ name }}"));
+        assert_eq!(value["brace"], string("Bonjour {{ name }} {literal}"));
+        assert_eq!(value["plain"], string("Keep %s and {{ var }}"));
+    }
+
+    #[test]
+    fn plural_forms() {
+        let source = r#"
+msgid ""
+msgstr ""
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#, c-format
+msgid "file"
+msgid_plural "files"
+msgstr[0] "%d файл"
+msgstr[1] "%d файла"
+msgstr[2] "%d файлов"
+"#;
+        let value = parse(source, "ru").unwrap();
+        assert_eq!(
+            value["file"],
+            plural(&[
+                ("{{ count }} файл", "one"),
+                ("{{ count }} файла", "few"),
+                ("{{ count }} файлов", "many"),
+                ("{{ count }} файлов", "other"),
+            ])
+        );
+
+        // without `Plural-Forms` the forms are the singular and the plural.
+        let source = r#"
+msgid "file"
+msgid_plural "files"
+msgstr[0] "un fichier"
+msgstr[1] "des fichiers"
+"#;
+        let value = parse(source, "fr").unwrap();
+        assert_eq!(
+            value["file"],
+            plural(&[
+                ("un fichier", "one"),
+                ("des fichiers", "many"),
+                ("des fichiers", "other"),
+            ])
+        );
+    }
+
+    #[test]
+    fn plural_expressions() {
+        let expr = Expr::parse("n==0 ? 0 : n==1 ? 1 : n%100>=3 && n%100<=10 ? 3 : 5").unwrap();
+        let forms = [0, 1, 3, 103, 11, 2].map(|n| expr.eval(n));
+        assert_eq!(forms, [0, 1, 3, 3, 5, 5]);
+        assert_eq!(Expr::parse("!(n > 1)").unwrap().eval(0), 1);
+        assert!(Expr::parse("n ==").is_err());
+        assert!(Expr::parse("x").is_err());
+    }
+
+    #[test]
+    fn invalid_catalogs() {
+        assert!(parse("msgid \"key\"\nmsgstr \"value", "en").is_err());
+        assert!(parse("msgid \"key\"\nmsgfoo \"value\"", "en").is_err());
+        assert!(parse(
+            "msgid \"key\"\nmsgstr \"a\"\n\nmsgid \"key\"\nmsgstr \"b\"",
+            "en"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::rc::Rc;
+
+use super::{
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+};
+
+/// Name of the locale generated with `generate-pseudo-locale`.
+pub const PSEUDO_LOCALE: &str = "pseudo";
+
+const OPEN: &str = "[";
+const CLOSE: &str = "]";
+const PADDING: &str = "~";
+
+impl LocalesOrNamespaces {
+    /// Pseudo-localize the values of the pseudo locale, copied from the default locale by inheritance.
+    pub fn pseudo_localize(&mut self, pseudo: &Rc<Key>) {
+        let locales = match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => namespaces
+                .iter_mut()
+                .flat_map(|namespace| namespace.locales.iter_mut())
+                .collect::<Vec<_>>(),
+            LocalesOrNamespaces::Locales(locales) => locales.iter_mut().collect(),
+        };
+        for locale in locales {
+            if &locale.name == pseudo {
+                pseudo_localize_keys(locale);
+            }
+        }
+    }
+}
+
+fn pseudo_localize_keys(locale: &mut Locale) {
+    for value in locale.keys.values_mut() {
+        pseudo_localize(value);
+    }
+}
+
+/// Accent the letters of the value, then pad it by about a third of its length and enclose it in brackets,
+/// so the untranslated strings, the truncated ones and the ones built by concatenation stand out.
+///
+/// The foreign keys are left untouched, they are resolved to the values of the pseudo locale.
+fn pseudo_localize(value: &mut ParsedValue) {
+    match value {
+        ParsedValue::Default | ParsedValue::ForeignKey(_) => {}
+        ParsedValue::Subkeys(subkeys) => pseudo_localize_keys(subkeys),
+        ParsedValue::String(s) => *s = pseudo_string(s),
+        ParsedValue::List(items) => {
+            for item in items {
+                *item = pseudo_string(item);
+            }
+        }
+        ParsedValue::ObjectList(list) => {
+            for item in &mut list.items {
+                for (_, value) in &mut item.0 {
+                    *value = pseudo_string(value);
+                }
+            }
+        }
+        ParsedValue::Plural(plurals) => {
+            let _: Result<_, ()> = plurals.try_for_each_value_mut(|value| {
+                pseudo_localize(value);
+                Ok(())
+            });
+        }
+        ParsedValue::Features { variants, default } => {
+            for (_, value) in variants {
+                pseudo_localize(value);
+            }
+            pseudo_localize(default);
+        }
+        ParsedValue::Select {
+            variants, other, ..
+        } => {
+            for (_, value) in variants {
+                pseudo_localize(value);
+            }
+            pseudo_localize(other);
+        }
+        ParsedValue::Variable(_)
+        | ParsedValue::TypedVariable { .. }
+        | ParsedValue::Formatted { .. }
+        | ParsedValue::Component { .. }
+        | ParsedValue::Bloc(_) => {
+            let len = accent_value(value);
+            let close = ParsedValue::String(close(len));
+            match value {
+                ParsedValue::Bloc(values) => {
+                    values.insert(0, ParsedValue::String(OPEN.to_string()));
+                    values.push(close);
+                }
+                value => {
+                    let inner = std::mem::take(value);
+                    *value = ParsedValue::Bloc(vec![
+                        ParsedValue::String(OPEN.to_string()),
+                        inner,
+                        close,
+                    ]);
+                }
+            }
+        }
+    }
+}
+
+/// Accent the strings of an interpolated value, returning the number of characters of its text.
+fn accent_value(value: &mut ParsedValue) -> usize {
+    match value {
+        ParsedValue::String(s) => {
+            *s = s.chars().map(accent).collect();
+            s.chars().count()
+        }
+        ParsedValue::Component { inner, .. } => accent_value(inner),
+        ParsedValue::Bloc(values) => values.iter_mut().map(accent_value).sum(),
+        _ => 0,
+    }
+}
+
+fn pseudo_string(s: &str) -> String {
+    if s.is_empty() {
+        return String::new();
+    }
+    let accented = s.chars().map(accent).collect::<String>();
+    format!("{}{}{}", OPEN, accented, close(s.chars().count()))
+}
+
+fn close(len: usize) -> String {
+    let padding = len.div_ceil(3);
+    if padding == 0 {
+        return CLOSE.to_string();
+    }
+    format!(" {}{}", PADDING.repeat(padding), CLOSE)
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Đ',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ŧ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'đ',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ṁ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ŧ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pseudo_strings() {
+        assert_eq!(pseudo_string("Hello"), "[Ĥéļļö ~~]");
+        assert_eq!(pseudo_string("Hi!"), "[Ĥî! ~]");
+        assert_eq!(pseudo_string(""), "");
+
+        let key = Rc::new(Key::new("count").unwrap());
+        let mut value = ParsedValue::Bloc(vec![
+            ParsedValue::String("You have ".to_string()),
+            ParsedValue::Variable(Rc::clone(&key)),
+            ParsedValue::String(" items".to_string()),
+        ]);
+        pseudo_localize(&mut value);
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("[".to_string()),
+                ParsedValue::String("Ýöû ĥåṽé ".to_string()),
+                ParsedValue::Variable(Rc::clone(&key)),
+                ParsedValue::String(" îŧéṁš".to_string()),
+                ParsedValue::String(" ~~~~~]".to_string()),
+            ])
+        );
+
+        let mut value = ParsedValue::Variable(Rc::clone(&key));
+        pseudo_localize(&mut value);
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("[".to_string()),
+                ParsedValue::Variable(key),
+                ParsedValue::String("]".to_string()),
+            ])
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+};
+
+/// Map the name of each variable selecting values to the variants declared for it in every key and locale.
+type SelectVariants = BTreeMap<String, BTreeSet<String>>;
+
+/// Create the `select` module, with an enum for each variable selecting values
+/// whose variants are all the ones declared for it and `other`.
+pub fn create_select_enums(locales: &LocalesOrNamespaces) -> TokenStream {
+    let mut selects = SelectVariants::new();
+    match locales {
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for locale in namespaces.iter().flat_map(|namespace| &namespace.locales) {
+                collect_locale(&mut selects, locale);
+            }
+        }
+        LocalesOrNamespaces::Locales(locales) => {
+            for locale in locales {
+                collect_locale(&mut selects, locale);
+            }
+        }
+    }
+    if selects.is_empty() {
+        return quote!();
+    }
+
+    let enums = selects.into_iter().filter_map(|(name, mut variants)| {
+        let ident = Key::new(&name)?.ident;
+        variants.insert(ParsedValue::SELECT_OTHER.to_string());
+        let mut idents: Vec<syn::Ident> = vec![];
+        let mut names = vec![];
+        for variant in variants {
+            let Some(key) = Key::new(&variant) else {
+                continue;
+            };
+            // variants converted to the same identifier, the first one is kept.
+            if !idents.contains(&key.ident) {
+                idents.push(key.ident);
+                names.push(variant);
+            }
+        }
+        let doc = format!(" The variants of the `{}` variable selecting values.", name);
+        Some(quote! {
+            #[doc = #doc]
+            #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+            #[allow(non_camel_case_types)]
+            pub enum #ident {
+                #(#idents,)*
+            }
+
+            impl #ident {
+                /// Return the name of the variant, as declared in the locale files.
+                pub const fn as_str(self) -> &'static str {
+                    match self {
+                        #(#ident::#idents => #names,)*
+                    }
+                }
+            }
+
+            impl leptos_i18n::SelectVariant for #ident {
+                fn variant(&self) -> &str {
+                    #ident::as_str(*self)
+                }
+            }
+        })
+    });
+
+    quote! {
+        /// The enums of the variables selecting values, the variants not declared in a locale selecting its `other` value.
+        pub mod select {
+            #(#enums)*
+        }
+    }
+}
+
+fn collect_locale(selects: &mut SelectVariants, locale: &Locale) {
+    for value in locale.keys.values() {
+        collect_value(selects, value);
+    }
+}
+
+fn collect_value(selects: &mut SelectVariants, value: &ParsedValue) {
+    match value {
+        ParsedValue::Select {
+            key,
+            variants,
+            other,
+        } => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            let names = selects.entry(name.to_string()).or_default();
+            names.extend(variants.iter().map(|(variant, _)| variant.clone()));
+            for (_, value) in variants {
+                collect_value(selects, value);
+            }
+            collect_value(selects, other);
+        }
+        ParsedValue::Subkeys(subkeys) => collect_locale(selects, subkeys),
+        ParsedValue::Plural(plurals) => {
+            for (_, value) in plurals.branches() {
+                collect_value(selects, value);
+            }
+        }
+        ParsedValue::Features { variants, default } => {
+            for (_, value) in variants {
+                collect_value(selects, value);
+            }
+            collect_value(selects, default);
+        }
+        ParsedValue::Component { inner, .. } => collect_value(selects, inner),
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                collect_value(selects, value);
+            }
+        }
+        // the values of the foreign keys are collected where they are declared.
+        ParsedValue::ForeignKey(_)
+        | ParsedValue::Default
+        | ParsedValue::String(_)
+        | ParsedValue::Variable(_)
+        | ParsedValue::TypedVariable { .. }
+        | ParsedValue::Formatted { .. }
+        | ParsedValue::List(_)
+        | ParsedValue::ObjectList(_) => {}
+    }
+}
@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+
+/// Arguments of `load_locales!`, empty for the main invocation or `shard = "name"` for a shard,
+/// with an optional `locale = path::to::Locale` pointing to the `Locale` of the main invocation,
+/// the `dynamic` flag to load the locales other than the default one at runtime,
+/// and an optional `export = "path"` to write the key manifest to.
+#[derive(Default)]
+pub struct LoadLocalesArgs {
+    pub shard: Option<String>,
+    pub locale: Option<syn::Path>,
+    pub dynamic: bool,
+    pub export: Option<String>,
+}
+
+impl Parse for LoadLocalesArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = LoadLocalesArgs::default();
+        while !input.is_empty() {
+            let name: syn::Ident = input.parse()?;
+            if name == "dynamic" {
+                if args.dynamic {
+                    return Err(syn::Error::new(name.span(), "duplicate argument"));
+                }
+                args.dynamic = true;
+                if !input.is_empty() {
+                    input.parse::<syn::Token![,]>()?;
+                }
+                continue;
+            }
+            input.parse::<syn::Token![=]>()?;
+            match name.to_string().as_str() {
+                "shard" if args.shard.is_none() => {
+                    args.shard = Some(input.parse::<syn::LitStr>()?.value());
+                }
+                "locale" if args.locale.is_none() => args.locale = Some(input.parse()?),
+                "export" if args.export.is_none() => {
+                    args.export = Some(input.parse::<syn::LitStr>()?.value());
+                }
+                "shard" | "locale" | "export" => {
+                    return Err(syn::Error::new(name.span(), "duplicate argument"));
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "unknown argument, expected `shard`, `locale`, `export` or `dynamic`",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        if args.shard.is_none() {
+            if let Some(locale) = &args.locale {
+                return Err(syn::Error::new_spanned(
+                    locale,
+                    "`locale` can only be used with `shard`",
+                ));
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Create the `i18n` module of a shard, with the keys of its namespaces for the `Locale` of the main invocation.
+pub fn create_shard_module(
+    locale: Option<&syn::Path>,
+    locale_type: TokenStream,
+    warnings: Option<TokenStream>,
+) -> TokenStream {
+    let locale = locale
+        .map(|locale| quote!(#locale))
+        .unwrap_or_else(|| quote!(crate::i18n::Locale));
+    quote! {
+        pub mod i18n {
+            pub use #locale as Locale;
+
+            #locale_type
+
+            #[inline]
+            pub fn use_i18n() -> leptos_i18n::I18nShardContext<I18nKeys> {
+                leptos_i18n::I18nShardContext::new(leptos_i18n::use_i18n_context())
+            }
+
+            #[inline]
+            pub fn try_use_i18n() -> Option<leptos_i18n::I18nShardContext<I18nKeys>> {
+                leptos_i18n::try_use_i18n_context().map(leptos_i18n::I18nShardContext::new)
+            }
+
+            /// Return the given locale giving access to the keys of this shard, to be used with `td!`.
+            #[inline]
+            pub fn locale(locale: Locale) -> leptos_i18n::ShardLocale<I18nKeys> {
+                leptos_i18n::ShardLocale::new(locale)
+            }
+
+            pub use leptos_i18n::{t, td, t_string, td_string, t_title, t_meta, t_asset, scope_i18n, use_i18n_scoped};
+
+            #warnings
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    rc::Rc,
+};
+
+use super::{
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    metadata::VariableDeclaration,
+    parsed_value::{ForeignKey, InterpolateKey, ParsedValue},
+};
+
+/// Render every key of each locale to a text snapshot, one `path = value` line per key sorted by name,
+/// the namespaces being in the order of the configuration.
+///
+/// The keys are the ones of the default locale, the keys missing in a locale take the value of the default locale.
+/// Variables are replaced by the default value declared in the `_variables` section, else written as `{{ name }}`,
+/// and components are written as tags.
+pub fn create_snapshots(locales: &LocalesOrNamespaces) -> Vec<(Rc<Key>, String)> {
+    let mut snapshots: Vec<(Rc<Key>, Vec<String>)> = vec![];
+    match locales {
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                let prefix = format!("{}::", namespace.key.name);
+                add_lines(&mut snapshots, &namespace.locales, &prefix);
+            }
+        }
+        LocalesOrNamespaces::Locales(locales) => add_lines(&mut snapshots, locales, ""),
+    }
+    snapshots
+        .into_iter()
+        .map(|(locale, lines)| {
+            let mut snapshot = String::new();
+            for line in lines {
+                snapshot.push_str(&line);
+                snapshot.push('\n');
+            }
+            (locale, snapshot)
+        })
+        .collect()
+}
+
+/// Return the lines of documentation showing the value of a key in the default locale,
+/// followed by the variables and components the key needs.
+pub fn value_doc_lines(
+    default_locale: &Key,
+    value: &ParsedValue,
+    interpolations: Option<&HashSet<InterpolateKey>>,
+) -> Vec<String> {
+    let text = match value {
+        ParsedValue::List(items) => format!("{:?}", items),
+        ParsedValue::ObjectList(_) | ParsedValue::Subkeys(_) | ParsedValue::Default => {
+            return vec![]
+        }
+        value => source_text(value),
+    };
+    let mut lines = vec![format!(
+        " Default locale (`{}`): {}",
+        default_locale.name,
+        inline_code(&text)
+    )];
+    let Some(interpolations) = interpolations else {
+        return lines;
+    };
+    let (variables, components) = interpolation_names(interpolations);
+    for (label, names) in [("Variables", variables), ("Components", components)] {
+        if names.is_empty() {
+            continue;
+        }
+        let names = names.into_iter().map(inline_code).collect::<Vec<_>>();
+        lines.push(String::new());
+        lines.push(format!(" {}: {}", label, names.join(", ")));
+    }
+    lines
+}
+
+/// Return the value written with the syntax of the locale files, the plurals being written as `[count: value | ...]`.
+pub fn source_text(value: &ParsedValue) -> String {
+    render(value, &Placeholders(None))
+}
+
+/// Return the names of the variables and of the components of the interpolations, sorted.
+pub fn interpolation_names(interpolations: &HashSet<InterpolateKey>) -> (Vec<&str>, Vec<&str>) {
+    let mut variables = vec![];
+    let mut components = vec![];
+    for key in interpolations {
+        match key {
+            InterpolateKey::Count(_) => variables.push("count"),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::TypedVariable(key, _)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key) => {
+                variables.push(key.name.strip_prefix("var_").unwrap_or(&key.name))
+            }
+            InterpolateKey::Component(key) => {
+                components.push(key.name.strip_prefix("comp_").unwrap_or(&key.name))
+            }
+        }
+    }
+    variables.sort_unstable();
+    variables.dedup();
+    components.sort_unstable();
+    components.dedup();
+    (variables, components)
+}
+
+/// Wrap the text in backticks, with enough of them to not be closed by the ones in the text.
+fn inline_code(text: &str) -> String {
+    if text.contains('`') {
+        format!("`` {} ``", text)
+    } else {
+        format!("`{}`", text)
+    }
+}
+
+fn add_lines(snapshots: &mut Vec<(Rc<Key>, Vec<String>)>, locales: &[Locale], prefix: &str) {
+    let Some(default_locale) = locales.first() else {
+        return;
+    };
+    let placeholders = Placeholders(default_locale.variables.as_ref());
+    for locale in locales {
+        let mut lines = vec![];
+        snapshot_map(
+            &mut lines,
+            Some(locale),
+            default_locale,
+            prefix,
+            &placeholders,
+        );
+        match snapshots
+            .iter_mut()
+            .find(|(name, _)| name == &locale.top_locale_name)
+        {
+            Some((_, snapshot)) => snapshot.extend(lines),
+            None => snapshots.push((Rc::clone(&locale.top_locale_name), lines)),
+        }
+    }
+}
+
+fn snapshot_map(
+    lines: &mut Vec<String>,
+    locale: Option<&Locale>,
+    default_locale: &Locale,
+    prefix: &str,
+    placeholders: &Placeholders,
+) {
+    let mut keys = default_locale.keys.iter().collect::<Vec<_>>();
+    keys.sort_unstable_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+    for (key, default_value) in keys {
+        let path = format!("{}{}", prefix, key.name);
+        let value = locale
+            .and_then(|locale| locale.keys.get(key))
+            .filter(|value| !matches!(value, ParsedValue::Default))
+            .unwrap_or(default_value);
+        match (value, default_value) {
+            (ParsedValue::Subkeys(subkeys), ParsedValue::Subkeys(default_subkeys)) => {
+                let prefix = format!("{}.", path);
+                snapshot_map(lines, Some(subkeys), default_subkeys, &prefix, placeholders);
+            }
+            (_, ParsedValue::Subkeys(default_subkeys)) => {
+                let prefix = format!("{}.", path);
+                snapshot_map(lines, None, default_subkeys, &prefix, placeholders);
+            }
+            (value, _) => snapshot_value(lines, value, &path, placeholders),
+        }
+    }
+}
+
+fn snapshot_value(
+    lines: &mut Vec<String>,
+    value: &ParsedValue,
+    path: &str,
+    placeholders: &Placeholders,
+) {
+    match value {
+        ParsedValue::Plural(plurals) => {
+            for (count, value) in plurals.branches() {
+                let line = format!("{}[{}] = {}", path, count, render(value, placeholders));
+                lines.push(line);
+            }
+        }
+        ParsedValue::Features { variants, default } => {
+            snapshot_value(lines, default, path, placeholders);
+            for (feature, value) in variants {
+                let path = format!("{}[feature = {:?}]", path, feature);
+                snapshot_value(lines, value, &path, placeholders);
+            }
+        }
+        ParsedValue::Select {
+            key,
+            variants,
+            other,
+        } => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            for (variant, value) in variants {
+                let path = format!("{}[{} = {}]", path, name, variant);
+                snapshot_value(lines, value, &path, placeholders);
+            }
+            let path = format!("{}[{} = {}]", path, name, ParsedValue::SELECT_OTHER);
+            snapshot_value(lines, other, &path, placeholders);
+        }
+        ParsedValue::List(items) => {
+            for (i, item) in items.iter().enumerate() {
+                lines.push(format!("{}[{}] = {}", path, i, escape(item)));
+            }
+        }
+        ParsedValue::ObjectList(list) => {
+            for (i, item) in list.items.iter().enumerate() {
+                for field in &list.fields {
+                    let value = item.get(field).unwrap_or_default();
+                    lines.push(format!(
+                        "{}[{}].{} = {}",
+                        path,
+                        i,
+                        field.name,
+                        escape(value)
+                    ));
+                }
+            }
+        }
+        value => lines.push(format!("{} = {}", path, render(value, placeholders))),
+    }
+}
+
+fn render(value: &ParsedValue, placeholders: &Placeholders) -> String {
+    let mut output = String::new();
+    render_inner(value, placeholders, &mut output);
+    output
+}
+
+fn render_inner(value: &ParsedValue, placeholders: &Placeholders, output: &mut String) {
+    match value {
+        ParsedValue::String(s) => output.push_str(&escape(s)),
+        ParsedValue::Variable(key) => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            match placeholders.get(name) {
+                Some(default) => output.push_str(&escape(default)),
+                None => {
+                    let _ = write!(output, "{{{{ {} }}}}", name);
+                }
+            }
+        }
+        ParsedValue::TypedVariable { key, ty } => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            match placeholders.get(name) {
+                Some(default) => output.push_str(&escape(default)),
+                None => {
+                    let _ = write!(output, "{{{{ {}: {} }}}}", name, ty);
+                }
+            }
+        }
+        ParsedValue::Formatted { key, formatter, .. } => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            match placeholders.get(name) {
+                Some(default) => output.push_str(&escape(default)),
+                None => {
+                    let _ = write!(output, "{{{{ {}, {} }}}}", name, formatter);
+                }
+            }
+        }
+        ParsedValue::Component { key, inner } => {
+            let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            let _ = write!(output, "<{}>", name);
+            render_inner(inner, placeholders, output);
+            let _ = write!(output, "</{}>", name);
+        }
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                render_inner(value, placeholders, output);
+            }
+        }
+        ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
+            ForeignKey::Set(inner) => render_inner(inner, placeholders, output),
+            ForeignKey::NotSet(_) => unreachable!(),
+        },
+        ParsedValue::Features { default, .. } => render_inner(default, placeholders, output),
+        ParsedValue::Select {
+            key,
+            variants,
+            other,
+        } => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            let _ = write!(output, "{{{} ", name);
+            for (variant, value) in variants {
+                let _ = write!(output, "{}: ", variant);
+                render_inner(value, placeholders, output);
+                output.push_str(" | ");
+            }
+            let _ = write!(output, "{}: ", ParsedValue::SELECT_OTHER);
+            render_inner(other, placeholders, output);
+            output.push('}');
+        }
+        ParsedValue::Plural(plurals) => {
+            output.push('[');
+            for (i, (count, value)) in plurals.branches().into_iter().enumerate() {
+                if i > 0 {
+                    output.push_str(" | ");
+                }
+                let _ = write!(output, "{}: ", count);
+                render_inner(value, placeholders, output);
+            }
+            output.push(']');
+        }
+        ParsedValue::Default
+        | ParsedValue::Subkeys(_)
+        | ParsedValue::List(_)
+        | ParsedValue::ObjectList(_) => {}
+    }
+}
+
+/// Keep one line per value.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+struct Placeholders<'a>(Option<&'a HashMap<String, VariableDeclaration>>);
+
+impl Placeholders<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0?.get(name)?.default.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_locales::key::KeyPath;
+
+    #[test]
+    fn doc_lines_of_value() {
+        let locale = Rc::new(Key::new("en").unwrap());
+        let value = ParsedValue::new(
+            "Hello {{ name }}, <b>welcome</b>",
+            &KeyPath::new(None),
+            &locale,
+        )
+        .unwrap();
+        let interpolations = value.get_keys();
+
+        assert_eq!(
+            value_doc_lines(&locale, &value, interpolations.as_ref()),
+            [
+                " Default locale (`en`): `Hello {{ name }}, <b>welcome</b>`",
+                "",
+                " Variables: `name`",
+                "",
+                " Components: `b`",
+            ]
+        );
+
+        let value = ParsedValue::String("use `cargo`".to_string());
+        assert_eq!(
+            value_doc_lines(&locale, &value, None),
+            [" Default locale (`en`): `` use `cargo` ``"]
+        );
+    }
+}
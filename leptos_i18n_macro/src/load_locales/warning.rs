@@ -7,8 +7,41 @@ use std::{cell::RefCell, fmt::Display, rc::Rc};
 
 #[derive(Debug)]
 pub enum Warning {
-    MissingKey { locale: Rc<Key>, key_path: KeyPath },
-    SurplusKey { locale: Rc<Key>, key_path: KeyPath },
+    MissingKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    SurplusKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    OrphanMetadata {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    UnusedVariable {
+        name: String,
+        namespace: Option<Rc<Key>>,
+    },
+    IgnoredVariables {
+        locale: Rc<Key>,
+        namespace: Option<Rc<Key>>,
+    },
+    UnknownConstant {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        name: String,
+    },
+    UnusedKey {
+        key_path: KeyPath,
+    },
+    UnknownKeyUsage {
+        key: String,
+        file: String,
+    },
+    UnknownNumberFormat {
+        locale: Rc<Key>,
+    },
 }
 
 thread_local! {
@@ -32,41 +65,209 @@ impl Display for Warning {
                 "Key {} is present in locale {:?} but not in default locale, it is ignored",
                 key_path, locale
             ),
+            Warning::OrphanMetadata { locale, key_path } => write!(
+                f,
+                "Metadata for key {} in locale {:?} don't match any key, it is ignored",
+                key_path, locale
+            ),
+            Warning::UnusedVariable {
+                name,
+                namespace: None,
+            } => write!(f, "Variable {:?} is declared but never used", name),
+            Warning::UnusedVariable {
+                name,
+                namespace: Some(namespace),
+            } => write!(
+                f,
+                "Variable {:?} is declared but never used in namespace {:?}",
+                name, namespace
+            ),
+            Warning::IgnoredVariables {
+                locale,
+                namespace: None,
+            } => write!(
+                f,
+                "Variables can only be declared in the default locale, the \"_variables\" section of locale {:?} is ignored",
+                locale
+            ),
+            Warning::IgnoredVariables {
+                locale,
+                namespace: Some(namespace),
+            } => write!(
+                f,
+                "Variables can only be declared in the default locale, the \"_variables\" section of locale {:?} in namespace {:?} is ignored",
+                locale, namespace
+            ),
+            Warning::UnknownConstant {
+                locale,
+                key_path,
+                name,
+            } => write!(
+                f,
+                "Unknown constant {:?} at key {} in locale {:?}, it is neither declared in the configuration nor an environment variable",
+                name, key_path, locale
+            ),
+            Warning::UnusedKey { key_path } => write!(
+                f,
+                "Key {} is never used by the `t!` macros of the crate",
+                key_path
+            ),
+            Warning::UnknownKeyUsage { key, file } => write!(
+                f,
+                "Key \"{}\" is used in {} but does not exist in the default locale",
+                key, file
+            ),
+            Warning::UnknownNumberFormat { locale } => write!(
+                f,
+                "The separators of the numbers of locale {:?} are not known, the English ones are used. Enable the \"icu\" feature to format them with ICU4X",
+                locale
+            ),
         }
     }
 }
 
-impl Warning {
-    #[cfg(not(feature = "nightly"))]
-    fn to_fn(&self, index: usize) -> TokenStream {
-        let msg = self.to_string();
-        let fn_name = format_ident!("w{}", index);
-        quote! {
-            #[deprecated(note = #msg)]
-            fn #fn_name() {
-                unimplemented!()
+/// Maximum number of missing, surplus or unused keys of a locale reported one by one,
+/// above that they are reported in a single warning listing the first ones.
+const MAX_UNGROUPED_KEYS: usize = 5;
+
+#[derive(PartialEq)]
+enum GroupKind {
+    Missing,
+    Surplus,
+    Unused,
+}
+
+struct KeysGroup<'a> {
+    kind: GroupKind,
+    /// `None` for the unused keys, which are the same for every locale.
+    locale: Option<&'a Rc<Key>>,
+    namespace: Option<&'a Rc<Key>>,
+    key_paths: Vec<&'a KeyPath>,
+}
+
+impl Display for KeysGroup<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count = self.key_paths.len();
+        match (&self.kind, self.locale) {
+            (GroupKind::Missing, Some(locale)) => {
+                write!(f, "{} keys are missing in locale {:?}", count, locale)?
+            }
+            (GroupKind::Surplus, Some(locale)) => write!(
+                f,
+                "{} keys are present in locale {:?} but not in default locale, they are ignored",
+                count, locale
+            )?,
+            _ => write!(
+                f,
+                "{} keys are never used by the `t!` macros of the crate",
+                count
+            )?,
+        }
+        if let Some(namespace) = self.namespace {
+            write!(f, " in namespace {:?}", namespace)?;
+        }
+        f.write_str(": ")?;
+        for (i, key_path) in self.key_paths.iter().take(MAX_UNGROUPED_KEYS).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
             }
+            write!(f, "{}", key_path)?;
         }
+        write!(f, " and {} more", count - MAX_UNGROUPED_KEYS)?;
+        if self.kind == GroupKind::Missing {
+            f.write_str(", the full list is returned by `Locale::missing_keys`")?;
+        }
+        Ok(())
     }
+}
 
-    #[cfg(feature = "nightly")]
-    fn emit(&self) {
-        use proc_macro::{Diagnostic, Span};
+/// Return the messages of the warnings, without duplicates and with the missing, surplus and unused keys grouped
+/// by locale and namespace when there are too many of them.
+fn warnings_messages(warnings: &[Warning]) -> Vec<String> {
+    let mut groups: Vec<KeysGroup> = vec![];
+    for warning in warnings {
+        let (kind, locale, key_path) = match warning {
+            Warning::MissingKey { locale, key_path } => {
+                (GroupKind::Missing, Some(locale), key_path)
+            }
+            Warning::SurplusKey { locale, key_path } => {
+                (GroupKind::Surplus, Some(locale), key_path)
+            }
+            Warning::UnusedKey { key_path } => (GroupKind::Unused, None, key_path),
+            _ => continue,
+        };
+        let namespace = key_path.namespace.as_ref();
+        match groups.iter_mut().find(|group| {
+            group.kind == kind && group.locale == locale && group.namespace == namespace
+        }) {
+            Some(group) => group.key_paths.push(key_path),
+            None => groups.push(KeysGroup {
+                kind,
+                locale,
+                namespace,
+                key_paths: vec![key_path],
+            }),
+        }
+    }
+    groups.retain(|group| group.key_paths.len() > MAX_UNGROUPED_KEYS);
 
-        Diagnostic::spanned(
-            Span::call_site(),
-            proc_macro::Level::Warning,
-            self.to_string(),
-        )
-        .emit();
+    let mut messages: Vec<String> = vec![];
+    let mut push = |message: String| {
+        if !messages.contains(&message) {
+            messages.push(message);
+        }
+    };
+    for warning in warnings {
+        let group = match warning {
+            Warning::MissingKey { locale, key_path } => groups.iter().find(|group| {
+                group.kind == GroupKind::Missing
+                    && group.locale == Some(locale)
+                    && group.namespace == key_path.namespace.as_ref()
+            }),
+            Warning::SurplusKey { locale, key_path } => groups.iter().find(|group| {
+                group.kind == GroupKind::Surplus
+                    && group.locale == Some(locale)
+                    && group.namespace == key_path.namespace.as_ref()
+            }),
+            Warning::UnusedKey { key_path } => groups.iter().find(|group| {
+                group.kind == GroupKind::Unused && group.namespace == key_path.namespace.as_ref()
+            }),
+            _ => None,
+        };
+        match group {
+            Some(group) => push(group.to_string()),
+            None => push(warning.to_string()),
+        }
     }
+    messages
 }
 
 #[cfg(not(feature = "nightly"))]
-fn generate_warnings_inner(warnings: &[Warning]) -> TokenStream {
-    let warning_fns = warnings.iter().enumerate().map(|(i, w)| w.to_fn(i));
+fn warning_fn(msg: &str, index: usize) -> TokenStream {
+    let fn_name = format_ident!("w{}", index);
+    quote! {
+        #[deprecated(note = #msg)]
+        fn #fn_name() {
+            unimplemented!()
+        }
+    }
+}
 
-    let fn_calls = (0..warnings.len()).map(|i| {
+#[cfg(feature = "nightly")]
+fn emit_message(msg: &str) {
+    use proc_macro::{Diagnostic, Span};
+
+    Diagnostic::spanned(Span::call_site(), proc_macro::Level::Warning, msg).emit();
+}
+
+#[cfg(not(feature = "nightly"))]
+fn generate_warnings_inner(messages: &[String]) -> TokenStream {
+    let warning_fns = messages
+        .iter()
+        .enumerate()
+        .map(|(i, msg)| warning_fn(msg, i));
+
+    let fn_calls = (0..messages.len()).map(|i| {
         let fn_name = format_ident!("w{}", i);
         quote!(#fn_name();)
     });
@@ -88,11 +289,11 @@ fn generate_warnings_inner(warnings: &[Warning]) -> TokenStream {
 #[cfg(not(feature = "nightly"))]
 pub fn generate_warnings() -> Option<TokenStream> {
     WARNINGS.with(|cell| {
-        let ws = cell.borrow();
+        let ws = cell.take();
         if ws.is_empty() {
             None
         } else {
-            Some(generate_warnings_inner(&ws))
+            Some(generate_warnings_inner(&warnings_messages(&ws)))
         }
     })
 }
@@ -100,9 +301,43 @@ pub fn generate_warnings() -> Option<TokenStream> {
 #[cfg(feature = "nightly")]
 pub fn generate_warnings() -> Option<TokenStream> {
     WARNINGS.with(|ws| {
-        for warning in ws.borrow().iter() {
-            warning.emit();
+        for msg in warnings_messages(&ws.take()) {
+            emit_message(&msg);
         }
         None
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missing_key(locale: &Rc<Key>, name: &str) -> Warning {
+        let mut key_path = KeyPath::new(None);
+        key_path.push_key(Rc::new(Key::new(name).unwrap()));
+        Warning::MissingKey {
+            locale: Rc::clone(locale),
+            key_path,
+        }
+    }
+
+    #[test]
+    fn group_missing_keys() {
+        let fr = Rc::new(Key::new("fr").unwrap());
+        let de = Rc::new(Key::new("de").unwrap());
+        let mut warnings = (0..7)
+            .map(|i| missing_key(&fr, &format!("key_{}", i)))
+            .collect::<Vec<_>>();
+        warnings.push(missing_key(&de, "key_0"));
+        warnings.push(missing_key(&de, "key_0"));
+
+        let messages = warnings_messages(&warnings);
+        assert_eq!(
+            messages,
+            [
+                "7 keys are missing in locale \"fr\": \"key_0\", \"key_1\", \"key_2\", \"key_3\", \"key_4\" and 2 more, the full list is returned by `Locale::missing_keys`",
+                "Missing key \"key_0\" in locale \"de\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use quote::quote;
+use syn::parse_macro_input;
+
+use super::{get_key, parsed_input::ParsedInput};
+
+pub fn t_asset(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ParsedInput {
+        context,
+        keys,
+        interpolations,
+    } = parse_macro_input!(tokens as ParsedInput);
+    if interpolations.is_some() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "t_asset! only supports string keys, interpolations are not allowed",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let get_key = get_key(
+        quote!(leptos_i18n::__private::GetKeys::get_keys(#context)),
+        keys,
+    );
+    quote!(move || -> &'static str { #get_key }).into()
+}
@@ -0,0 +1,104 @@
+use quote::quote;
+use syn::{parse_macro_input, token::Comma, Expr, Ident, Token};
+
+use super::{
+    parsed_input::{Keys, ParsedInput},
+    t_macro_inner,
+};
+
+pub struct ParsedMetaInput {
+    pub context: Expr,
+    pub attribute: Ident,
+    pub attribute_value: Expr,
+    pub keys: Keys,
+}
+
+impl syn::parse::Parse for ParsedMetaInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let context = input.parse()?;
+        input.parse::<Comma>()?;
+        let attribute: Ident = input.parse()?;
+        if !matches!(
+            attribute.to_string().as_str(),
+            "name" | "property" | "http_equiv"
+        ) {
+            return Err(syn::Error::new(
+                attribute.span(),
+                "expected one of `name`, `property` or `http_equiv`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let attribute_value = input.parse()?;
+        input.parse::<Comma>()?;
+        let keys = input.parse()?;
+        Ok(ParsedMetaInput {
+            context,
+            attribute,
+            attribute_value,
+            keys,
+        })
+    }
+}
+
+fn text_prop(context: Expr, keys: Keys) -> proc_macro2::TokenStream {
+    let value = t_macro_inner(
+        ParsedInput {
+            context,
+            keys,
+            interpolations: None,
+        },
+        false,
+    );
+    quote!(Some(leptos::TextProp::from(#value)))
+}
+
+pub fn t_title(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ParsedInput {
+        context,
+        keys,
+        interpolations,
+    } = parse_macro_input!(tokens as ParsedInput);
+    if interpolations.is_some() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "t_title! only supports string keys, interpolations are not allowed",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let text = text_prop(context, keys);
+    quote! {
+        leptos_i18n::__private::Title(leptos_i18n::__private::TitleProps {
+            formatter: None,
+            text: #text,
+        })
+    }
+    .into()
+}
+
+pub fn t_meta(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ParsedMetaInput {
+        context,
+        attribute,
+        attribute_value,
+        keys,
+    } = parse_macro_input!(tokens as ParsedMetaInput);
+    let content = text_prop(context, keys);
+    let attributes = ["name", "property", "http_equiv"].map(|attr| {
+        let ident = Ident::new(attr, attribute.span());
+        if attribute == attr {
+            quote!(#ident: Some(leptos::TextProp::from(#attribute_value)))
+        } else {
+            quote!(#ident: None)
+        }
+    });
+    quote! {
+        leptos_i18n::__private::Meta(leptos_i18n::__private::MetaProps {
+            charset: None,
+            #(#attributes,)*
+            content: #content,
+            attrs: vec![],
+        })
+    }
+    .into()
+}
@@ -3,33 +3,37 @@ use syn::parse_macro_input;
 
 use self::parsed_input::{Keys, ParsedInput};
 
+pub mod asset;
 pub mod interpolate;
+pub mod meta;
 pub mod parsed_input;
+pub mod scope;
+pub mod string;
 
 pub fn t_macro(tokens: proc_macro::TokenStream, direct: bool) -> proc_macro::TokenStream {
     let input = parse_macro_input!(tokens as ParsedInput);
     t_macro_inner(input, direct).into()
 }
 
+pub fn get_key(get_keys: proc_macro2::TokenStream, keys: Keys) -> proc_macro2::TokenStream {
+    match keys {
+        Keys::SingleKey(key) => quote!(#get_keys.#key),
+        Keys::Subkeys(keys) => quote!(#get_keys #(.#keys)*),
+        Keys::Namespace(namespace, keys) => {
+            quote!(#get_keys.#namespace #(.#keys)*)
+        }
+    }
+}
+
 pub fn t_macro_inner(input: ParsedInput, direct: bool) -> proc_macro2::TokenStream {
     let ParsedInput {
         context,
         keys,
         interpolations,
     } = input;
-    let get_keys = if direct {
-        quote!(leptos_i18n::Locale::get_keys(#context))
-    } else {
-        quote!(leptos_i18n::I18nContext::get_keys(#context))
-    };
+    let get_keys = quote!(leptos_i18n::__private::GetKeys::get_keys(#context));
 
-    let get_key = match keys {
-        Keys::SingleKey(key) => quote!(#get_keys.#key),
-        Keys::Subkeys(keys) => quote!(#get_keys #(.#keys)*),
-        Keys::Namespace(namespace, keys) => {
-            quote!(#get_keys.#namespace #(.#keys)*)
-        }
-    };
+    let get_key = get_key(get_keys, keys);
     let inner = if let Some(interpolations) = interpolations {
         quote! {
             {
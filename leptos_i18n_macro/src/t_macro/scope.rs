@@ -0,0 +1,34 @@
+use quote::quote;
+use syn::{parse_macro_input, token::Comma, Expr};
+
+use super::{get_key, parsed_input::Keys};
+
+pub struct ParsedScopeInput {
+    pub context: Expr,
+    pub keys: Keys,
+}
+
+impl syn::parse::Parse for ParsedScopeInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let context = input.parse()?;
+        input.parse::<Comma>()?;
+        let keys = input.parse()?;
+        Ok(ParsedScopeInput { context, keys })
+    }
+}
+
+/// The scope is a closure returning the subkeys, so a path that is not subkeys or a namespace fails to compile.
+fn create_scope(context: proc_macro2::TokenStream, keys: Keys) -> proc_macro::TokenStream {
+    let get_key = get_key(quote!(_keys), keys);
+    quote!(leptos_i18n::I18nScope::new(#context, |_keys| &#get_key)).into()
+}
+
+pub fn scope_i18n(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ParsedScopeInput { context, keys } = parse_macro_input!(tokens as ParsedScopeInput);
+    create_scope(quote!(#context), keys)
+}
+
+pub fn use_i18n_scoped(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let keys = parse_macro_input!(tokens as Keys);
+    create_scope(quote!(use_i18n()), keys)
+}
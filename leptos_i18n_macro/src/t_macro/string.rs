@@ -0,0 +1,30 @@
+use quote::quote;
+use syn::parse_macro_input;
+
+use super::{get_key, parsed_input::ParsedInput};
+
+/// `t_string!` and `td_string!` only differ by their first argument, both supported by `GetKeys`.
+pub fn t_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ParsedInput {
+        context,
+        keys,
+        interpolations,
+    } = parse_macro_input!(tokens as ParsedInput);
+    let get_key = get_key(
+        quote!(leptos_i18n::__private::GetKeys::get_keys(#context)),
+        keys,
+    );
+    let interpolations = interpolations.unwrap_or_default();
+    quote! {
+        {
+            #[allow(unused)]
+            use leptos_i18n::__private::BuildString;
+            let _key = #get_key;
+            #(
+                let _key = _key.#interpolations;
+            )*
+            _key.build_string()
+        }
+    }
+    .into()
+}
@@ -0,0 +1,5 @@
+#![deny(warnings)]
+leptos_i18n::load_locales!(dynamic);
+
+#[cfg(test)]
+mod tests;
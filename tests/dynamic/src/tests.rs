@@ -0,0 +1,74 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn strings() {
+    let en = td!(Locale::en, hello_world);
+    assert_eq!(en, "Hello World!");
+    let fr = td!(Locale::fr, hello_world);
+    assert_eq!(fr, "Bonjour le monde !");
+}
+
+#[test]
+fn foreign_key() {
+    let en = td!(Locale::en, foreign_key);
+    assert_eq!(en, "before Hello World! after");
+    let fr = td!(Locale::fr, foreign_key);
+    assert_eq!(fr, "avant Bonjour le monde ! après");
+}
+
+#[test]
+fn interpolations() {
+    let count = 3;
+    let en = td!(Locale::en, click_count, count);
+    assert_eq_rendered!(en, "You clicked 3 times");
+    let fr = td!(Locale::fr, click_count, count);
+    assert_eq_rendered!(fr, "Vous avez cliqué 3 fois");
+}
+
+#[test]
+fn lists() {
+    let en = td!(Locale::en, tips);
+    assert_eq!(en, ["Save often", "Use shortcuts"]);
+    let fr = td!(Locale::fr, tips);
+    assert_eq!(
+        fr,
+        [
+            "Sauvegardez souvent",
+            "Utilisez les raccourcis",
+            "Lisez la doc"
+        ]
+    );
+}
+
+#[test]
+fn object_lists() {
+    let en = td!(Locale::en, faq);
+    assert_eq!(en.len(), 2);
+    assert_eq!(en[1].question, "Is it free?");
+    let fr = td!(Locale::fr, faq);
+    assert_eq!(fr.len(), 1);
+    assert_eq!(fr[0].question, "Qu'est-ce que c'est ?");
+    assert_eq!(fr[0].answer, "Une bibliothèque");
+}
+
+#[test]
+fn subkeys() {
+    let fr = td!(Locale::fr, subkeys.subkey_1);
+    assert_eq!(fr, "sous-clé 1");
+    let fr = td!(Locale::fr, subkeys.defaulted);
+    assert_eq!(fr, "this subkey is declared in locale en");
+}
+
+#[test]
+fn assets() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/target/i18n/fr.json");
+    let contents = std::fs::read_to_string(path).unwrap();
+    assert!(contents.contains(r#""hello_world": "Bonjour le monde !""#));
+    assert!(contents.contains(r#""subkeys.subkey_1": "sous-clé 1""#));
+    // interpolations are compiled in.
+    assert!(!contents.contains("click_count"));
+    assert!(
+        !std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/target/i18n/en.json")).exists()
+    );
+}
@@ -0,0 +1,28 @@
+### Messages of the Fluent test
+
+# Label of the button switching the language
+click_to_change_lang = Click to change language
+click_count = You clicked { $count } times
+
+-brand = Leptos
+welcome = Welcome to { -brand }, { $name }!
+
+emails = { $unread ->
+    [0] No unread email
+    [one] One unread email
+   *[other] { $unread } unread emails
+  } for { $user }
+
+float_plural = { $ratio ->
+    [0.5] Half
+   *[other] Not half
+  }
+
+login = Log in
+    .title = Click to log in
+login_hint = { login.title }: { login }
+
+multiline =
+    first
+    second
+bold = Some <b>bold</b> text
@@ -0,0 +1,27 @@
+### Messages du test de Fluent
+
+click_to_change_lang = Cliquez pour changez de langue
+click_count = Vous avez cliqué { $count } fois
+
+-brand = Leptos
+welcome = Bienvenue sur { -brand }, { $name } !
+
+emails = { $unread ->
+    [0] Aucun email non lu
+    [one] Un email non lu
+   *[other] { $unread } emails non lus
+  } pour { $user }
+
+float_plural = { $ratio ->
+    [0.5] Moitié
+   *[other] Pas la moitié
+  }
+
+login = Se connecter
+    .title = Cliquez pour vous connecter
+login_hint = { login.title } : { login }
+
+multiline =
+    premier
+    second
+bold = Du texte en <b>gras</b>
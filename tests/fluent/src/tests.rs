@@ -0,0 +1,91 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn click_to_change_lang() {
+    let en = td!(Locale::en, click_to_change_lang);
+    assert_eq!(en, "Click to change language");
+    let fr = td!(Locale::fr, click_to_change_lang);
+    assert_eq!(fr, "Cliquez pour changez de langue");
+}
+
+#[test]
+fn click_count() {
+    for count in -5..5 {
+        let en = td!(Locale::en, click_count, count);
+        assert_eq_rendered!(en, format!("You clicked {} times", count));
+        let fr = td!(Locale::fr, click_count, count);
+        assert_eq_rendered!(fr, format!("Vous avez cliqué {} fois", count));
+    }
+}
+
+#[test]
+fn term() {
+    let en = td!(Locale::en, welcome, name = "Alice");
+    assert_eq_rendered!(en, "Welcome to Leptos, Alice!");
+    let fr = td!(Locale::fr, welcome, name = "Alice");
+    assert_eq_rendered!(fr, "Bienvenue sur Leptos, Alice !");
+}
+
+#[test]
+fn select() {
+    let user = "Alice";
+    let count = || 0;
+    let en = td!(Locale::en, emails, count, user);
+    assert_eq_rendered!(en, "No unread email for Alice");
+    let fr = td!(Locale::fr, emails, count, user);
+    assert_eq_rendered!(fr, "Aucun email non lu pour Alice");
+    let count = || 1;
+    let en = td!(Locale::en, emails, count, user);
+    assert_eq_rendered!(en, "One unread email for Alice");
+    let fr = td!(Locale::fr, emails, count, user);
+    assert_eq_rendered!(fr, "Un email non lu pour Alice");
+    let count = || 3;
+    let en = td!(Locale::en, emails, count, user);
+    assert_eq_rendered!(en, "3 unread emails for Alice");
+    let fr = td!(Locale::fr, emails, count, user);
+    assert_eq_rendered!(fr, "3 emails non lus pour Alice");
+}
+
+#[test]
+fn float_select() {
+    let count = || 0.5;
+    let en = td!(Locale::en, float_plural, count);
+    assert_eq_rendered!(en, "Half");
+    let fr = td!(Locale::fr, float_plural, count);
+    assert_eq_rendered!(fr, "Moitié");
+    let count = || 0.25;
+    let en = td!(Locale::en, float_plural, count);
+    assert_eq_rendered!(en, "Not half");
+    let fr = td!(Locale::fr, float_plural, count);
+    assert_eq_rendered!(fr, "Pas la moitié");
+}
+
+#[test]
+fn attributes() {
+    let en = td!(Locale::en, login.value);
+    assert_eq!(en, "Log in");
+    let fr = td!(Locale::fr, login.title);
+    assert_eq!(fr, "Cliquez pour vous connecter");
+    let en = td!(Locale::en, login_hint);
+    assert_eq!(en, "Click to log in: Log in");
+    let fr = td!(Locale::fr, login_hint);
+    assert_eq!(fr, "Cliquez pour vous connecter : Se connecter");
+}
+
+#[test]
+fn multiline() {
+    let en = td!(Locale::en, multiline);
+    assert_eq!(en, "first\nsecond");
+    let fr = td!(Locale::fr, multiline);
+    assert_eq!(fr, "premier\nsecond");
+}
+
+#[test]
+fn component() {
+    let b = |children: ChildrenFn| view! { <b>{children}</b> };
+    let en = td!(Locale::en, bold, <b>);
+    assert_eq_rendered!(en, "Some <b>bold</b> text");
+    let fr = td!(Locale::fr, bold, <b>);
+    assert_eq_rendered!(fr, "Du texte en <b>gras</b>");
+}
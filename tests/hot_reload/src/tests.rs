@@ -0,0 +1,57 @@
+use crate::i18n::*;
+use common::*;
+use std::{path::PathBuf, time::Duration};
+
+/// Restore the content of the locale file when the test ends, even if it fails.
+struct RestoreFile {
+    path: PathBuf,
+    content: String,
+}
+
+impl Drop for RestoreFile {
+    fn drop(&mut self) {
+        std::fs::write(&self.path, &self.content).unwrap();
+    }
+}
+
+// a single test, as the edited file is shared by every test of the crate.
+#[test]
+fn edited_file() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("locales/fr.json");
+    let content = std::fs::read_to_string(&path).unwrap();
+    let _restore = RestoreFile {
+        path: path.clone(),
+        content: content.clone(),
+    };
+
+    // the restored file of a previous run is read again, with the same values.
+    assert_eq!(td!(Locale::fr, hello_world), "Bonjour le monde !");
+
+    let edited = content
+        .replace("Bonjour le monde !", "Salut le monde !")
+        .replace("Vous avez cliqué", "Tu as cliqué")
+        .replace("\"Utilisez les raccourcis\"", "\"Utilisez les raccourcis\", \"Lisez la doc\"");
+    std::fs::write(&path, edited).unwrap();
+    // the files are checked at most every 100 milliseconds.
+    std::thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(td!(Locale::fr, hello_world), "Salut le monde !");
+    assert_eq!(
+        td!(Locale::fr, tips),
+        [
+            "Sauvegardez souvent",
+            "Utilisez les raccourcis",
+            "Lisez la doc"
+        ]
+    );
+    // interpolations are not reloaded.
+    let count = 3;
+    assert_eq_rendered!(td!(Locale::fr, click_count, count), "Vous avez cliqué 3 fois");
+    // the other locales keep their compiled values.
+    assert_eq!(td!(Locale::en, hello_world), "Hello World!");
+
+    // an invalid file keeps the last values read.
+    std::fs::write(&path, "{ not json").unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(td!(Locale::fr, hello_world), "Salut le monde !");
+}
@@ -0,0 +1,61 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn nesting() {
+    let en = td!(Locale::en, inbox.title);
+    assert_eq!(en, "Welcome to Mailbox");
+    let fr = td!(Locale::fr, inbox.title);
+    assert_eq!(fr, "Bienvenue dans Messagerie");
+    let en = td!(Locale::en, inbox.confirm);
+    assert_eq!(en, "OK or Cancel");
+}
+
+#[test]
+fn interpolations() {
+    let name = || "Ada";
+    assert_eq_rendered!(td!(Locale::en, inbox.greeting, name), "Hello Ada");
+    let sender = "<admin>";
+    assert_eq!(
+        td_string!(Locale::fr, inbox.signature, sender),
+        "Envoyé par <admin>"
+    );
+}
+
+#[test]
+fn plural_suffixes() {
+    let messages = |locale: Locale, count: i32| {
+        td_string!(locale, inbox.messages, count = move || count).into_owned()
+    };
+    assert_eq!(messages(Locale::en, 0), "No messages");
+    assert_eq!(messages(Locale::en, 1), "One message");
+    assert_eq!(messages(Locale::en, 3), "3 messages");
+    assert_eq!(messages(Locale::fr, 0), "Aucun message");
+    assert_eq!(messages(Locale::fr, 1), "Un message");
+    assert_eq!(messages(Locale::fr, 1000000), "1000000 de messages");
+}
+
+#[test]
+fn nested_plural_suffixes() {
+    let folder = || "Inbox";
+    let count = || 1;
+    assert_eq_rendered!(
+        td!(Locale::en, inbox.folder.unread, count, folder),
+        "1 unread message in Inbox"
+    );
+    let count = || 2;
+    assert_eq_rendered!(
+        td!(Locale::fr, inbox.folder.unread, count, folder),
+        "2 messages non lus dans Inbox"
+    );
+}
+
+#[test]
+fn attachments() {
+    let count = || 1;
+    assert_eq_rendered!(td!(Locale::en, inbox.attachment, count), "One attachment");
+    let count = || 4;
+    assert_eq_rendered!(td!(Locale::en, inbox.attachment, count), "4 attachments");
+    let count = || 0;
+    assert_eq_rendered!(td!(Locale::fr, inbox.attachment, count), "Une pièce jointe");
+}
@@ -41,3 +41,27 @@ fn defaulted_foreign_key() {
     let fr = td!(Locale::fr, defaulted_foreign_key);
     assert_eq_rendered!(fr, "before Click to increment the counter after");
 }
+
+#[test]
+fn defaulted_subkeys() {
+    let en = td!(Locale::en, defaulted_subkeys.subkey);
+    assert_eq!(en, "this subkey is declared in locale en");
+    let fr = td!(Locale::fr, defaulted_subkeys.subkey);
+    assert_eq!(fr, "this subkey is declared in locale en");
+}
+
+#[test]
+fn defaulted_list() {
+    let en = td!(Locale::en, defaulted_list);
+    assert_eq!(en, ["this list is declared in locale en"]);
+    let fr = td!(Locale::fr, defaulted_list);
+    assert_eq!(fr, ["this list is declared in locale en"]);
+}
+
+#[test]
+fn defaulted_not_missing() {
+    let en = leptos_i18n::Locale::missing_keys(Locale::en);
+    assert!(en.is_empty());
+    let fr = leptos_i18n::Locale::missing_keys(Locale::fr);
+    assert!(fr.is_empty());
+}
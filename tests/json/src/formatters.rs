@@ -0,0 +1,60 @@
+use crate::i18n::*;
+use common::*;
+use leptos_i18n::{FormatDate, FormatTime};
+
+#[derive(Clone, Copy)]
+struct Day;
+
+impl FormatDate for Day {
+    fn to_date(&self) -> (i32, u8, u8) {
+        (2023, 12, 1)
+    }
+}
+
+impl FormatTime for Day {
+    fn to_time(&self) -> (u8, u8) {
+        (13, 5)
+    }
+}
+
+#[test]
+fn number_formatter() {
+    let count = || 1234567;
+    let en = td!(Locale::en, formatted_count, count);
+    assert_eq_rendered!(en, "1,234,567 visits");
+    let fr = td!(Locale::fr, formatted_count, count);
+    assert_eq_rendered!(fr, "1\u{202f}234\u{202f}567 visites");
+    let count = || 0.5;
+    let fr = td_string!(Locale::fr, formatted_count, count);
+    assert_eq!(fr, "0,5 visites");
+}
+
+#[test]
+fn currency_formatter() {
+    let price = || 1234.5;
+    let en = td!(Locale::en, formatted_price, price);
+    assert_eq_rendered!(en, "Total: €1,234.50");
+    let fr = td_string!(Locale::fr, formatted_price, price);
+    assert_eq!(fr, "Total : 1\u{202f}234,50\u{a0}€");
+}
+
+#[test]
+fn datetime_formatter() {
+    let day = || Day;
+    let en = td!(Locale::en, formatted_date, day);
+    assert_eq_rendered!(en, "Updated on 12/1/2023, 1:05\u{202f}PM");
+    let fr = td_string!(Locale::fr, formatted_date, day);
+    assert_eq!(fr, "Mis à jour le 01/12/2023 13:05");
+}
+
+#[test]
+fn formatted_plural_count() {
+    let count = || 1;
+    let en = td!(Locale::en, formatted_plural, count);
+    assert_eq_rendered!(en, "one visit");
+    let count = || 1500;
+    let en = td!(Locale::en, formatted_plural, count);
+    assert_eq_rendered!(en, "1,500 visits");
+    let fr = td_string!(Locale::fr, formatted_plural, count);
+    assert_eq!(fr, "1\u{202f}500 visites");
+}
@@ -1,5 +1,5 @@
 #![deny(warnings)]
-leptos_i18n::load_locales!();
+leptos_i18n::load_locales!(export = "target/i18n-manifest.json");
 
 #[cfg(test)]
 mod plurals;
@@ -15,3 +15,18 @@ mod defaulted;
 
 #[cfg(test)]
 mod foreign;
+
+#[cfg(test)]
+mod formatters;
+
+#[cfg(test)]
+mod select;
+
+#[cfg(test)]
+mod typed;
+
+#[cfg(test)]
+mod package;
+
+#[cfg(test)]
+mod router;
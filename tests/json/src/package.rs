@@ -0,0 +1,27 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn package_key() {
+    let en = td!(Locale::en, ui.ok);
+    assert_eq!(en, "OK");
+    let fr = td!(Locale::fr, ui.ok);
+    assert_eq!(fr, "Valider");
+}
+
+#[test]
+fn package_interpolation() {
+    let name = || "menu";
+    let en = td!(Locale::en, ui.close_dialog, name);
+    assert_eq_rendered!(en, "Close menu");
+    let fr = td!(Locale::fr, ui.close_dialog, name);
+    assert_eq_rendered!(fr, "Fermer menu");
+}
+
+#[test]
+fn package_foreign_key() {
+    let en = td!(Locale::en, ui.ok_or_cancel);
+    assert_eq!(en, "OK or Cancel");
+    let fr = td!(Locale::fr, ui.ok_or_cancel);
+    assert_eq!(fr, "Valider ou Annuler");
+}
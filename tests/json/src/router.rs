@@ -0,0 +1,75 @@
+use crate::i18n::*;
+use common::*;
+use leptos_i18n::I18nRoute;
+use leptos_router::*;
+
+#[component]
+fn Docs() -> impl IntoView {
+    leptos_i18n::use_locale_param::<Locale>("lang");
+}
+
+/// Render the routes for the path, the router of the server does not follow the navigation so each path is a new render.
+fn render_path(path: &str) {
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: format!("http://leptos.rs{}", path),
+    }));
+    let _ = view! {
+        <Router>
+            <Routes>
+                <Route path="/docs/:lang" view=Docs />
+            </Routes>
+        </Router>
+    }
+    .into_view();
+}
+
+#[test]
+fn locale_param() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    render_path("/docs/fr");
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    render_path("/docs/en");
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    render_path("/docs/FR");
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    // an unknown tag keeps the current locale.
+    render_path("/docs/xx");
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+}
+
+#[component]
+fn About() -> impl IntoView {
+    "about"
+}
+
+fn render_localized_path(path: &str) -> String {
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: format!("http://leptos.rs{}", path),
+    }));
+    view! {
+        <Router>
+            <Routes>
+                <I18nRoute<Locale, _, _> view=Outlet>
+                    <Route path="about" view=About />
+                </I18nRoute<Locale, _, _>>
+            </Routes>
+        </Router>
+    }
+    .into_view()
+    .render_to_string()
+    .to_string()
+}
+
+#[test]
+fn i18n_route() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    assert!(render_localized_path("/fr/about").contains("about"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    assert!(render_localized_path("/en/about").contains("about"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    runtime.dispose();
+}
@@ -0,0 +1,49 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn select_value() {
+    let name = "Alex";
+    let en = td!(Locale::en, invitation, gender = || "female", name);
+    assert_eq_rendered!(en, "Alex invited you to her party");
+    let en = td!(Locale::en, invitation, gender = || select::gender::male, name);
+    assert_eq_rendered!(en, "Alex invited you to his party");
+    // the variants not declared select the `other` value.
+    let en = td!(Locale::en, invitation, gender = || "unknown", name);
+    assert_eq_rendered!(en, "Alex invited you to their party");
+    let fr = td!(Locale::fr, invitation, gender = || select::gender::female, name);
+    assert_eq_rendered!(fr, "Alex vous invite à sa fête");
+}
+
+#[test]
+fn select_plurals() {
+    let gender = || select::gender::female;
+    let en = td!(Locale::en, inbox, gender, count = || 0);
+    assert_eq_rendered!(en, "She has no messages");
+    let en = td!(Locale::en, inbox, gender, count = || 3);
+    assert_eq_rendered!(en, "She has 3 messages");
+    let gender = || select::gender::male;
+    let en = td!(Locale::en, inbox, gender, count = || 3);
+    assert_eq_rendered!(en, "They have 3 messages");
+    let fr = td!(Locale::fr, inbox, gender, count = || 0);
+    assert_eq_rendered!(fr, "Il n'a aucun message");
+}
+
+#[test]
+fn select_string() {
+    let en = td_string!(Locale::en, invitation, gender = || "male", name = "Alex");
+    assert_eq!(en, "Alex invited you to his party");
+    let gender = || String::from("female");
+    let fr = td_string!(Locale::fr, inbox, gender, count = || 2);
+    assert_eq!(fr, "Elle a 2 messages");
+}
+
+#[test]
+fn select_enum() {
+    assert_eq!(select::gender::female.as_str(), "female");
+    assert_eq!(select::gender::other.as_str(), "other");
+    assert_eq!(
+        leptos_i18n::SelectVariant::variant(&select::gender::male),
+        "male"
+    );
+}
@@ -49,3 +49,663 @@ fn subkey_3() {
     let fr = td!(Locale::fr, subkeys.subkey_3, count);
     assert_eq_rendered!(fr, "3");
 }
+
+#[test]
+fn get_string() {
+    let en = I18nKeys::en;
+    assert_eq!(
+        en.get_string("click_to_change_lang"),
+        Some("Click to change language")
+    );
+    assert_eq!(en.get_string("subkeys.subkey_1"), Some("subkey_1"));
+    assert_eq!(en.get_string("click_count"), None);
+    assert_eq!(en.get_string("missing"), None);
+    let fr = I18nKeys::fr;
+    assert_eq!(
+        fr.get_string("click_to_change_lang"),
+        Some("Cliquez pour changez de langue")
+    );
+}
+
+#[test]
+fn t_title() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let _ = t_title!(i18n, click_to_change_lang).into_view();
+    let title = leptos_meta::use_head().title;
+    assert_eq!(
+        title.as_string().as_deref(),
+        Some("Click to change language")
+    );
+    i18n.set_locale(Locale::fr);
+    assert_eq!(
+        title.as_string().as_deref(),
+        Some("Cliquez pour changez de langue")
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn t_meta() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let _ = t_meta!(i18n, name = "description", click_to_inc).into_view();
+    let _ = t_meta!(i18n, property = "og:title", click_to_change_lang).into_view();
+    let head = leptos_meta::use_head();
+    let tags = head.tags.as_string();
+    assert!(tags.contains(r#"<meta name="description" content="Click to increment the counter""#));
+    assert!(tags.contains(r#"<meta property="og:title" content="Click to change language""#));
+    // the server renders the attributes once, the tags registered after the change are in the new locale.
+    i18n.set_locale(Locale::fr);
+    let _ = t_meta!(i18n, name = "description", click_to_inc).into_view();
+    let _ = t_meta!(i18n, property = "og:title", click_to_change_lang).into_view();
+    let tags = head.tags.as_string();
+    assert!(tags.contains(
+        r#"<meta name="description" content="Cliquez pour incrémenter le compteur""#
+    ));
+    assert!(tags.contains(r#"<meta property="og:title" content="Cliquez pour changez de langue""#));
+    runtime.dispose();
+}
+
+#[test]
+fn html_attrs() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let html = leptos_meta::use_head().html;
+    // the context sets the attributes without the component.
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="en" dir="ltr""#));
+    i18n.set_locale(Locale::fr);
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="fr" dir="ltr""#));
+    // an `<Html />` of the page overwrites `lang`, the component sets it again.
+    leptos_meta::Html(leptos_meta::HtmlProps {
+        lang: Some("de".into()),
+        dir: None,
+        class: None,
+        attributes: vec![],
+    });
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="de" dir="ltr""#));
+    let _ = view! { <I18nHtmlAttrs /> };
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="fr" dir="ltr""#));
+    runtime.dispose();
+}
+
+struct ClickError(u32);
+
+impl leptos_i18n::LocalizedError for ClickError {
+    fn key_path(&self) -> std::borrow::Cow<'static, str> {
+        "click_count".into()
+    }
+
+    fn args(&self) -> Vec<(&'static str, leptos_i18n::ErrorArg)> {
+        vec![("count", leptos_i18n::ErrorArg::value(self.0))]
+    }
+}
+
+struct NamedError(&'static str);
+
+impl leptos_i18n::LocalizedError for NamedError {
+    fn key_path(&self) -> std::borrow::Cow<'static, str> {
+        self.0.into()
+    }
+}
+
+#[test]
+fn localize_error() {
+    use leptos_i18n::localize_error;
+    assert_eq!(
+        localize_error(Locale::en, &ClickError(3)).as_deref(),
+        Some("You clicked 3 times")
+    );
+    assert_eq!(
+        localize_error(Locale::fr, &ClickError(3)).as_deref(),
+        Some("Vous avez cliqué 3 fois")
+    );
+    assert_eq!(
+        localize_error(Locale::fr, &NamedError("click_to_change_lang")).as_deref(),
+        Some("Cliquez pour changez de langue")
+    );
+    assert_eq!(
+        localize_error(Locale::en, &NamedError("click_count")).as_deref(),
+        Some("You clicked {{ count }} times")
+    );
+    assert_eq!(localize_error(Locale::en, &NamedError("f32_plural")), None);
+    assert_eq!(localize_error(Locale::en, &NamedError("missing")), None);
+}
+
+#[test]
+fn locale_serde() {
+    assert_eq!(serde_json::to_string(&Locale::fr).unwrap(), "\"fr\"");
+    let locale: Locale = serde_json::from_str("\"en\"").unwrap();
+    assert_eq!(locale, Locale::en);
+    assert!(serde_json::from_str::<Locale>("\"de\"").is_err());
+}
+
+#[test]
+fn locale_utilities() {
+    assert_eq!(Locale::COUNT, 2);
+    assert_eq!(Locale::iter().collect::<Vec<_>>(), [Locale::en, Locale::fr]);
+    assert_eq!(Locale::fr.as_str(), "fr");
+    assert_eq!(Locale::fr.to_string(), "fr");
+    assert_eq!("FR".parse::<Locale>(), Ok(Locale::fr));
+    assert_eq!(Locale::try_from(" en "), Ok(Locale::en));
+    let err = "de".parse::<Locale>().unwrap_err();
+    assert_eq!(err.tag(), "de");
+    assert_eq!(err.to_string(), "unknown locale \"de\"");
+}
+
+#[test]
+fn default_negotiation() {
+    use leptos_i18n::{Locale as _, LocaleSource};
+    assert_eq!(
+        Locale::NEGOTIATION,
+        [LocaleSource::Cookie, LocaleSource::AcceptLanguage]
+    );
+    assert_eq!(Locale::COOKIE_NAME, "i18n_pref_locale");
+    assert_eq!(Locale::STORAGE, leptos_i18n::StorageKind::Cookie);
+    assert_eq!(
+        Locale::COOKIE_ATTRIBUTES,
+        leptos_i18n::CookieAttributes::DEFAULT
+    );
+}
+
+#[test]
+fn locale_order() {
+    assert!(Locale::en < Locale::fr);
+    assert_eq!(Locale::en.index(), 0);
+    assert_eq!(Locale::fr.index(), 1);
+    assert_eq!(Locale::from_index(1), Some(Locale::fr));
+    assert_eq!(Locale::from_index(2), None);
+    let map = std::collections::BTreeMap::from([(Locale::fr, 1), (Locale::en, 0)]);
+    assert_eq!(
+        map.into_keys().collect::<Vec<_>>(),
+        [Locale::en, Locale::fr]
+    );
+}
+
+#[test]
+fn negotiate_locale() {
+    let locale: Locale = leptos_i18n::negotiate_locale("de-DE, fr;q=0.8, en;q=0.5");
+    assert_eq!(locale, Locale::fr);
+    let locale: Locale = leptos_i18n::negotiate_locale("de-DE");
+    assert_eq!(locale, Locale::en);
+}
+
+#[test]
+fn http_headers() {
+    use leptos_i18n::{
+        accept_language_header, content_language_header, locale_from_accept_language_header,
+        locale_from_content_language_header,
+    };
+    let header = accept_language_header(&[Locale::fr, Locale::en]);
+    assert_eq!(header, "fr, en;q=0.9");
+    assert_eq!(
+        locale_from_accept_language_header::<Locale>(&header),
+        Locale::fr
+    );
+    let header = content_language_header(Locale::fr);
+    assert_eq!(header, "fr");
+    assert_eq!(
+        locale_from_content_language_header::<Locale>(&header),
+        Some(Locale::fr)
+    );
+}
+
+struct FixedStorage(&'static str);
+
+impl leptos_i18n::LocaleStorage for FixedStorage {
+    fn load(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+
+    fn save(&self, _locale: &str) {}
+}
+
+#[test]
+fn provide_with_storage() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context_with_storage(FixedStorage("fr"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context_with_storage(FixedStorage("de"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    runtime.dispose();
+}
+
+#[derive(Default)]
+struct RecordingStorage(std::rc::Rc<std::cell::RefCell<Vec<String>>>);
+
+impl leptos_i18n::LocaleStorage for RecordingStorage {
+    fn load(&self) -> Option<String> {
+        self.0.borrow().last().cloned()
+    }
+
+    fn save(&self, locale: &str) {
+        self.0.borrow_mut().push(locale.to_string());
+    }
+}
+
+#[test]
+fn consent_storage() {
+    use leptos_i18n::{ConsentStorage, LocaleStorage};
+
+    let runtime = create_runtime();
+    let saved = RecordingStorage::default();
+    let saved_locales = std::rc::Rc::clone(&saved.0);
+    let consent = ConsentStorage::new(saved, false);
+    consent.save("fr");
+    consent.save("en");
+    assert!(saved_locales.borrow().is_empty());
+    assert_eq!(consent.load(), None);
+
+    consent.grant();
+    assert!(consent.is_granted());
+    assert_eq!(*saved_locales.borrow(), ["en"]);
+    consent.grant();
+    consent.save("fr");
+    assert_eq!(*saved_locales.borrow(), ["en", "fr"]);
+
+    consent.revoke();
+    consent.save("en");
+    assert_eq!(consent.load().as_deref(), Some("fr"));
+    runtime.dispose();
+}
+
+#[test]
+fn provide_with_locale() {
+    let runtime = create_runtime();
+    assert!(try_use_i18n().is_none());
+    let store_locale = run_as_child(|| {
+        let i18n = provide_i18n_context_with(Locale::fr);
+        assert_eq!(use_i18n().get_locale_untracked(), Locale::fr);
+        i18n
+    });
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    assert_eq!(store_locale.get_locale_untracked(), Locale::fr);
+    assert!(try_use_i18n().is_some());
+    runtime.dispose();
+}
+
+#[test]
+fn localize() {
+    use leptos_i18n::localize;
+    assert_eq!(
+        localize(Locale::fr, "click_count", &[("count", &3)]).as_deref(),
+        Some("Vous avez cliqué 3 fois")
+    );
+    assert_eq!(
+        localize(Locale::en, "subkeys.subkey_1", &[]).as_deref(),
+        Some("subkey_1")
+    );
+    assert_eq!(
+        localize(Locale::en, "click_count", &[]).as_deref(),
+        Some("You clicked {{ count }} times")
+    );
+    assert_eq!(localize(Locale::en, "subkeys.subkey_3", &[]), None);
+}
+
+#[test]
+fn runtime_lookup() {
+    use std::collections::HashMap;
+
+    let key_paths = Locale::key_paths();
+    assert!(key_paths.windows(2).all(|paths| paths[0] < paths[1]));
+    assert!(key_paths.contains(&"click_count"));
+    assert!(key_paths.contains(&"subkeys.subkey_3"));
+    assert!(!key_paths.contains(&"subkeys"));
+
+    assert_eq!(
+        Locale::fr
+            .get_translation("click_to_change_lang")
+            .as_deref(),
+        Some("Cliquez pour changez de langue")
+    );
+    assert_eq!(
+        Locale::en.get_translation("click_count").as_deref(),
+        Some("You clicked {{ count }} times")
+    );
+    let args = HashMap::from([("count", leptos_i18n::Value::from(3))]);
+    let click_count = Locale::fr.translation_builder("click_count").unwrap();
+    assert_eq!(click_count.args(args).build(), "Vous avez cliqué 3 fois");
+
+    // components and plurals can't be rendered at runtime.
+    assert_eq!(Locale::en.get_translation("subkeys.subkey_2"), None);
+    assert_eq!(Locale::en.get_translation("subkeys.subkey_3"), None);
+    assert_eq!(Locale::en.get_translation("missing"), None);
+}
+
+#[test]
+fn key_manifest() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/target/i18n-manifest.json");
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(manifest["default"], "en");
+    assert_eq!(
+        manifest["locales"][1]["plural_categories"],
+        serde_json::json!(["one", "many", "other"])
+    );
+    let keys = manifest["keys"].as_array().unwrap();
+    let paths = keys
+        .iter()
+        .map(|key| key["path"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, Locale::key_paths());
+    let key = |path: &str| keys.iter().find(|key| key["path"] == path).unwrap();
+    assert_eq!(
+        key("click_count"),
+        &serde_json::json!({
+            "path": "click_count",
+            "kind": "interpolation",
+            "source": "You clicked {{ count }} times",
+            "variables": ["count"]
+        })
+    );
+    assert_eq!(key("f32_plural")["kind"], "plural");
+    assert_eq!(key("tips")["description"], "Tips shown on the home page");
+    assert_eq!(
+        key("change_lang")["deprecated"]["replacement"],
+        "click_to_change_lang"
+    );
+}
+
+#[test]
+fn localized_paths() {
+    use leptos_i18n::Locale as _;
+    assert_eq!(Locale::get_all(), &[Locale::en, Locale::fr]);
+    let paths = leptos_i18n::localized_paths::<Locale, _>(["/", "/about"]);
+    assert_eq!(paths, ["/en", "/en/about", "/fr", "/fr/about"]);
+}
+
+#[test]
+fn email_renderer() {
+    use leptos_i18n::{Email, EmailRenderer};
+    let email = EmailRenderer::new(Locale::fr)
+        .var("name", "<Jean>")
+        .render("email")
+        .unwrap();
+    assert_eq!(
+        email,
+        Email {
+            subject: "Bienvenue <Jean>".to_string(),
+            html: "Bonjour &lt;Jean&gt;, <b>bienvenue</b> &amp; profitez".to_string(),
+            text: "Bonjour <Jean>, bienvenue & profitez".to_string(),
+        }
+    );
+    let html = EmailRenderer::new(Locale::en)
+        .component("b", |children| format!("<strong>{}</strong>", children))
+        .render_html("email.body");
+    assert_eq!(
+        html.as_deref(),
+        Some("Hello {{ name }}, <strong>welcome</strong> &amp; enjoy")
+    );
+    let renderer = EmailRenderer::new(Locale::en);
+    assert_eq!(
+        renderer.render_html("click_to_inc").as_deref(),
+        Some("Click to increment the counter")
+    );
+    assert_eq!(renderer.render_text("f32_plural"), None);
+    assert_eq!(renderer.render("subkeys"), None);
+}
+
+#[test]
+fn locale_announcer() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let announcement = i18n.locale_announcement("click_to_change_lang");
+    assert_eq!(announcement.get_untracked(), "");
+    i18n.set_locale(Locale::fr);
+    assert_eq!(
+        announcement.get_untracked(),
+        "Cliquez pour changez de langue"
+    );
+    i18n.set_locale(Locale::en);
+    assert_eq!(announcement.get_untracked(), "Click to change language");
+
+    let rendered = render_to_string(i18n.locale_announcer("click_to_change_lang"));
+    assert!(rendered.starts_with("<div role=\"status\" aria-live=\"polite\""));
+    runtime.dispose();
+}
+
+#[test]
+fn t_asset() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let asset = t_asset!(i18n, subkeys.subkey_1);
+    assert_eq!(asset(), "subkey_1");
+    let asset = t_asset!(i18n, click_to_change_lang);
+    i18n.set_locale(Locale::fr);
+    assert_eq!(asset(), "Cliquez pour changez de langue");
+    runtime.dispose();
+}
+
+#[test]
+fn list() {
+    let en = td!(Locale::en, tips);
+    assert_eq!(en, ["Save often", "Use shortcuts"]);
+    let fr = td!(Locale::fr, tips);
+    assert_eq!(
+        fr,
+        [
+            "Sauvegardez souvent",
+            "Utilisez les raccourcis",
+            "Lisez la doc"
+        ]
+    );
+}
+
+#[test]
+fn object_list() {
+    let en = td!(Locale::en, faq);
+    assert_eq!(en.len(), 2);
+    assert_eq!(en[0].question, "What is it?");
+    assert_eq!(en[0].answer, "A library");
+    assert_eq!(en[1].question, "Is it free?");
+    assert_eq!(en[1].answer, "Yes");
+    let fr = td!(Locale::fr, faq);
+    assert_eq!(fr.len(), 1);
+    assert_eq!(fr[0].question, "Qu'est-ce que c'est ?");
+    assert_eq!(fr[0].answer, "Une bibliothèque");
+}
+
+#[test]
+fn scalar_values() {
+    assert_eq!(td!(Locale::en, max_items), "5");
+    assert_eq!(td!(Locale::en, ratio), "1.5");
+    assert_eq!(td!(Locale::fr, ratio), "1,5");
+    assert_eq!(td!(Locale::fr, enabled), "true");
+}
+
+#[test]
+fn constants() {
+    let en = td!(Locale::en, version);
+    assert_eq!(en, "Version 0.1.0, contact support@example.com");
+    let fr = td!(Locale::fr, version);
+    assert_eq!(fr, "Version 0.1.0, contactez support@example.com");
+}
+
+#[test]
+fn feature_values() {
+    // the `globex` feature is enabled by default in this crate.
+    assert_eq!(td!(Locale::en, product_name), "Globex Hub");
+    assert_eq!(td!(Locale::fr, product_name), "Globex Hub");
+    let name = "John";
+    let en = td!(Locale::en, product_welcome, name);
+    assert_eq_rendered!(en, "Welcome to Globex, John");
+    let fr = td!(Locale::fr, product_welcome, name);
+    assert_eq_rendered!(fr, "Bienvenue sur Globex, John");
+}
+
+#[test]
+fn escaped_keys() {
+    assert_eq!(td!(Locale::en, r#type), "Type");
+    assert_eq!(td!(Locale::fr, _404), "Page introuvable");
+    assert_eq!(td!(Locale::en, clé), "Key");
+    assert_eq!(td!(Locale::fr, r#match.self_), "Sorte");
+    let en = I18nKeys::en;
+    assert_eq!(en.get_string("404"), Some("Page not found"));
+    assert_eq!(en.get_string("match.self"), Some("Kind"));
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_key() {
+    assert_eq!(td!(Locale::en, change_lang), "Change language");
+    assert_eq!(td!(Locale::fr, change_lang), "Changer de langue");
+}
+
+#[test]
+fn icu_conversion() {
+    let locale: icu_locale_core::Locale = Locale::fr.into();
+    assert_eq!(locale, icu_locale_core::locale!("fr"));
+    let langid: icu_locale_core::LanguageIdentifier = Locale::en.into();
+    assert_eq!(langid, icu_locale_core::langid!("en"));
+}
+
+#[test]
+fn display_interpolation() {
+    let en = td!(Locale::en, click_count, count = 3);
+    assert_eq!(en.to_string(), "You clicked 3 times");
+    let fr = td!(Locale::fr, click_count, count = "trois");
+    assert_eq!(format!("{}", fr), "Vous avez cliqué trois fois");
+
+    let count = || 2.5;
+    let en = td!(Locale::en, f32_plural, count);
+    assert_eq!(en.to_string(), "You have 2.5€");
+    let count = || 0.0;
+    let en = td!(Locale::en, f32_plural, count);
+    assert_eq!(en.to_string(), "You are broke");
+
+    let b = |children: ChildrenFn| view! { <b>{children}</b> };
+    let en = td!(Locale::en, subkeys.subkey_2, <b>);
+    assert_eq!(en.to_string(), "subkey_2");
+    assert_eq!(format!("{:#}", en), "<b>subkey_2</b>");
+}
+
+#[test]
+fn t_string() {
+    let en: std::borrow::Cow<'static, str> = td_string!(Locale::en, click_to_change_lang);
+    assert!(matches!(en, std::borrow::Cow::Borrowed("Click to change language")));
+
+    let en = td_string!(Locale::en, click_count, count = 3);
+    assert_eq!(en, "You clicked 3 times");
+    let count = || 2.5;
+    let fr = td_string!(Locale::fr, f32_plural, count);
+    assert_eq!(fr, "Vous avez 2.5€");
+
+    // components are not needed
+    let en = td_string!(Locale::en, subkeys.subkey_2);
+    assert_eq!(en, "subkey_2");
+
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    i18n.set_locale(Locale::fr);
+    let fr = t_string!(i18n, click_count, count = "trois");
+    assert_eq!(fr, "Vous avez cliqué trois fois");
+    runtime.dispose();
+}
+
+#[test]
+fn error_page() {
+    let page = leptos_i18n::render_error_page(Locale::fr, "errors.500").unwrap();
+    assert_eq!(
+        page,
+        "<!DOCTYPE html><html lang=\"fr\" dir=\"ltr\"><head><meta charset=\"utf-8\"><title>Erreur serveur</title></head><body><h1>Erreur serveur</h1><p>Une erreur est survenue, <b>réessayez</b> plus tard.</p></body></html>"
+    );
+    assert_eq!(leptos_i18n::render_error_page(Locale::en, "errors.404"), None);
+}
+
+#[test]
+fn override_locale() {
+    let guard = leptos_i18n::override_locale(Locale::fr);
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context_with_storage(FixedStorage("en"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+
+    {
+        let _nested = leptos_i18n::override_locale(Locale::en);
+        let runtime = create_runtime();
+        assert_eq!(provide_i18n_context().get_locale_untracked(), Locale::en);
+        runtime.dispose();
+    }
+
+    let runtime = create_runtime();
+    assert_eq!(provide_i18n_context().get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+
+    drop(guard);
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context_with_storage(FixedStorage("en"));
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    runtime.dispose();
+}
+
+#[component]
+fn ClickCount(count: i32) -> impl IntoView {
+    let i18n = use_i18n();
+    view! { <p>{t!(i18n, click_count, count)}</p> }
+}
+
+#[test]
+fn testing_context() {
+    use leptos_i18n::testing::TestI18n;
+    let i18n = TestI18n::new(Locale::fr);
+    i18n.assert_rendered(
+        || view! { <ClickCount count=3 /> },
+        "<p>Vous avez cliqué 3 fois</p>",
+    );
+    assert_eq!(i18n.next_locale(), Locale::en);
+    assert_eq!(
+        i18n.render(|| view! { <ClickCount count=3 /> }),
+        "<p>You clicked 3 times</p>"
+    );
+    assert_eq!(i18n.next_locale(), Locale::fr);
+    let context = i18n.context();
+    let rendered = i18n.render_all(|| t!(context, click_to_inc));
+    assert_eq!(
+        rendered,
+        [
+            (Locale::en, "Click to increment the counter".to_string()),
+            (Locale::fr, "Cliquez pour incrémenter le compteur".to_string())
+        ]
+    );
+    assert_eq!(i18n.locale(), Locale::fr);
+}
+
+#[test]
+fn render_keys() {
+    use leptos_i18n::testing::TestI18n;
+    let i18n = TestI18n::new(Locale::fr);
+    let rendered = i18n.render_keys();
+    // the lists are not rendered.
+    let paths = rendered
+        .lines()
+        .map(|line| line.split_once(" = ").unwrap().0)
+        .collect::<Vec<_>>();
+    let lists = ["defaulted_list", "faq", "tips"];
+    let expected = Locale::key_paths()
+        .iter()
+        .filter(|path| !lists.contains(path))
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(paths, expected);
+    for line in [
+        "click_count = Vous avez cliqué {{ count }} fois",
+        "email.body = Bonjour {{ name }}, <b>bienvenue</b> &amp; profitez",
+        "formatted_date = Mis à jour le 01/01/1970 00:00",
+        "formatted_price = Total : 0,00\u{a0}€",
+        "inbox = Aucun message",
+        "subkeys.subkey_3 = 0",
+        "typed_plural = Aucun article",
+    ] {
+        assert!(rendered.lines().any(|rendered| rendered == line), "{}", line);
+    }
+
+    let all = i18n.render_all_keys();
+    assert_eq!(all[0].0, Locale::en);
+    assert!(all[0].1.contains("\nclick_count = You clicked {{ count }} times\n"));
+    assert_eq!(all[1], (Locale::fr, rendered));
+    assert_eq!(i18n.locale(), Locale::fr);
+}
@@ -0,0 +1,25 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn typed_variables() {
+    let count = move || 3u32;
+    let en = td!(Locale::en, typed_count, count, cart = || "cart");
+    assert_eq_rendered!(en, "3 items in cart");
+    let fr = td!(Locale::fr, typed_count, count, cart = || "panier");
+    assert_eq_rendered!(fr, "3 articles dans panier");
+}
+
+#[test]
+fn typed_plural() {
+    let en = td!(Locale::en, typed_plural, count = || 0);
+    assert_eq_rendered!(en, "No items");
+    let fr = td!(Locale::fr, typed_plural, count = || 5);
+    assert_eq_rendered!(fr, "5 articles");
+}
+
+#[test]
+fn typed_string() {
+    let en = td_string!(Locale::en, typed_count, count = || 1, cart = || "cart");
+    assert_eq!(en, "1 items in cart");
+}
@@ -0,0 +1,24 @@
+use crate::i18n::*;
+
+#[test]
+fn key_fallback() {
+    let en = td!(Locale::en, first_namespace::colour);
+    assert_eq!(en, "Color");
+    let fr = td!(Locale::fr, first_namespace::colour);
+    assert_eq!(fr, "Colour");
+}
+
+#[test]
+fn map_fallback() {
+    let en = td!(Locale::en, second_namespace::subkeys.subkey_4);
+    assert_eq!(en, "subkey_4 color");
+    let fr = td!(Locale::fr, second_namespace::subkeys.subkey_4);
+    assert_eq!(fr, "subkey_4 colour");
+}
+
+#[test]
+fn fallback_not_missing() {
+    let fr = leptos_i18n::Locale::missing_keys(Locale::fr);
+    assert!(!fr.contains(&"first_namespace::colour"));
+    assert!(!fr.contains(&"second_namespace::subkeys.subkey_4"));
+}
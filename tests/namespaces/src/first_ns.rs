@@ -50,3 +50,18 @@ fn plural_only_en() {
     let fr = td!(Locale::fr, first_namespace::plural_only_en, count);
     assert_eq_rendered!(fr, "pas de plurals en français");
 }
+
+#[test]
+fn get_string() {
+    let en = I18nKeys::en;
+    assert_eq!(
+        en.get_string("first_namespace::common_key"),
+        Some("first namespace")
+    );
+    assert_eq!(en.get_string("common_key"), None);
+    let fr = I18nKeys::fr;
+    assert_eq!(
+        fr.get_string("second_namespace::subkeys.subkey_1"),
+        Some("subkey_1")
+    );
+}
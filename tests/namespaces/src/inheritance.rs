@@ -0,0 +1,39 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn overridden_key() {
+    let en_gb = td!(Locale::en_GB, first_namespace::click_to_change_lang);
+    assert_eq!(en_gb, "Click to change the language");
+    let en_gb = td!(Locale::en_GB, second_namespace::common_key);
+    assert_eq!(en_gb, "second namespace (GB)");
+}
+
+#[test]
+fn inherited_key() {
+    let en_gb = td!(Locale::en_GB, first_namespace::common_key);
+    assert_eq!(en_gb, "first namespace");
+    let en_gb = td!(Locale::en_GB, second_namespace::click_to_inc);
+    assert_eq!(en_gb, "Click to increment the counter");
+}
+
+#[test]
+fn inherited_subkeys() {
+    let en_gb = td!(Locale::en_GB, second_namespace::subkeys.subkey_1);
+    assert_eq!(en_gb, "subkey_1 (GB)");
+    let count = || 0;
+    let en_gb = td!(Locale::en_GB, second_namespace::subkeys.subkey_3, count);
+    assert_eq_rendered!(en_gb, "zero");
+}
+
+#[test]
+fn inherited_foreign_key() {
+    let en_gb = td!(Locale::en_GB, second_namespace::foreign_key_to_same_namespace);
+    assert_eq_rendered!(en_gb, "before second namespace (GB) after");
+}
+
+#[test]
+fn inherited_not_missing() {
+    let en_gb = leptos_i18n::Locale::missing_keys(Locale::en_GB);
+    assert!(en_gb.is_empty());
+}
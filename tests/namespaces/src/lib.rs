@@ -1,7 +1,25 @@
 #![deny(warnings)]
 leptos_i18n::load_locales!();
 
+pub mod admin {
+    leptos_i18n::load_locales!(shard = "admin");
+}
+
 #[cfg(test)]
 mod first_ns;
 #[cfg(test)]
 mod second_ns;
+#[cfg(test)]
+mod inheritance;
+#[cfg(test)]
+mod fallback;
+#[cfg(test)]
+mod locales;
+#[cfg(test)]
+mod shard;
+#[cfg(test)]
+mod pseudo;
+#[cfg(test)]
+mod scope;
+#[cfg(test)]
+mod package;
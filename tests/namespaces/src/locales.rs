@@ -0,0 +1,150 @@
+use crate::i18n::*;
+
+#[test]
+fn find_best_match() {
+    assert_eq!(Locale::find_best_match(&["en-GB"]), Locale::en_GB);
+    assert_eq!(Locale::find_best_match(&["en_gb"]), Locale::en_GB);
+    assert_eq!(Locale::find_best_match(&["en-GB-oxendict"]), Locale::en_GB);
+    assert_eq!(Locale::find_best_match(&["en-US"]), Locale::en);
+    assert_eq!(Locale::find_best_match(&["fr-CH", "en"]), Locale::fr);
+    assert_eq!(Locale::find_best_match(&["de-DE", "en-GB"]), Locale::en_GB);
+    assert_eq!(Locale::find_best_match(&["de", "*"]), Locale::en);
+    assert_eq!(Locale::find_best_match::<&str>(&[]), Locale::en);
+}
+
+#[test]
+fn find_matches() {
+    assert_eq!(
+        Locale::find_matches(&["fr-CH", "en-GB", "fr", "de", "en-US"]),
+        [Locale::fr, Locale::en_GB, Locale::en]
+    );
+    assert_eq!(Locale::find_matches(&["de", "*"]), []);
+    assert!(leptos_i18n::use_locales().is_empty());
+}
+
+#[test]
+fn from_str_subtags() {
+    use leptos_i18n::Locale as _;
+    assert_eq!(Locale::from_str("en-GB"), Some(Locale::en_GB));
+    assert_eq!(Locale::from_str("en-GB-oxendict"), Some(Locale::en_GB));
+    assert_eq!(Locale::from_str("fr-CA"), Some(Locale::fr));
+    assert_eq!(Locale::from_str("de-CH"), None);
+    assert_eq!("fr_ca".parse::<Locale>(), Ok(Locale::fr));
+    assert!("de".parse::<Locale>().is_err());
+}
+
+#[test]
+fn fallback_chain() {
+    // `en-GB` extends `en` from its tag, without `inherits` in the configuration.
+    assert_eq!(Locale::en_GB.fallback_chain(), [Locale::en_GB, Locale::en]);
+    assert_eq!(Locale::fr.fallback_chain(), [Locale::fr, Locale::en]);
+    assert_eq!(Locale::en.fallback_chain(), [Locale::en]);
+}
+
+#[test]
+fn direction() {
+    assert_eq!(Locale::en.direction(), leptos_i18n::Direction::Ltr);
+    assert_eq!(Locale::fr.direction().as_str(), "ltr");
+    assert_eq!(Locale::en_GB.direction().start(), "left");
+}
+
+#[test]
+fn snapshot() {
+    let fr = Locale::fr.snapshot();
+    let expected = "\
+first_namespace::click_to_change_lang = Cliquez pour changez de langue
+first_namespace::colour = Colour
+first_namespace::common_key = premier namespace
+first_namespace::plural_only_en = pas de plurals en français
+second_namespace::click_count = Vous avez cliqué 3 fois
+second_namespace::click_to_inc = Cliquez pour incrémenter le compteur
+second_namespace::common_key = deuxième namespace
+second_namespace::foreign_key_to_another_namespace = before premier namespace after
+second_namespace::foreign_key_to_same_namespace = before deuxième namespace after
+second_namespace::subkeys.subkey_1 = subkey_1
+second_namespace::subkeys.subkey_2 = <b>subkey_2</b>
+second_namespace::subkeys.subkey_3[0] = zero
+second_namespace::subkeys.subkey_3[_] = 3
+second_namespace::subkeys.subkey_4 = subkey_4 colour
+ui::cancel = Annuler
+ui::close_dialog = Fermer {{ name }}
+ui::ok = Valider
+ui::ok_or_cancel = Valider ou Annuler
+";
+    assert_eq!(fr, expected);
+    assert!(Locale::en
+        .snapshot()
+        .contains("first_namespace::plural_only_en[3..=8] = included end\n"));
+}
+
+#[test]
+fn render_keys() {
+    use leptos_i18n::{testing::TestI18n, Locale as _};
+    let i18n = TestI18n::new(Locale::fr);
+    let rendered = i18n.render_keys();
+    assert_eq!(rendered.lines().count(), Locale::key_paths().len());
+    for line in [
+        "first_namespace::common_key = premier namespace",
+        "second_namespace::subkeys.subkey_2 = <b>subkey_2</b>",
+        "ui::close_dialog = Fermer {{ name }}",
+    ] {
+        assert!(rendered.lines().any(|rendered| rendered == line), "{}", line);
+    }
+    let rendered = i18n.render_all_keys();
+    assert_eq!(rendered.len(), Locale::get_all().len());
+}
+
+#[test]
+fn runtime_lookup() {
+    let key_paths = Locale::key_paths();
+    assert_eq!(key_paths.len(), 17);
+    assert!(key_paths.contains(&"second_namespace::subkeys.subkey_1"));
+    assert!(!key_paths.iter().any(|path| path.starts_with("admin::")));
+
+    assert_eq!(
+        Locale::fr
+            .get_translation("first_namespace::common_key")
+            .as_deref(),
+        Some("premier namespace")
+    );
+    assert_eq!(
+        Locale::fr
+            .get_translation("second_namespace.subkeys.subkey_1")
+            .as_deref(),
+        Some("subkey_1")
+    );
+    let click_count = Locale::fr
+        .translation_builder("second_namespace.click_count")
+        .unwrap();
+    assert_eq!(click_count.variables(), ["count"]);
+    assert_eq!(
+        click_count.arg("count", 2).build(),
+        "Vous avez cliqué 2 fois"
+    );
+    assert_eq!(Locale::fr.get_translation("common_key"), None);
+    assert_eq!(Locale::fr.get_translation("admin::title"), None);
+}
+
+#[test]
+fn negotiation_config() {
+    use leptos_i18n::{Locale as _, LocaleSource};
+    assert_eq!(
+        Locale::NEGOTIATION,
+        [
+            LocaleSource::Url,
+            LocaleSource::Cookie,
+            LocaleSource::AcceptLanguage
+        ]
+    );
+    assert_eq!(Locale::COOKIE_NAME, "lang");
+    assert_eq!(
+        leptos_i18n::CookieStorage::for_locale::<Locale>().name(),
+        "lang"
+    );
+    let attributes = leptos_i18n::CookieStorage::for_locale::<Locale>().attributes();
+    assert_eq!(attributes, Locale::COOKIE_ATTRIBUTES);
+    assert_eq!(
+        attributes.cookie(Locale::COOKIE_NAME, "fr"),
+        "lang=fr; SameSite=Strict; Secure; Path=/app; Max-Age=2592000"
+    );
+}
@@ -0,0 +1,31 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn package_namespace() {
+    let en = td!(Locale::en, ui::cancel);
+    assert_eq!(en, "Cancel");
+    let fr = td!(Locale::fr, ui::cancel);
+    assert_eq!(fr, "Annuler");
+}
+
+#[test]
+fn package_inherited_key() {
+    let en_gb = td!(Locale::en_GB, ui::ok);
+    assert_eq!(en_gb, "OK");
+}
+
+#[test]
+fn package_interpolation() {
+    let name = || "menu";
+    let fr = td!(Locale::fr, ui::close_dialog, name);
+    assert_eq_rendered!(fr, "Fermer menu");
+}
+
+#[test]
+fn package_foreign_key() {
+    let en = td!(Locale::en, ui::ok_or_cancel);
+    assert_eq!(en, "OK or Cancel");
+    let fr = td!(Locale::fr, ui::ok_or_cancel);
+    assert_eq!(fr, "Valider ou Annuler");
+}
@@ -0,0 +1,31 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn pseudo_locale() {
+    assert_eq!(Locale::pseudo.as_str(), "pseudo");
+    let pseudo = td!(Locale::pseudo, first_namespace::common_key);
+    assert_eq!(pseudo, "[ƒîŕšŧ ñåṁéšþåçé ~~~~~]");
+}
+
+#[test]
+fn pseudo_interpolation() {
+    let count = move || 3;
+    let pseudo = td!(Locale::pseudo, second_namespace::click_count, count);
+    assert_eq_rendered!(pseudo, "[Ýöû çļîçķéđ 3 ŧîṁéš ~~~~~~]");
+}
+
+#[test]
+fn pseudo_foreign_key() {
+    let pseudo = td!(Locale::pseudo, second_namespace::foreign_key_to_same_namespace);
+    assert_eq_rendered!(pseudo, "[ƀéƒöŕé [šéçöñđ ñåṁéšþåçé ~~~~~~] åƒŧéŕ ~~~~~]");
+}
+
+#[test]
+fn pseudo_shard() {
+    use crate::admin::i18n::locale;
+    assert_eq!(
+        td!(locale(Locale::pseudo), admin::dashboard),
+        "[Đåšĥƀöåŕđ ~~~]"
+    );
+}
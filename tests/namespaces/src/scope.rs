@@ -0,0 +1,40 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn scoped_locale() {
+    let second = scope_i18n!(Locale::en, second_namespace);
+    assert_eq!(td!(second, common_key), "second namespace");
+    let subkeys = scope_i18n!(Locale::fr, second_namespace::subkeys);
+    assert_eq!(td!(subkeys, subkey_4), "subkey_4 colour");
+    assert_eq!(subkeys.inner(), Locale::fr);
+}
+
+#[test]
+fn scoped_context() {
+    let runtime = create_runtime();
+    provide_i18n_context_with(Locale::en);
+    let subkeys = use_i18n_scoped!(second_namespace.subkeys);
+    let subkey_1 = t!(subkeys, subkey_1);
+    assert_eq!(subkey_1(), "subkey_1");
+    subkeys.set_locale(Locale::en_GB);
+    assert_eq!(subkey_1(), "subkey_1 (GB)");
+    assert_eq!(subkeys.get_locale_untracked(), Locale::en_GB);
+    runtime.dispose();
+}
+
+#[test]
+fn nested_scope() {
+    let second = scope_i18n!(Locale::en, second_namespace);
+    let subkeys = scope_i18n!(second, subkeys);
+    assert_eq!(td!(subkeys, subkey_4), "subkey_4 color");
+    let count = move || 0;
+    assert_eq_rendered!(td!(subkeys, subkey_3, count), "zero");
+}
+
+#[test]
+fn scoped_shard() {
+    use crate::admin::i18n::locale;
+    let admin = scope_i18n!(locale(Locale::fr), admin);
+    assert_eq!(td!(admin, dashboard), "Tableau de bord");
+}
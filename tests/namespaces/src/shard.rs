@@ -0,0 +1,43 @@
+use crate::admin::i18n::*;
+use common::*;
+
+#[test]
+fn shard_keys() {
+    assert_eq!(td!(locale(Locale::en), admin::dashboard), "Dashboard");
+    assert_eq!(td!(locale(Locale::fr), admin::dashboard), "Tableau de bord");
+    assert_eq!(td!(locale(Locale::en_GB), admin::dashboard), "Dashboard");
+    let count = || 3;
+    let fr = td!(locale(Locale::fr), admin::user_count, count);
+    assert_eq_rendered!(fr, "3 utilisateurs");
+}
+
+#[test]
+fn shard_context() {
+    let runtime = create_runtime();
+    assert!(try_use_i18n().is_none());
+    crate::i18n::provide_i18n_context_with(Locale::fr);
+    let i18n = use_i18n();
+    assert_eq!(t!(i18n, admin::dashboard)(), "Tableau de bord");
+    i18n.set_locale(Locale::en);
+    assert_eq!(t!(i18n, admin::dashboard)(), "Dashboard");
+    assert_eq!(i18n.context().get_locale_untracked(), Locale::en);
+    runtime.dispose();
+}
+
+#[test]
+fn main_keys_exclude_shard() {
+    assert!(!Locale::fr.snapshot().contains("admin::"));
+    assert_eq!(crate::i18n::I18nKeys::fr.get_string("admin::dashboard"), None);
+}
+
+#[test]
+fn missing_key_marker() {
+    assert_eq!(td!(locale(Locale::en), admin::settings), "Settings");
+    assert_eq!(td!(locale(Locale::fr), admin::settings), "[admin::settings]");
+    assert_eq!(td!(locale(Locale::en_GB), admin::settings), "Settings");
+    let date = || "today";
+    let en = td!(locale(Locale::en), admin::last_login, date);
+    assert_eq_rendered!(en, "Last login: today");
+    let fr = td!(locale(Locale::fr), admin::last_login, date);
+    assert_eq_rendered!(fr, "[admin::last_login]");
+}
@@ -0,0 +1,96 @@
+use crate::i18n::*;
+use common::*;
+
+fn files(locale: Locale, count: u32) -> String {
+    td_string!(locale, files, count = move || count).into_owned()
+}
+
+fn distance(locale: Locale, count: f64) -> String {
+    td_string!(locale, distance, count = move || count).into_owned()
+}
+
+#[test]
+fn english() {
+    assert_eq!(files(Locale::en, 0), "0 files");
+    assert_eq!(files(Locale::en, 1), "1 file");
+    assert_eq!(files(Locale::en, 2), "2 files");
+    assert_eq!(distance(Locale::en, 1.0), "1 mile");
+    assert_eq!(distance(Locale::en, 1.5), "1.5 miles");
+}
+
+#[test]
+fn french() {
+    assert_eq!(files(Locale::fr, 0), "0 fichier");
+    assert_eq!(files(Locale::fr, 1), "1 fichier");
+    assert_eq!(files(Locale::fr, 2), "2 fichiers");
+    assert_eq!(files(Locale::fr, 1000000), "1000000 de fichiers");
+    assert_eq!(distance(Locale::fr, 1.5), "1.5 kilomètre");
+    assert_eq!(distance(Locale::fr, 2.5), "2.5 kilomètres");
+}
+
+#[test]
+fn russian() {
+    assert_eq!(files(Locale::ru, 1), "1 файл");
+    assert_eq!(files(Locale::ru, 21), "21 файл");
+    assert_eq!(files(Locale::ru, 3), "3 файла");
+    assert_eq!(files(Locale::ru, 22), "22 файла");
+    assert_eq!(files(Locale::ru, 5), "5 файлов");
+    assert_eq!(files(Locale::ru, 11), "11 файлов");
+    assert_eq!(files(Locale::ru, 12), "12 файлов");
+    assert_eq!(distance(Locale::ru, 2.5), "2.5 километра");
+}
+
+#[test]
+fn arabic() {
+    assert_eq!(files(Locale::ar, 0), "zero");
+    assert_eq!(files(Locale::ar, 1), "one");
+    assert_eq!(files(Locale::ar, 2), "two");
+    assert_eq!(files(Locale::ar, 3), "few 3");
+    assert_eq!(files(Locale::ar, 103), "few 103");
+    assert_eq!(files(Locale::ar, 11), "many 11");
+    assert_eq!(files(Locale::ar, 100), "other 100");
+    assert_eq!(distance(Locale::ar, 0.5), "other 0.5");
+}
+
+#[test]
+fn czech() {
+    assert_eq!(files(Locale::cs, 1), "1 soubor");
+    assert_eq!(files(Locale::cs, 3), "3 soubory");
+    assert_eq!(files(Locale::cs, 5), "5 souborů");
+    assert_eq!(distance(Locale::cs, 1.5), "1.5 kilometru");
+}
+
+#[test]
+fn mixed_with_counts() {
+    let count = || 0;
+    assert_eq_rendered!(td!(Locale::en, mixed, count), "no files");
+    assert_eq_rendered!(td!(Locale::ru, mixed, count), "нет файлов");
+    assert_eq_rendered!(td!(Locale::ar, mixed, count), "zero");
+    let count = || 1;
+    assert_eq_rendered!(td!(Locale::en, mixed, count), "one file");
+    assert_eq_rendered!(td!(Locale::fr, mixed, count), "un fichier");
+    let count = || 24;
+    assert_eq_rendered!(td!(Locale::ru, mixed, count), "24 файла");
+    assert_eq_rendered!(td!(Locale::cs, mixed, count), "24 souborů");
+}
+
+#[test]
+fn directions() {
+    assert_eq!(Locale::fr.direction(), leptos_i18n::Direction::Rtl);
+    assert_eq!(Locale::ar.direction(), leptos_i18n::Direction::Rtl);
+    assert_eq!(Locale::cs.direction(), leptos_i18n::Direction::Ltr);
+    assert_eq!(Locale::ru.direction(), leptos_i18n::Direction::Ltr);
+}
+
+#[test]
+fn html_dir() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let html = leptos_meta::use_head().html;
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="en" dir="ltr""#));
+    i18n.set_locale(Locale::fr);
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="fr" dir="rtl""#));
+    i18n.set_locale(Locale::cs);
+    assert_eq!(html.as_string().as_deref(), Some(r#" lang="cs" dir="ltr""#));
+    runtime.dispose();
+}
@@ -0,0 +1,84 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn click_to_change_lang() {
+    let en = td!(Locale::en, click_to_change_lang);
+    assert_eq!(en, "Click to change language");
+    let fr = td!(Locale::fr, click_to_change_lang);
+    assert_eq!(fr, "Cliquez pour changez de langue");
+}
+
+#[test]
+fn placeholders() {
+    let en = td_string!(Locale::en, greeting, arg1 = "Alice", arg2 = 3);
+    assert_eq!(en, "Hello Alice, you have 3 new messages");
+    let fr = td_string!(Locale::fr, greeting, arg1 = "Alice", arg2 = 3);
+    assert_eq!(fr, "Bonjour Alice, vous avez 3 nouveaux messages");
+    let author = || "Bob";
+    assert_eq_rendered!(td!(Locale::fr, signed, author), "Signé par Bob");
+}
+
+#[test]
+fn plural_forms() {
+    let file = |locale: Locale, count: i32| {
+        td_string!(locale, file, count = move || count).into_owned()
+    };
+    assert_eq!(file(Locale::en, 1), "1 file");
+    assert_eq!(file(Locale::en, 0), "0 files");
+    assert_eq!(file(Locale::fr, 0), "0 fichier");
+    assert_eq!(file(Locale::fr, 2), "2 fichiers");
+    assert_eq!(file(Locale::ru, 21), "21 файл");
+    assert_eq!(file(Locale::ru, 3), "3 файла");
+    assert_eq!(file(Locale::ru, 11), "11 файлов");
+}
+
+#[test]
+fn contexts() {
+    assert_eq!(td!(Locale::en, menu.file.open), "Open");
+    assert_eq!(td!(Locale::fr, menu.file.save), "Enregistrer");
+    assert_eq!(td!(Locale::ru, menu.file.open), "Открыть");
+}
+
+#[test]
+fn untranslated() {
+    // fuzzy and untranslated messages take the value of the default locale.
+    assert_eq!(td!(Locale::fr, untranslated), "Untranslated");
+    assert_eq!(td!(Locale::ru, untranslated), "Untranslated");
+}
+
+#[test]
+fn untranslated_missing_keys() {
+    use leptos_i18n::Locale as _;
+    assert!(Locale::en.missing_keys().is_empty());
+    assert_eq!(Locale::fr.missing_keys(), ["untranslated"]);
+    assert_eq!(Locale::ru.missing_keys(), ["untranslated"]);
+}
+
+#[test]
+fn missing_key_handler() {
+    use std::sync::Mutex;
+    static REPORTED: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+    leptos_i18n::set_missing_key_handler(|locale, key_path| {
+        REPORTED
+            .lock()
+            .unwrap()
+            .push((locale.to_string(), key_path.to_string()))
+    });
+
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    i18n.set_locale(Locale::fr);
+    i18n.set_locale(Locale::en);
+    i18n.set_locale(Locale::fr);
+    runtime.dispose();
+
+    leptos_i18n::clear_missing_key_handler();
+
+    let reported = REPORTED.lock().unwrap();
+    assert_eq!(
+        &*reported,
+        &[("fr".to_string(), "untranslated".to_string())]
+    );
+}
@@ -0,0 +1,11 @@
+#![deny(warnings)]
+leptos_i18n::load_locales!();
+
+#[cfg(test)]
+mod plurals;
+
+#[cfg(test)]
+mod subkeys;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn subkey_1() {
+    let en = td!(Locale::en, subkeys.subkey_1);
+    assert_eq!(en, "subkey_1");
+    let fr = td!(Locale::fr, subkeys.subkey_1);
+    assert_eq!(fr, "subkey_1");
+}
+
+#[test]
+fn subkey_2() {
+    let b = |children: ChildrenFn| view! { <b>{children}</b> };
+    let en = td!(Locale::en, subkeys.subkey_2, <b>);
+    assert_eq_rendered!(en, "<b>subkey_2</b>");
+    let fr = td!(Locale::fr, subkeys.subkey_2, <b>);
+    assert_eq_rendered!(fr, "<b>subkey_2</b>");
+
+    let b = |children: ChildrenFn| view! { <div>"before "{children}" after"</div> };
+    let en = td!(Locale::en, subkeys.subkey_2, <b>);
+    assert_eq_rendered!(en, "<div>before subkey_2 after</div>");
+    let fr = td!(Locale::fr, subkeys.subkey_2, <b>);
+    assert_eq_rendered!(fr, "<div>before subkey_2 after</div>");
+}
+
+#[test]
+fn subkey_3() {
+    let count = || 0;
+    let en = td!(Locale::en, subkeys.subkey_3, count);
+    assert_eq_rendered!(en, "zero");
+    let fr = td!(Locale::fr, subkeys.subkey_3, count);
+    assert_eq_rendered!(fr, "0");
+    let count = || 1;
+    let en = td!(Locale::en, subkeys.subkey_3, count);
+    assert_eq_rendered!(en, "one");
+    let fr = td!(Locale::fr, subkeys.subkey_3, count);
+    assert_eq_rendered!(fr, "1");
+    let count = || 3;
+    let en = td!(Locale::en, subkeys.subkey_3, count);
+    assert_eq_rendered!(en, "3");
+    let fr = td!(Locale::fr, subkeys.subkey_3, count);
+    assert_eq_rendered!(fr, "3");
+}
+
+#[test]
+fn dotted_keys() {
+    let en = td!(Locale::en, home.title);
+    assert_eq!(en, "Home");
+    let fr = td!(Locale::fr, home.title);
+    assert_eq!(fr, "Accueil");
+    let en = td!(Locale::en, home.subtitle);
+    assert_eq!(en, "Welcome");
+    let fr = td!(Locale::fr, home.subtitle);
+    assert_eq!(fr, "Bienvenue");
+    let en = td!(Locale::en, subkeys.subkey_4);
+    assert_eq!(en, "subkey_4");
+    let fr = td!(Locale::fr, subkeys.subkey_4);
+    assert_eq!(fr, "sous-clé 4");
+}
@@ -42,3 +42,19 @@ fn subkey_3() {
     let fr = td!(Locale::fr, subkeys.subkey_3, count);
     assert_eq_rendered!(fr, "3");
 }
+
+#[test]
+fn dotted_keys() {
+    let en = td!(Locale::en, home.title);
+    assert_eq!(en, "Home");
+    let fr = td!(Locale::fr, home.title);
+    assert_eq!(fr, "Accueil");
+    let en = td!(Locale::en, home.subtitle);
+    assert_eq!(en, "Welcome");
+    let fr = td!(Locale::fr, home.subtitle);
+    assert_eq!(fr, "Bienvenue");
+    let en = td!(Locale::en, subkeys.subkey_4);
+    assert_eq!(en, "subkey_4");
+    let fr = td!(Locale::fr, subkeys.subkey_4);
+    assert_eq!(fr, "sous-clé 4");
+}
@@ -49,3 +49,11 @@ fn subkey_3() {
     let fr = td!(Locale::fr, subkeys.subkey_3, count);
     assert_eq_rendered!(fr, "3");
 }
+
+#[test]
+fn escaped() {
+    let en = td!(Locale::en, escaped);
+    assert_eq!(en, "first\nsecond");
+    let fr = td!(Locale::fr, escaped);
+    assert_eq!(fr, "premier\nsecond");
+}